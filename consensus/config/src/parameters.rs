@@ -61,6 +61,12 @@ pub struct Parameters {
     #[serde(default = "Parameters::default_commit_sync_batches_ahead")]
     pub commit_sync_batches_ahead: usize,
 
+    /// Number of recently accepted blocks sent by peers to remember, so that a block sent again
+    /// is ignored without being verified and processed a second time.
+    /// Setting this to 0 disables the deduplication.
+    #[serde(default = "Parameters::default_sent_blocks_dedup_cache_size")]
+    pub sent_blocks_dedup_cache_size: usize,
+
     /// Anemo network settings.
     #[serde(default = "AnemoParameters::default")]
     pub anemo: AnemoParameters,
@@ -124,6 +130,10 @@ impl Parameters {
     pub(crate) fn default_commit_sync_batches_ahead() -> usize {
         200
     }
+
+    pub(crate) fn default_sent_blocks_dedup_cache_size() -> usize {
+        10_000
+    }
 }
 
 impl Default for Parameters {
@@ -138,6 +148,7 @@ impl Default for Parameters {
             commit_sync_parallel_fetches: Parameters::default_commit_sync_parallel_fetches(),
             commit_sync_batch_size: Parameters::default_commit_sync_batch_size(),
             commit_sync_batches_ahead: Parameters::default_commit_sync_batches_ahead(),
+            sent_blocks_dedup_cache_size: Parameters::default_sent_blocks_dedup_cache_size(),
             anemo: AnemoParameters::default(),
             tonic: TonicParameters::default(),
         }
@@ -341,7 +341,14 @@ where
 mod tests {
     #![allow(non_snake_case)]
 
-    use std::{collections::BTreeSet, sync::Arc, time::Duration};
+    use std::{
+        collections::BTreeSet,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
 
     use async_trait::async_trait;
     use bytes::Bytes;
@@ -360,8 +367,8 @@ mod tests {
     use super::*;
     use crate::{
         authority_node::AuthorityService,
-        block::{BlockAPI as _, BlockRef, Round, TestBlock, VerifiedBlock},
-        block_verifier::NoopBlockVerifier,
+        block::{BlockAPI as _, BlockRef, Round, SignedBlock, TestBlock, VerifiedBlock},
+        block_verifier::{BlockVerifier, NoopBlockVerifier},
         context::Context,
         core_thread::{CoreError, CoreThreadDispatcher},
         error::ConsensusResult,
@@ -550,6 +557,69 @@ mod tests {
         assert_eq!(blocks[0], input_block);
     }
 
+    /// Accepts all blocks, counting how many have been verified.
+    #[derive(Default)]
+    struct CountingBlockVerifier {
+        verified: AtomicUsize,
+    }
+
+    impl BlockVerifier for CountingBlockVerifier {
+        fn verify(&self, _block: &SignedBlock) -> ConsensusResult<()> {
+            self.verified.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+
+        fn check_ancestors(
+            &self,
+            _block: &VerifiedBlock,
+            _ancestors: &[VerifiedBlock],
+        ) -> ConsensusResult<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_authority_service_ignores_duplicate_blocks() {
+        let (context, _keys) = Context::new_for_test(4);
+        let context = Arc::new(context);
+        let block_verifier = Arc::new(CountingBlockVerifier::default());
+        let core_dispatcher = Arc::new(FakeCoreThreadDispatcher::new());
+        let (_tx_block_broadcast, rx_block_broadcast) = broadcast::channel(100);
+        let network_client = Arc::new(FakeNetworkClient::default());
+        let store = Arc::new(MemStore::new());
+        let dag_state = Arc::new(RwLock::new(DagState::new(context.clone(), store.clone())));
+        let synchronizer = Synchronizer::start(
+            network_client,
+            context.clone(),
+            core_dispatcher.clone(),
+            block_verifier.clone(),
+            dag_state.clone(),
+        );
+        let authority_service = AuthorityService::new(
+            context.clone(),
+            block_verifier.clone(),
+            Arc::new(CommitVoteMonitor::new(context.clone())),
+            synchronizer,
+            core_dispatcher.clone(),
+            rx_block_broadcast,
+            dag_state,
+            store,
+        );
+
+        let peer = context.committee.to_authority_index(1).unwrap();
+        let input_block = VerifiedBlock::new_for_test(TestBlock::new(9, 1).build());
+        for _ in 0..2 {
+            authority_service
+                .handle_send_block(peer, input_block.serialized().clone())
+                .await
+                .unwrap();
+        }
+
+        // The resent block is neither verified nor processed again.
+        assert_eq!(block_verifier.verified.load(Ordering::SeqCst), 1);
+        assert_eq!(core_dispatcher.get_blocks(), vec![input_block]);
+    }
+
     // TODO: build AuthorityFixture.
     #[rstest]
     #[tokio::test(flavor = "current_thread")]
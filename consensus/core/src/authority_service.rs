@@ -1,13 +1,14 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{pin::Pin, sync::Arc, time::Duration};
+use std::{num::NonZeroUsize, pin::Pin, sync::Arc, time::Duration};
 
 use async_trait::async_trait;
 use bytes::Bytes;
 use consensus_config::AuthorityIndex;
 use futures::{ready, stream, task, Stream, StreamExt};
-use parking_lot::RwLock;
+use lru::LruCache;
+use parking_lot::{Mutex, RwLock};
 use sui_macros::fail_point_async;
 use tokio::{sync::broadcast, time::sleep};
 use tokio_util::sync::ReusableBoxFuture;
@@ -39,6 +40,9 @@ pub(crate) struct AuthorityService<C: CoreThreadDispatcher> {
     rx_block_broadcaster: broadcast::Receiver<VerifiedBlock>,
     dag_state: Arc<RwLock<DagState>>,
     store: Arc<dyn Store>,
+    /// Recently accepted blocks sent by peers, used to skip duplicates.
+    /// None when deduplication is disabled.
+    recent_sent_blocks: Option<Mutex<LruCache<BlockRef, ()>>>,
 }
 
 impl<C: CoreThreadDispatcher> AuthorityService<C> {
@@ -52,6 +56,8 @@ impl<C: CoreThreadDispatcher> AuthorityService<C> {
         dag_state: Arc<RwLock<DagState>>,
         store: Arc<dyn Store>,
     ) -> Self {
+        let recent_sent_blocks = NonZeroUsize::new(context.parameters.sent_blocks_dedup_cache_size)
+            .map(|capacity| Mutex::new(LruCache::new(capacity)));
         Self {
             context,
             block_verifier,
@@ -61,6 +67,7 @@ impl<C: CoreThreadDispatcher> AuthorityService<C> {
             rx_block_broadcaster,
             dag_state,
             store,
+            recent_sent_blocks,
         }
     }
 }
@@ -76,7 +83,7 @@ impl<C: CoreThreadDispatcher> NetworkService for AuthorityService<C> {
 
         let peer_hostname = &self.context.committee.authority(peer).hostname;
 
-        // TODO: dedup block verifications, here and with fetched blocks.
+        // TODO: dedup block verifications with fetched blocks.
         let signed_block: SignedBlock =
             bcs::from_bytes(&serialized_block).map_err(ConsensusError::MalformedBlock)?;
 
@@ -94,6 +101,28 @@ impl<C: CoreThreadDispatcher> NetworkService for AuthorityService<C> {
         }
         let peer_hostname = &self.context.committee.authority(peer).hostname;
 
+        // Skip blocks that have already been accepted, before the more expensive verification.
+        let block_ref = BlockRef::new(
+            signed_block.round(),
+            signed_block.author(),
+            VerifiedBlock::compute_digest(&serialized_block),
+        );
+        if let Some(recent_sent_blocks) = &self.recent_sent_blocks {
+            if recent_sent_blocks.lock().get(&block_ref).is_some() {
+                self.context
+                    .metrics
+                    .node_metrics
+                    .duplicate_blocks
+                    .with_label_values(&[peer_hostname])
+                    .inc();
+                debug!(
+                    "Block {:?} from {} is a duplicate, ignored.",
+                    block_ref, peer
+                );
+                return Ok(());
+            }
+        }
+
         // Reject blocks failing validations.
         if let Err(e) = self.block_verifier.verify(&signed_block) {
             self.context
@@ -200,6 +229,11 @@ impl<C: CoreThreadDispatcher> NetworkService for AuthorityService<C> {
             .with_label_values(&[&peer_hostname])
             .inc();
 
+        // Only remember blocks that have passed all checks, so rejected blocks can be resent.
+        if let Some(recent_sent_blocks) = &self.recent_sent_blocks {
+            recent_sent_blocks.lock().put(block_ref, ());
+        }
+
         let missing_ancestors = self
             .core_dispatcher
             .add_blocks(vec![verified_block])
@@ -16,6 +16,7 @@ use crate::block::BlockTimestampMs;
 #[cfg(test)]
 use crate::metrics::test_metrics;
 use crate::metrics::Metrics;
+use crate::network::peer_score::PeerScores;
 
 /// Context contains per-epoch configuration and metrics shared by all components
 /// of this authority.
@@ -33,6 +34,8 @@ pub(crate) struct Context {
     pub metrics: Arc<Metrics>,
     /// Access to local clock
     pub clock: Arc<Clock>,
+    /// Quality scores of peers, updated from the outcomes of network requests.
+    pub peer_scores: Arc<PeerScores>,
 }
 
 impl Context {
@@ -44,6 +47,7 @@ impl Context {
         metrics: Arc<Metrics>,
         clock: Arc<Clock>,
     ) -> Self {
+        let peer_scores = Arc::new(PeerScores::new(own_index, &committee));
         Self {
             own_index,
             committee,
@@ -51,6 +55,7 @@ impl Context {
             protocol_config,
             metrics,
             clock,
+            peer_scores,
         }
     }
 
@@ -82,12 +87,14 @@ impl Context {
     #[cfg(test)]
     pub(crate) fn with_authority_index(mut self, authority: AuthorityIndex) -> Self {
         self.own_index = authority;
+        self.peer_scores = Arc::new(PeerScores::new(self.own_index, &self.committee));
         self
     }
 
     #[cfg(test)]
     pub(crate) fn with_committee(mut self, committee: Committee) -> Self {
         self.committee = committee;
+        self.peer_scores = Arc::new(PeerScores::new(self.own_index, &self.committee));
         self
     }
 
@@ -10,7 +10,7 @@ use prometheus::{
     HistogramVec, IntCounter, IntCounterVec, IntGauge, IntGaugeVec, Registry,
 };
 
-use crate::network::metrics::{NetworkRouteMetrics, QuinnConnectionMetrics};
+use crate::network::metrics::{NetworkPeerMetrics, NetworkRouteMetrics, QuinnConnectionMetrics};
 
 // starts from 1μs, 50μs, 100μs...
 const FINE_GRAINED_LATENCY_SEC_BUCKETS: &[f64] = &[
@@ -102,6 +102,7 @@ pub(crate) struct NodeMetrics {
     pub(crate) rejected_blocks: IntCounterVec,
     pub(crate) rejected_future_blocks: IntCounterVec,
     pub(crate) verified_blocks: IntCounterVec,
+    pub(crate) duplicate_blocks: IntCounterVec,
     pub(crate) committed_leaders_total: IntCounterVec,
     pub(crate) last_committed_leader_round: IntGauge,
     pub(crate) commit_round_advancement_interval: Histogram,
@@ -271,6 +272,12 @@ impl NodeMetrics {
                 &["authority"],
                 registry,
             ).unwrap(),
+            duplicate_blocks: register_int_counter_vec_with_registry!(
+                "duplicate_blocks",
+                "Number of blocks sent by each peer that were ignored as already accepted",
+                &["authority"],
+                registry,
+            ).unwrap(),
             committed_leaders_total: register_int_counter_vec_with_registry!(
                 "committed_leaders_total",
                 "Total number of (direct or indirect) committed leaders per authority",
@@ -492,6 +499,7 @@ pub(crate) struct NetworkMetrics {
     pub(crate) network_type: IntGaugeVec,
     pub(crate) inbound: NetworkRouteMetrics,
     pub(crate) outbound: NetworkRouteMetrics,
+    pub(crate) outbound_peers: NetworkPeerMetrics,
 }
 
 impl NetworkMetrics {
@@ -506,6 +514,7 @@ impl NetworkMetrics {
             .unwrap(),
             inbound: NetworkRouteMetrics::new("inbound", registry),
             outbound: NetworkRouteMetrics::new("outbound", registry),
+            outbound_peers: NetworkPeerMetrics::new(registry),
         }
     }
 }
@@ -135,15 +135,22 @@ impl NetworkClient for AnemoClient {
         block: &VerifiedBlock,
         timeout: Duration,
     ) -> ConsensusResult<()> {
-        let mut client = self.get_client(peer, timeout).await?;
-        let request = SendBlockRequest {
-            block: block.serialized().clone(),
-        };
-        client
-            .send_block(anemo::Request::new(request).with_timeout(timeout))
-            .await
-            .map_err(|e| ConsensusError::NetworkRequest(format!("send_block failed: {e:?}")))?;
-        Ok(())
+        let metrics = &self.context.metrics.network_metrics.outbound_peers;
+        metrics.record_request(peer, "send_block", block.serialized().len());
+        let result: ConsensusResult<()> = async {
+            let mut client = self.get_client(peer, timeout).await?;
+            let request = SendBlockRequest {
+                block: block.serialized().clone(),
+            };
+            client
+                .send_block(anemo::Request::new(request).with_timeout(timeout))
+                .await
+                .map_err(|e| ConsensusError::NetworkRequest(format!("send_block failed: {e:?}")))?;
+            Ok(())
+        }
+        .await;
+        metrics.record_result(peer, "send_block", &result, |_| 0);
+        result
     }
 
     async fn subscribe_blocks(
@@ -162,7 +169,6 @@ impl NetworkClient for AnemoClient {
         highest_accepted_rounds: Vec<Round>,
         timeout: Duration,
     ) -> ConsensusResult<Vec<Bytes>> {
-        let mut client = self.get_client(peer, timeout).await?;
         let request = FetchBlocksRequest {
             block_refs: block_refs
                 .iter()
@@ -176,18 +182,30 @@ impl NetworkClient for AnemoClient {
                 .collect(),
             highest_accepted_rounds,
         };
-        let response = client
-            .fetch_blocks(anemo::Request::new(request).with_timeout(timeout))
-            .await
-            .map_err(|e: Status| {
-                if e.status() == StatusCode::RequestTimeout {
-                    ConsensusError::NetworkRequestTimeout(format!("fetch_blocks timeout: {e:?}"))
-                } else {
-                    ConsensusError::NetworkRequest(format!("fetch_blocks failed: {e:?}"))
-                }
-            })?;
-        let body = response.into_body();
-        Ok(body.blocks)
+        let metrics = &self.context.metrics.network_metrics.outbound_peers;
+        metrics.record_request(peer, "fetch_blocks", request.payload_size());
+        let result: ConsensusResult<Vec<Bytes>> = async {
+            let mut client = self.get_client(peer, timeout).await?;
+            let response = client
+                .fetch_blocks(anemo::Request::new(request).with_timeout(timeout))
+                .await
+                .map_err(|e: Status| {
+                    if e.status() == StatusCode::RequestTimeout {
+                        ConsensusError::NetworkRequestTimeout(format!(
+                            "fetch_blocks timeout: {e:?}"
+                        ))
+                    } else {
+                        ConsensusError::NetworkRequest(format!("fetch_blocks failed: {e:?}"))
+                    }
+                })?;
+            let body = response.into_body();
+            Ok(body.blocks)
+        }
+        .await;
+        metrics.record_result(peer, "fetch_blocks", &result, |blocks| {
+            blocks.iter().map(Bytes::len).sum()
+        });
+        result
     }
 
     async fn fetch_commits(
@@ -592,6 +610,14 @@ pub(crate) struct FetchBlocksRequest {
     highest_accepted_rounds: Vec<Round>,
 }
 
+impl FetchBlocksRequest {
+    /// Size of the request contents, for metrics.
+    fn payload_size(&self) -> usize {
+        self.block_refs.iter().map(Vec::len).sum::<usize>()
+            + self.highest_accepted_rounds.len() * std::mem::size_of::<Round>()
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 pub(crate) struct FetchBlocksResponse {
     // Serialized SignedBlock.
@@ -1,12 +1,15 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use consensus_config::AuthorityIndex;
 use prometheus::{
     register_histogram_vec_with_registry, register_int_counter_vec_with_registry,
     register_int_gauge_vec_with_registry, register_int_gauge_with_registry, HistogramVec,
     IntCounterVec, IntGauge, IntGaugeVec, Registry,
 };
 
+use crate::error::ConsensusError;
+
 #[derive(Clone)]
 pub(crate) struct QuinnConnectionMetrics {
     /// The connection status of known peers. 0 if not connected, 1 if connected.
@@ -295,3 +298,99 @@ impl NetworkRouteMetrics {
         }
     }
 }
+
+/// Traffic of the network client to each peer, labeled by the peer's authority index and the
+/// client method.
+#[derive(Clone)]
+pub(crate) struct NetworkPeerMetrics {
+    /// Requests made to a peer
+    pub requests: IntCounterVec,
+    /// Bytes of request payload sent to a peer
+    pub bytes_sent: IntCounterVec,
+    /// Bytes of response payload received from a peer
+    pub bytes_received: IntCounterVec,
+    /// Failed requests to a peer, by error class
+    pub errors: IntCounterVec,
+}
+
+impl NetworkPeerMetrics {
+    pub fn new(registry: &Registry) -> Self {
+        Self {
+            requests: register_int_counter_vec_with_registry!(
+                "outbound_peer_requests",
+                "The number of requests made to a peer",
+                &["peer", "method"],
+                registry
+            )
+            .unwrap(),
+            bytes_sent: register_int_counter_vec_with_registry!(
+                "outbound_peer_bytes_sent",
+                "The number of request payload bytes sent to a peer",
+                &["peer", "method"],
+                registry
+            )
+            .unwrap(),
+            bytes_received: register_int_counter_vec_with_registry!(
+                "outbound_peer_bytes_received",
+                "The number of response payload bytes received from a peer",
+                &["peer", "method"],
+                registry
+            )
+            .unwrap(),
+            errors: register_int_counter_vec_with_registry!(
+                "outbound_peer_request_errors",
+                "The number of failed requests to a peer, by error class",
+                &["peer", "method", "class"],
+                registry
+            )
+            .unwrap(),
+        }
+    }
+
+    /// Records a request to `peer` carrying `bytes` of payload.
+    pub(crate) fn record_request(&self, peer: AuthorityIndex, method: &str, bytes: usize) {
+        let peer = peer.value().to_string();
+        self.requests.with_label_values(&[&peer, method]).inc();
+        self.bytes_sent
+            .with_label_values(&[&peer, method])
+            .inc_by(bytes as u64);
+    }
+
+    /// Records `bytes` of response payload received from `peer`.
+    pub(crate) fn record_response(&self, peer: AuthorityIndex, method: &str, bytes: usize) {
+        self.bytes_received
+            .with_label_values(&[&peer.value().to_string(), method])
+            .inc_by(bytes as u64);
+    }
+
+    /// Records the outcome of a request to `peer`, where `received` gives the size of a
+    /// successful response.
+    pub(crate) fn record_result<T>(
+        &self,
+        peer: AuthorityIndex,
+        method: &str,
+        result: &Result<T, ConsensusError>,
+        received: impl FnOnce(&T) -> usize,
+    ) {
+        match result {
+            Ok(response) => self.record_response(peer, method, received(response)),
+            Err(error) => self
+                .errors
+                .with_label_values(&[&peer.value().to_string(), method, error_class(error)])
+                .inc(),
+        }
+    }
+}
+
+/// Coarse classification of client errors, to keep the cardinality of error labels low.
+fn error_class(error: &ConsensusError) -> &'static str {
+    match error {
+        ConsensusError::NetworkRequestTimeout(_) => "timeout",
+        ConsensusError::NetworkRequest(_) => "request",
+        ConsensusError::UnknownNetworkPeer(_)
+        | ConsensusError::PeerDisconnected(_)
+        | ConsensusError::NetworkClientConnection(_) => "connection",
+        ConsensusError::Shutdown => "shutdown",
+        _ => "other",
+    }
+}
@@ -23,6 +23,7 @@ use bytes::Bytes;
 use consensus_config::{AuthorityIndex, NetworkKeyPair};
 use futures::Stream;
 
+use self::resubscribe::{LastReceivedRound, ResubscribeBackoff, ResubscribingBlockStream};
 use crate::{
     block::{BlockRef, VerifiedBlock},
     commit::TrustedCommit,
@@ -47,6 +48,8 @@ pub(crate) mod epoch_filter;
 pub(crate) mod metrics;
 #[cfg(test)]
 mod network_tests;
+pub(crate) mod peer_score;
+pub(crate) mod resubscribe;
 #[cfg(test)]
 pub(crate) mod test_network;
 pub(crate) mod tonic_network;
@@ -81,6 +84,22 @@ pub(crate) trait NetworkClient: Send + Sync + Sized + 'static {
         timeout: Duration,
     ) -> ConsensusResult<BlockStream>;
 
+    /// Subscribes to blocks from a peer like `subscribe_blocks`, but resubscribes with `backoff`
+    /// whenever the stream ends or cannot be established. Each subscription starts after the
+    /// round returned by `last_received`, which should only advance with blocks the caller has
+    /// accepted. Subscription attempts are recorded in the metrics of `context`.
+    fn subscribe_blocks_resilient(
+        self: Arc<Self>,
+        context: Arc<Context>,
+        peer: AuthorityIndex,
+        last_received: LastReceivedRound,
+        timeout: Duration,
+        backoff: ResubscribeBackoff,
+    ) -> BlockStream {
+        ResubscribingBlockStream::new(context, self, peer, last_received, timeout, backoff)
+            .into_block_stream()
+    }
+
     // TODO: add a parameter for maximum total size of blocks returned.
     /// Fetches serialized `SignedBlock`s from a peer. It also might return additional ancestor blocks
     /// of the requested blocks according to the provided `highest_accepted_rounds`. The `highest_accepted_rounds`
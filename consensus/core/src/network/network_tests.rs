@@ -7,6 +7,7 @@ use bytes::Bytes;
 use consensus_config::NetworkKeyPair;
 use futures::StreamExt as _;
 use parking_lot::Mutex;
+use prometheus::IntCounterVec;
 use rstest::rstest;
 use tokio::time::sleep;
 
@@ -15,7 +16,7 @@ use super::{
     NetworkClient, NetworkManager,
 };
 use crate::{
-    block::{TestBlock, VerifiedBlock},
+    block::{BlockDigest, BlockRef, TestBlock, VerifiedBlock},
     context::Context,
     Round,
 };
@@ -161,6 +162,79 @@ async fn send_and_receive_blocks_with_auth(
         .is_err());
 }
 
+#[rstest]
+#[tokio::test]
+async fn peer_metrics_record_send_and_fetch(
+    #[values(AnemoManagerBuilder {}, TonicManagerBuilder {})] manager_builder: impl ManagerBuilder,
+) {
+    let (context, keys) = Context::new_for_test(4);
+
+    let context_0 = Arc::new(
+        context
+            .clone()
+            .with_authority_index(context.committee.to_authority_index(0).unwrap()),
+    );
+    let mut manager_0 = manager_builder.build(context_0.clone(), keys[0].0.clone());
+    let client_0 = manager_0.client();
+    manager_0.install_service(service_with_own_blocks()).await;
+
+    let context_1 = Arc::new(
+        context
+            .clone()
+            .with_authority_index(context.committee.to_authority_index(1).unwrap()),
+    );
+    let mut manager_1 = manager_builder.build(context_1.clone(), keys[1].0.clone());
+    manager_1.install_service(service_with_own_blocks()).await;
+
+    // Wait for anemo to initialize.
+    sleep(Duration::from_secs(5)).await;
+
+    let peer = context.committee.to_authority_index(1).unwrap();
+    let test_block = VerifiedBlock::new_for_test(TestBlock::new(9, 0).build());
+    client_0
+        .send_block(peer, &test_block, Duration::from_secs(5))
+        .await
+        .unwrap();
+    let block_refs = vec![BlockRef::new(3, peer, BlockDigest::MIN)];
+    let blocks = client_0
+        .fetch_blocks(peer, block_refs, vec![], Duration::from_secs(5))
+        .await
+        .unwrap();
+    assert_eq!(blocks, vec![block_for_round(3)]);
+
+    let metrics = &context_0.metrics.network_metrics.outbound_peers;
+    let peer_label = peer.value().to_string();
+    let count = |counter: &IntCounterVec, method: &str| {
+        counter.with_label_values(&[&peer_label, method]).get()
+    };
+    assert_eq!(count(&metrics.requests, "send_block"), 1);
+    assert_eq!(
+        count(&metrics.bytes_sent, "send_block"),
+        test_block.serialized().len() as u64
+    );
+    assert_eq!(count(&metrics.requests, "fetch_blocks"), 1);
+    assert!(count(&metrics.bytes_sent, "fetch_blocks") > 0);
+    assert_eq!(
+        count(&metrics.bytes_received, "fetch_blocks"),
+        block_for_round(3).len() as u64
+    );
+    // Both requests succeeded, and nothing was sent to other peers.
+    assert_eq!(
+        metrics
+            .errors
+            .with_label_values(&[&peer_label, "send_block", "request"])
+            .get(),
+        0
+    );
+    assert_eq!(
+        metrics
+            .requests
+            .with_label_values(&["2", "send_block"])
+            .get(),
+        0
+    );
+}
+
 #[rstest]
 #[tokio::test]
 async fn subscribe_and_receive_blocks(
@@ -0,0 +1,141 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use consensus_config::{AuthorityIndex, Committee};
+use parking_lot::RwLock;
+use rand::{prelude::SliceRandom as _, thread_rng};
+
+/// Weight given to the latest observation when updating the moving averages.
+const EWMA_ALPHA: f64 = 0.2;
+
+/// Latency at which a peer with a perfect success rate has its score halved.
+const LATENCY_SCALE_MS: f64 = 1_000.0;
+
+/// Quality of a peer as observed from the outcomes of requests sent to it.
+/// Both components are exponentially weighted moving averages, so recent
+/// behaviour dominates.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct PeerScore {
+    /// Fraction of recent requests that succeeded, in [0, 1].
+    success_rate: f64,
+    /// Latency of recent successful requests, in milliseconds.
+    latency_ms: f64,
+}
+
+impl PeerScore {
+    /// Combines success rate and latency into a single score, higher is better.
+    pub(crate) fn value(&self) -> f64 {
+        self.success_rate / (1.0 + self.latency_ms / LATENCY_SCALE_MS)
+    }
+
+    fn record(&mut self, success: bool, latency: Option<Duration>) {
+        let outcome = if success { 1.0 } else { 0.0 };
+        self.success_rate += EWMA_ALPHA * (outcome - self.success_rate);
+        if let Some(latency) = latency {
+            let latency_ms = latency.as_secs_f64() * 1_000.0;
+            self.latency_ms += EWMA_ALPHA * (latency_ms - self.latency_ms);
+        }
+    }
+}
+
+impl Default for PeerScore {
+    // Peers start optimistic, so they get tried before any outcome is known.
+    fn default() -> Self {
+        Self {
+            success_rate: 1.0,
+            latency_ms: 0.0,
+        }
+    }
+}
+
+/// Tracks a `PeerScore` per authority, updated from `NetworkClient` call outcomes,
+/// so that catch-up logic can prefer responsive peers.
+pub(crate) struct PeerScores {
+    own_index: AuthorityIndex,
+    peers: Vec<AuthorityIndex>,
+    scores: RwLock<Vec<PeerScore>>,
+}
+
+impl PeerScores {
+    pub(crate) fn new(own_index: AuthorityIndex, committee: &Committee) -> Self {
+        Self {
+            own_index,
+            peers: committee.authorities().map(|(index, _)| index).collect(),
+            scores: RwLock::new(vec![PeerScore::default(); committee.size()]),
+        }
+    }
+
+    /// Records a successful request to `peer` which took `latency` to complete.
+    pub(crate) fn record_success(&self, peer: AuthorityIndex, latency: Duration) {
+        self.scores.write()[peer.value()].record(true, Some(latency));
+    }
+
+    /// Records a failed or timed out request to `peer`.
+    pub(crate) fn record_failure(&self, peer: AuthorityIndex) {
+        self.scores.write()[peer.value()].record(false, None);
+    }
+
+    pub(crate) fn score(&self, peer: AuthorityIndex) -> PeerScore {
+        self.scores.read()[peer.value()]
+    }
+
+    /// Returns up to `n` peers, excluding this authority, ordered from the highest score to
+    /// the lowest. Peers with equal scores are returned in random order, to spread the load.
+    pub(crate) fn best_peers(&self, n: usize) -> Vec<AuthorityIndex> {
+        let mut peers = self
+            .peers
+            .iter()
+            .copied()
+            .filter(|peer| *peer != self.own_index)
+            .collect::<Vec<_>>();
+        peers.shuffle(&mut thread_rng());
+
+        let scores = self.scores.read();
+        peers.sort_by(|a, b| {
+            scores[b.value()]
+                .value()
+                .total_cmp(&scores[a.value()].value())
+        });
+        peers.truncate(n);
+        peers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use consensus_config::AuthorityIndex;
+
+    use crate::context::Context;
+
+    #[test]
+    fn failing_peer_ranks_below_healthy_peer() {
+        let (context, _) = Context::new_for_test(4);
+        let peer_scores = &context.peer_scores;
+        let failing = AuthorityIndex::new_for_test(1);
+        let healthy = AuthorityIndex::new_for_test(2);
+        let slow = AuthorityIndex::new_for_test(3);
+
+        for _ in 0..10 {
+            peer_scores.record_failure(failing);
+            peer_scores.record_success(healthy, Duration::from_millis(50));
+            peer_scores.record_success(slow, Duration::from_millis(1_500));
+        }
+
+        assert!(peer_scores.score(failing).value() < peer_scores.score(slow).value());
+        assert!(peer_scores.score(slow).value() < peer_scores.score(healthy).value());
+
+        // Own authority is never returned.
+        assert_eq!(peer_scores.best_peers(4), vec![healthy, slow, failing]);
+        assert_eq!(peer_scores.best_peers(1), vec![healthy]);
+
+        // A failing peer recovers once it starts responding again.
+        for _ in 0..20 {
+            peer_scores.record_success(failing, Duration::from_millis(10));
+        }
+        assert_eq!(peer_scores.best_peers(1), vec![failing]);
+    }
+}
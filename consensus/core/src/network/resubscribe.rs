@@ -0,0 +1,320 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{sync::Arc, time::Duration};
+
+use bytes::Bytes;
+use consensus_config::AuthorityIndex;
+use futures::{stream, StreamExt as _};
+use tokio::time::sleep;
+use tracing::debug;
+
+use super::{BlockStream, NetworkClient};
+use crate::{context::Context, Round};
+
+/// Returns the highest round of the peer's blocks accepted by the receiver of a
+/// `ResubscribingBlockStream`, which is where a new subscription continues from.
+pub(crate) type LastReceivedRound = Arc<dyn Fn() -> Round + Send + Sync>;
+
+/// Delays between attempts to resubscribe to a peer. The first attempt after blocks have been
+/// received is immediate, then the delay grows exponentially from `initial_delay` up to
+/// `max_delay`.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ResubscribeBackoff {
+    pub(crate) initial_delay: Duration,
+    pub(crate) max_delay: Duration,
+    pub(crate) multiplier: f32,
+}
+
+impl ResubscribeBackoff {
+    fn next_delay(&self, delay: Duration) -> Duration {
+        if delay.is_zero() {
+            self.initial_delay
+        } else {
+            delay.mul_f32(self.multiplier).min(self.max_delay)
+        }
+    }
+}
+
+impl Default for ResubscribeBackoff {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+            multiplier: 1.2,
+        }
+    }
+}
+
+/// A block subscription to a peer which survives the underlying stream ending or failing to be
+/// established, by resubscribing with `ResubscribeBackoff`.
+/// Blocks are not inspected here: every resubscription continues after the round returned by
+/// `last_received`, so blocks that the receiver rejected are streamed again.
+/// The resulting stream never ends on its own.
+pub(crate) struct ResubscribingBlockStream<C: NetworkClient> {
+    context: Arc<Context>,
+    client: Arc<C>,
+    peer: AuthorityIndex,
+    last_received: LastReceivedRound,
+    timeout: Duration,
+    backoff: ResubscribeBackoff,
+    delay: Duration,
+    blocks: Option<BlockStream>,
+}
+
+impl<C: NetworkClient> ResubscribingBlockStream<C> {
+    pub(crate) fn new(
+        context: Arc<Context>,
+        client: Arc<C>,
+        peer: AuthorityIndex,
+        last_received: LastReceivedRound,
+        timeout: Duration,
+        backoff: ResubscribeBackoff,
+    ) -> Self {
+        Self {
+            context,
+            client,
+            peer,
+            last_received,
+            timeout,
+            backoff,
+            delay: Duration::ZERO,
+            blocks: None,
+        }
+    }
+
+    pub(crate) fn into_block_stream(self) -> BlockStream {
+        Box::pin(stream::unfold(self, |mut state| async move {
+            let block = state.next_block().await;
+            Some((block, state))
+        }))
+    }
+
+    async fn next_block(&mut self) -> Bytes {
+        loop {
+            if let Some(blocks) = self.blocks.as_mut() {
+                if let Some(block) = blocks.next().await {
+                    self.delay = Duration::ZERO;
+                    return block;
+                }
+                debug!("Subscription to blocks from peer {} ended", self.peer);
+                self.blocks = None;
+            }
+
+            if !self.delay.is_zero() {
+                debug!(
+                    "Delaying resubscription to peer {} by {} seconds",
+                    self.peer,
+                    self.delay.as_secs_f32(),
+                );
+                sleep(self.delay).await;
+            }
+            self.delay = self.backoff.next_delay(self.delay);
+
+            let last_received = (self.last_received)();
+            match self
+                .client
+                .subscribe_blocks(self.peer, last_received, self.timeout)
+                .await
+            {
+                Ok(blocks) => {
+                    debug!(
+                        "Subscribed to peer {} after round {}",
+                        self.peer, last_received
+                    );
+                    self.record_attempt("success");
+                    self.blocks = Some(blocks);
+                }
+                Err(e) => {
+                    debug!(
+                        "Failed to subscribe to blocks from peer {}: {}",
+                        self.peer, e
+                    );
+                    self.record_attempt("failure");
+                }
+            }
+        }
+    }
+
+    fn record_attempt(&self, outcome: &str) {
+        let peer_hostname = &self.context.committee.authority(self.peer).hostname;
+        self.context
+            .metrics
+            .node_metrics
+            .subscriber_connection_attempts
+            .with_label_values(&[peer_hostname, outcome])
+            .inc();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicU32, Ordering},
+        time::Duration,
+    };
+
+    use async_trait::async_trait;
+    use futures::{stream, StreamExt as _};
+    use parking_lot::Mutex;
+
+    use super::*;
+    use crate::{
+        block::{BlockAPI as _, BlockRef, SignedBlock, TestBlock, VerifiedBlock},
+        error::ConsensusResult,
+        CommitIndex,
+    };
+
+    /// Serves the blocks of one round range per subscription, so each stream ends after a few
+    /// blocks.
+    struct ResubscribeTestClient {
+        subscriptions: Mutex<Vec<(AuthorityIndex, Round)>>,
+    }
+
+    #[async_trait]
+    impl NetworkClient for ResubscribeTestClient {
+        const SUPPORT_STREAMING: bool = true;
+
+        async fn send_block(
+            &self,
+            _peer: AuthorityIndex,
+            _block: &VerifiedBlock,
+            _timeout: Duration,
+        ) -> ConsensusResult<()> {
+            unimplemented!("Unimplemented")
+        }
+
+        async fn subscribe_blocks(
+            &self,
+            peer: AuthorityIndex,
+            last_received: Round,
+            _timeout: Duration,
+        ) -> ConsensusResult<BlockStream> {
+            self.subscriptions.lock().push((peer, last_received));
+            let blocks = (last_received + 1..=last_received + 3)
+                .map(|round| {
+                    VerifiedBlock::new_for_test(TestBlock::new(round, peer.value() as u32).build())
+                        .serialized()
+                        .clone()
+                })
+                .collect::<Vec<_>>();
+            Ok(Box::pin(stream::iter(blocks)))
+        }
+
+        async fn fetch_blocks(
+            &self,
+            _peer: AuthorityIndex,
+            _block_refs: Vec<BlockRef>,
+            _highest_accepted_rounds: Vec<Round>,
+            _timeout: Duration,
+        ) -> ConsensusResult<Vec<Bytes>> {
+            unimplemented!("Unimplemented")
+        }
+
+        async fn fetch_commits(
+            &self,
+            _peer: AuthorityIndex,
+            _start: CommitIndex,
+            _end: CommitIndex,
+            _timeout: Duration,
+        ) -> ConsensusResult<(Vec<Bytes>, Vec<Bytes>)> {
+            unimplemented!("Unimplemented")
+        }
+    }
+
+    /// Receives `count` blocks from `blocks`, accepting those for which `accept` returns true by
+    /// advancing `last_accepted`. Returns the rounds of all received blocks.
+    async fn receive_blocks(
+        mut blocks: BlockStream,
+        count: usize,
+        last_accepted: &AtomicU32,
+        mut accept: impl FnMut(Round) -> bool,
+    ) -> Vec<Round> {
+        let mut rounds = vec![];
+        for _ in 0..count {
+            let block = blocks.next().await.unwrap();
+            let round = bcs::from_bytes::<SignedBlock>(&block).unwrap().round();
+            if accept(round) {
+                last_accepted.fetch_max(round, Ordering::Relaxed);
+            }
+            rounds.push(round);
+        }
+        rounds
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn resubscribes_after_stream_ends() {
+        let (context, _keys) = Context::new_for_test(4);
+        let client = Arc::new(ResubscribeTestClient {
+            subscriptions: Mutex::new(vec![]),
+        });
+        let peer = AuthorityIndex::new_for_test(2);
+        let last_accepted = Arc::new(AtomicU32::new(0));
+
+        let blocks = client.clone().subscribe_blocks_resilient(
+            Arc::new(context),
+            peer,
+            {
+                let last_accepted = last_accepted.clone();
+                Arc::new(move || last_accepted.load(Ordering::Relaxed))
+            },
+            Duration::from_secs(1),
+            ResubscribeBackoff::default(),
+        );
+        let rounds = receive_blocks(blocks, 6, &last_accepted, |_| true).await;
+        assert_eq!(rounds, vec![1, 2, 3, 4, 5, 6]);
+
+        // The second subscription continues from the last accepted round.
+        assert_eq!(*client.subscriptions.lock(), vec![(peer, 0), (peer, 3)]);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn resubscribes_from_last_accepted_round() {
+        let (context, _keys) = Context::new_for_test(4);
+        let client = Arc::new(ResubscribeTestClient {
+            subscriptions: Mutex::new(vec![]),
+        });
+        let peer = AuthorityIndex::new_for_test(2);
+        let last_accepted = Arc::new(AtomicU32::new(0));
+
+        let blocks = client.clone().subscribe_blocks_resilient(
+            Arc::new(context),
+            peer,
+            {
+                let last_accepted = last_accepted.clone();
+                Arc::new(move || last_accepted.load(Ordering::Relaxed))
+            },
+            Duration::from_secs(1),
+            ResubscribeBackoff::default(),
+        );
+        // The block of round 3 is rejected the first time it is received, e.g. because its
+        // timestamp is too far ahead, and accepted afterwards.
+        let mut rejected = false;
+        let rounds = receive_blocks(blocks, 6, &last_accepted, |round| {
+            if round == 3 && !rejected {
+                rejected = true;
+                return false;
+            }
+            true
+        })
+        .await;
+
+        // The rejected block is streamed again after resubscribing.
+        assert_eq!(rounds, vec![1, 2, 3, 3, 4, 5]);
+        assert_eq!(*client.subscriptions.lock(), vec![(peer, 0), (peer, 2)]);
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        let backoff = ResubscribeBackoff {
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(150),
+            multiplier: 2.0,
+        };
+        let delay = backoff.next_delay(Duration::ZERO);
+        assert_eq!(delay, Duration::from_millis(100));
+        let delay = backoff.next_delay(delay);
+        assert_eq!(delay, Duration::from_millis(150));
+        assert_eq!(backoff.next_delay(delay), Duration::from_millis(150));
+    }
+}
@@ -70,8 +70,14 @@ impl NetworkService for Mutex<TestService> {
         block_refs: Vec<BlockRef>,
         _highest_accepted_rounds: Vec<Round>,
     ) -> ConsensusResult<Vec<Bytes>> {
-        self.lock().handle_fetch_blocks.push((peer, block_refs));
-        Ok(vec![])
+        let mut state = self.lock();
+        // Let index in own_blocks be the round, as in handle_subscribe_blocks().
+        let blocks = block_refs
+            .iter()
+            .filter_map(|block_ref| state.own_blocks.get(block_ref.round as usize).cloned())
+            .collect();
+        state.handle_fetch_blocks.push((peer, block_refs));
+        Ok(blocks)
     }
 
     async fn handle_fetch_commits(
@@ -92,70 +92,14 @@ impl TonicClient {
             .max_encoding_message_size(config.message_size_limit)
             .max_decoding_message_size(config.message_size_limit))
     }
-}
-
-// TODO: make sure callsites do not send request to own index, and return error otherwise.
-#[async_trait]
-impl NetworkClient for TonicClient {
-    const SUPPORT_STREAMING: bool = true;
-
-    async fn send_block(
-        &self,
-        peer: AuthorityIndex,
-        block: &VerifiedBlock,
-        timeout: Duration,
-    ) -> ConsensusResult<()> {
-        let mut client = self.get_client(peer, timeout).await?;
-        let mut request = Request::new(SendBlockRequest {
-            block: block.serialized().clone(),
-        });
-        request.set_timeout(timeout);
-        client
-            .send_block(request)
-            .await
-            .map_err(|e| ConsensusError::NetworkRequest(format!("send_block failed: {e:?}")))?;
-        Ok(())
-    }
 
-    async fn subscribe_blocks(
-        &self,
-        peer: AuthorityIndex,
-        last_received: Round,
-        timeout: Duration,
-    ) -> ConsensusResult<BlockStream> {
-        let mut client = self.get_client(peer, timeout).await?;
-        // TODO: add sampled block acknowledgments for latency measurements.
-        let request = Request::new(stream::once(async move {
-            SubscribeBlocksRequest {
-                last_received_round: last_received,
-            }
-        }));
-        let response = client.subscribe_blocks(request).await.map_err(|e| {
-            ConsensusError::NetworkRequest(format!("subscribe_blocks failed: {e:?}"))
-        })?;
-        let stream = response
-            .into_inner()
-            .filter_map(move |b| async move {
-                match b {
-                    Ok(response) => Some(response.block),
-                    Err(e) => {
-                        debug!("Network error received from {}: {e:?}", peer);
-                        None
-                    }
-                }
-            })
-            .boxed();
-        Ok(stream)
-    }
-
-    async fn fetch_blocks(
+    async fn fetch_blocks_from(
         &self,
         peer: AuthorityIndex,
         block_refs: Vec<BlockRef>,
         highest_accepted_rounds: Vec<Round>,
         timeout: Duration,
     ) -> ConsensusResult<Vec<Bytes>> {
-        let mut client = self.get_client(peer, timeout).await?;
         let mut request = Request::new(FetchBlocksRequest {
             block_refs: block_refs
                 .iter()
@@ -169,6 +113,16 @@ impl NetworkClient for TonicClient {
                 .collect(),
             highest_accepted_rounds,
         });
+        self.context
+            .metrics
+            .network_metrics
+            .outbound_peers
+            .record_request(
+                peer,
+                "fetch_blocks",
+                prost::Message::encoded_len(request.get_ref()),
+            );
+        let mut client = self.get_client(peer, timeout).await?;
         request.set_timeout(timeout);
         let mut stream = client
             .fetch_blocks(request)
@@ -220,6 +174,104 @@ impl NetworkClient for TonicClient {
         }
         Ok(blocks)
     }
+}
+
+// TODO: make sure callsites do not send request to own index, and return error otherwise.
+#[async_trait]
+impl NetworkClient for TonicClient {
+    const SUPPORT_STREAMING: bool = true;
+
+    async fn send_block(
+        &self,
+        peer: AuthorityIndex,
+        block: &VerifiedBlock,
+        timeout: Duration,
+    ) -> ConsensusResult<()> {
+        let metrics = &self.context.metrics.network_metrics.outbound_peers;
+        metrics.record_request(peer, "send_block", block.serialized().len());
+        let result: ConsensusResult<()> = async {
+            let mut client = self.get_client(peer, timeout).await?;
+            let mut request = Request::new(SendBlockRequest {
+                block: block.serialized().clone(),
+            });
+            request.set_timeout(timeout);
+            client
+                .send_block(request)
+                .await
+                .map_err(|e| ConsensusError::NetworkRequest(format!("send_block failed: {e:?}")))?;
+            Ok(())
+        }
+        .await;
+        metrics.record_result(peer, "send_block", &result, |_| 0);
+        result
+    }
+
+    async fn subscribe_blocks(
+        &self,
+        peer: AuthorityIndex,
+        last_received: Round,
+        timeout: Duration,
+    ) -> ConsensusResult<BlockStream> {
+        let metrics = &self.context.metrics.network_metrics.outbound_peers;
+        let subscribe_request = SubscribeBlocksRequest {
+            last_received_round: last_received,
+        };
+        metrics.record_request(
+            peer,
+            "subscribe_blocks",
+            prost::Message::encoded_len(&subscribe_request),
+        );
+        let result = async {
+            let mut client = self.get_client(peer, timeout).await?;
+            // TODO: add sampled block acknowledgments for latency measurements.
+            let request = Request::new(stream::once(async move { subscribe_request }));
+            client.subscribe_blocks(request).await.map_err(|e| {
+                ConsensusError::NetworkRequest(format!("subscribe_blocks failed: {e:?}"))
+            })
+        }
+        .await;
+        metrics.record_result(peer, "subscribe_blocks", &result, |_| 0);
+        let response = result?;
+        // Blocks received over the subscription are counted as they arrive.
+        let bytes_received = metrics
+            .bytes_received
+            .with_label_values(&[&peer.value().to_string(), "subscribe_blocks"]);
+        let stream = response
+            .into_inner()
+            .inspect(move |b| {
+                if let Ok(response) = b {
+                    bytes_received.inc_by(response.block.len() as u64);
+                }
+            })
+            .filter_map(move |b| async move {
+                match b {
+                    Ok(response) => Some(response.block),
+                    Err(e) => {
+                        debug!("Network error received from {}: {e:?}", peer);
+                        None
+                    }
+                }
+            })
+            .boxed();
+        Ok(stream)
+    }
+
+    async fn fetch_blocks(
+        &self,
+        peer: AuthorityIndex,
+        block_refs: Vec<BlockRef>,
+        highest_accepted_rounds: Vec<Round>,
+        timeout: Duration,
+    ) -> ConsensusResult<Vec<Bytes>> {
+        let metrics = &self.context.metrics.network_metrics.outbound_peers;
+        let result = self
+            .fetch_blocks_from(peer, block_refs, highest_accepted_rounds, timeout)
+            .await;
+        metrics.record_result(peer, "fetch_blocks", &result, |blocks| {
+            blocks.iter().map(Bytes::len).sum()
+        });
+        result
+    }
 
     async fn fetch_commits(
         &self,
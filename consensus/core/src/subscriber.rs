@@ -7,20 +7,20 @@ use consensus_config::AuthorityIndex;
 use futures::StreamExt;
 use mysten_metrics::spawn_monitored_task;
 use parking_lot::{Mutex, RwLock};
-use tokio::{task::JoinHandle, time::sleep};
-use tracing::{debug, error, info};
+use tokio::task::JoinHandle;
+use tracing::{error, info};
 
 use crate::{
     block::BlockAPI as _,
     context::Context,
     dag_state::DagState,
-    network::{NetworkClient, NetworkService},
-    Round,
+    network::{resubscribe::ResubscribeBackoff, NetworkClient, NetworkService},
 };
 
-/// Subscriber manages the block stream subscriptions to other peers, taking care of retrying
-/// when subscription streams break. Blocks returned from the peer are sent to the authority
-/// service for processing.
+/// Subscriber manages the block stream subscriptions to other peers, resubscribing with
+/// `ResubscribeBackoff` when subscription streams break. Blocks returned from the peer are sent to the authority
+/// service for processing, and each resubscription continues after the peer's last block accepted
+/// into `DagState`.
 /// Currently subscription management for individual peer is not exposed, but it could become
 /// useful in future.
 pub(crate) struct Subscriber<C: NetworkClient, S: NetworkService> {
@@ -58,11 +58,7 @@ impl<C: NetworkClient, S: NetworkService> Subscriber<C, S> {
         let context = self.context.clone();
         let network_client = self.network_client.clone();
         let authority_service = self.authority_service.clone();
-        let last_received = self
-            .dag_state
-            .read()
-            .get_last_block_for_authority(peer)
-            .round();
+        let dag_state = self.dag_state.clone();
 
         let mut subscriptions = self.subscriptions.lock();
         self.unsubscribe_locked(peer, &mut subscriptions[peer.value()]);
@@ -70,8 +66,8 @@ impl<C: NetworkClient, S: NetworkService> Subscriber<C, S> {
             context,
             network_client,
             authority_service,
+            dag_state,
             peer,
-            last_received,
         )));
         let peer_hostname = &self.context.committee.authority(peer).hostname;
         self.context
@@ -106,84 +102,27 @@ impl<C: NetworkClient, S: NetworkService> Subscriber<C, S> {
         context: Arc<Context>,
         network_client: Arc<C>,
         authority_service: Arc<S>,
+        dag_state: Arc<RwLock<DagState>>,
         peer: AuthorityIndex,
-        last_received: Round,
     ) {
-        const IMMEDIATE_RETRIES: i64 = 3;
-        // When not immediately retrying, limit retry delay between 100ms and 10s.
-        const INITIAL_RETRY_INTERVAL: Duration = Duration::from_millis(100);
-        const MAX_RETRY_INTERVAL: Duration = Duration::from_secs(10);
-        const RETRY_INTERVAL_MULTIPLIER: f32 = 1.2;
-        let peer_hostname = &context.committee.authority(peer).hostname;
-        let mut retries: i64 = 0;
-        let mut delay = INITIAL_RETRY_INTERVAL;
-        'subscription: loop {
-            if retries > IMMEDIATE_RETRIES {
-                debug!(
-                    "Delaying retry {} of peer {} subscription, in {} seconds",
-                    retries,
-                    peer,
-                    delay.as_secs_f32(),
+        // Timeout of each subscription attempt.
+        const SUBSCRIBE_TIMEOUT: Duration = Duration::from_secs(10);
+        let mut blocks = network_client.subscribe_blocks_resilient(
+            context,
+            peer,
+            Arc::new(move || dag_state.read().get_last_block_for_authority(peer).round()),
+            SUBSCRIBE_TIMEOUT,
+            ResubscribeBackoff::default(),
+        );
+        while let Some(block) = blocks.next().await {
+            let result = authority_service
+                .handle_send_block(peer, block.clone())
+                .await;
+            if let Err(e) = result {
+                info!(
+                    "Failed to process block from peer {}: {}. Block: {:?}",
+                    peer, e, block,
                 );
-                sleep(delay).await;
-                // Update delay for the next retry.
-                delay = delay
-                    .mul_f32(RETRY_INTERVAL_MULTIPLIER)
-                    .min(MAX_RETRY_INTERVAL);
-            } else if retries > 0 {
-                // Retry immediately, but still yield to avoid monopolizing the thread.
-                tokio::task::yield_now().await;
-            } else {
-                // First attempt, reset delay for next retries but no waiting.
-                delay = INITIAL_RETRY_INTERVAL;
-            }
-            retries += 1;
-            let mut blocks = match network_client
-                .subscribe_blocks(peer, last_received, MAX_RETRY_INTERVAL)
-                .await
-            {
-                Ok(blocks) => {
-                    debug!("Subscribed to peer {} after {} attempts", peer, retries);
-                    context
-                        .metrics
-                        .node_metrics
-                        .subscriber_connection_attempts
-                        .with_label_values(&[&peer_hostname, "success"])
-                        .inc();
-                    blocks
-                }
-                Err(e) => {
-                    debug!("Failed to subscribe to blocks from peer {}: {}", peer, e);
-                    context
-                        .metrics
-                        .node_metrics
-                        .subscriber_connection_attempts
-                        .with_label_values(&[&peer_hostname, "failure"])
-                        .inc();
-                    continue 'subscription;
-                }
-            };
-            'stream: loop {
-                match blocks.next().await {
-                    Some(block) => {
-                        let result = authority_service
-                            .handle_send_block(peer, block.clone())
-                            .await;
-                        if let Err(e) = result {
-                            info!(
-                                "Failed to process block from peer {}: {}. Block: {:?}",
-                                peer, e, block,
-                            );
-                        }
-                        // Reset retries when a block is received.
-                        retries = 0;
-                    }
-                    None => {
-                        debug!("Subscription to blocks from peer {} ended", peer);
-                        retries += 1;
-                        break 'stream;
-                    }
-                }
             }
         }
     }
@@ -194,6 +133,7 @@ mod test {
     use anemo::async_trait;
     use bytes::Bytes;
     use futures::stream;
+    use tokio::time::sleep;
 
     use super::*;
     use crate::{
@@ -201,6 +141,7 @@ mod test {
         error::ConsensusResult,
         network::{test_network::TestService, BlockStream},
         storage::mem_store::MemStore,
+        Round,
     };
 
     struct SubscriberTestClient {}
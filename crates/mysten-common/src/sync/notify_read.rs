@@ -12,9 +12,11 @@ use std::pin::Pin;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
 use tokio::sync::oneshot;
 
-type Registrations<V> = Vec<oneshot::Sender<V>>;
+/// Pending registrations for a key, along with when each of them was made.
+type Registrations<V> = Vec<(Instant, oneshot::Sender<V>)>;
 
 pub struct NotifyRead<K, V> {
     pending: Vec<Mutex<HashMap<K, Registrations<V>>>>,
@@ -40,12 +42,43 @@ impl<K: Eq + Hash + Clone, V: Clone> NotifyRead<K, V> {
         let rem = self
             .count_pending
             .fetch_sub(registrations.len(), Ordering::Relaxed);
-        for registration in registrations {
+        for (_, registration) in registrations {
             registration.send(value.clone()).ok();
         }
         rem
     }
 
+    /// Resolves every registration made more than `ttl` ago with `value`, and returns the keys
+    /// that had registrations expired. Used to bound the number of pending registrations when
+    /// some keys may never be notified.
+    pub fn expire(&self, ttl: Duration, value: &V) -> Vec<K> {
+        let Some(cutoff) = Instant::now().checked_sub(ttl) else {
+            return vec![];
+        };
+        let mut expired_keys = vec![];
+        for pending in &self.pending {
+            let mut pending = pending.lock();
+            pending.retain(|key, registrations| {
+                let (expired, live): (Registrations<V>, Registrations<V>) =
+                    mem::take(registrations)
+                        .into_iter()
+                        .partition(|(registered_at, _)| *registered_at <= cutoff);
+                *registrations = live;
+                if expired.is_empty() {
+                    return true;
+                }
+                self.count_pending
+                    .fetch_sub(expired.len(), Ordering::Relaxed);
+                for (_, registration) in expired {
+                    registration.send(value.clone()).ok();
+                }
+                expired_keys.push(key.clone());
+                !registrations.is_empty()
+            });
+        }
+        expired_keys
+    }
+
     pub fn register_one(&self, key: &K) -> Registration<K, V> {
         self.count_pending.fetch_add(1, Ordering::Relaxed);
         let (sender, receiver) = oneshot::channel();
@@ -75,7 +108,7 @@ impl<K: Eq + Hash + Clone, V: Clone> NotifyRead<K, V> {
         self.pending(key)
             .entry(key.clone())
             .or_default()
-            .push(sender);
+            .push((Instant::now(), sender));
     }
 
     fn pending(&self, key: &K) -> MutexGuard<HashMap<K, Registrations<V>>> {
@@ -100,7 +133,7 @@ impl<K: Eq + Hash + Clone, V: Clone> NotifyRead<K, V> {
             return;
         };
         let mut count_deleted = 0usize;
-        registrations.retain(|s| {
+        registrations.retain(|(_, s)| {
             let delete = s.is_closed();
             if delete {
                 count_deleted += 1;
@@ -6,12 +6,16 @@ use dashmap::DashMap;
 use std::future::Future;
 use std::net::SocketAddr;
 use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::task::{Context, Poll};
 use std::time::Instant;
 
 use once_cell::sync::OnceCell;
-use prometheus::{register_int_gauge_vec_with_registry, IntGaugeVec, Registry, TextEncoder};
+use prometheus::{
+    register_histogram_vec_with_registry, register_int_gauge_vec_with_registry, Histogram,
+    HistogramVec, IntGauge, IntGaugeVec, Registry, TextEncoder,
+};
 use tap::TapFallible;
 use tracing::warn;
 
@@ -26,10 +30,16 @@ pub use guards::*;
 pub const TX_TYPE_SINGLE_WRITER_TX: &str = "single_writer";
 pub const TX_TYPE_SHARED_OBJ_TX: &str = "shared_object";
 
+/// Default buckets of the `monitored_future_latency` histogram, in seconds.
+pub const DEFAULT_FUTURE_LATENCY_SEC_BUCKETS: &[f64] = &[
+    0.0005, 0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1., 2., 5., 10., 20., 30., 60., 90.,
+];
+
 #[derive(Debug)]
 pub struct Metrics {
     pub tasks: IntGaugeVec,
     pub futures: IntGaugeVec,
+    pub futures_latency: HistogramVec,
     pub channels: IntGaugeVec,
     pub scope_iterations: IntGaugeVec,
     pub scope_duration_ns: IntGaugeVec,
@@ -37,22 +47,32 @@ pub struct Metrics {
 }
 
 impl Metrics {
-    fn new(registry: &Registry) -> Self {
+    fn new(registry: &Registry, future_latency_buckets: &[f64]) -> Self {
         Self {
             tasks: register_int_gauge_vec_with_registry!(
                 "monitored_tasks",
-                "Number of running tasks per callsite.",
+                "Number of running tasks per callsite. The callsite is the file and line the task \
+                 was spawned from, or the name it was given.",
                 &["callsite"],
                 registry,
             )
             .unwrap(),
             futures: register_int_gauge_vec_with_registry!(
                 "monitored_futures",
-                "Number of pending futures per callsite.",
+                "Number of pending futures per callsite. The callsite is the file and line of the \
+                 future, or the name it was given.",
                 &["callsite"],
                 registry,
             )
             .unwrap(),
+            futures_latency: register_histogram_vec_with_registry!(
+                "monitored_future_latency",
+                "Time taken by futures to complete, per callsite.",
+                &["callsite"],
+                future_latency_buckets.to_vec(),
+                registry,
+            )
+            .unwrap(),
             channels: register_int_gauge_vec_with_registry!(
                 "monitored_channels",
                 "Size of channels.",
@@ -88,18 +108,48 @@ impl Metrics {
 static METRICS: OnceCell<Metrics> = OnceCell::new();
 
 pub fn init_metrics(registry: &Registry) {
+    init_metrics_with_future_latency_buckets(registry, DEFAULT_FUTURE_LATENCY_SEC_BUCKETS)
+}
+
+/// Like `init_metrics`, but with custom buckets for the `monitored_future_latency` histogram.
+pub fn init_metrics_with_future_latency_buckets(registry: &Registry, buckets: &[f64]) {
     let _ = METRICS
-        .set(Metrics::new(registry))
+        .set(Metrics::new(registry, buckets))
         // this happens many times during tests
         .tap_err(|_| warn!("init_metrics registry overwritten"));
 }
 
+/// Initializes the metrics unless they already are, without logging. Returns whether this call
+/// initialized them. Metrics are only registered with `registry` when it does, so test harnesses
+/// can call this repeatedly with the same registry.
+pub fn init_metrics_silent(registry: &Registry) -> bool {
+    let mut initialized = false;
+    METRICS.get_or_init(|| {
+        initialized = true;
+        Metrics::new(registry, DEFAULT_FUTURE_LATENCY_SEC_BUCKETS)
+    });
+    initialized
+}
+
 pub fn get_metrics() -> Option<&'static Metrics> {
     METRICS.get()
 }
 
 #[macro_export]
 macro_rules! monitored_future {
+    // Records the time the future takes to complete in the `monitored_future_latency` histogram,
+    // including when it is dropped before completing.
+    (latency: $fut: expr) => {{
+        let location: &str = concat!(file!(), ':', line!());
+
+        async move {
+            let _latency_guard = mysten_metrics::get_metrics()
+                .map(|m| mysten_metrics::latency_guard(&m.futures_latency, location));
+
+            $fut.await
+        }
+    }};
+
     ($fut: expr) => {{
         monitored_future!(futures, $fut, "", INFO, false)
     }};
@@ -145,10 +195,82 @@ macro_rules! monitored_future {
             $fut.await
         }
     }};
+
+    // Labels the future with `$callsite` itself rather than its file and line, so that the label
+    // survives refactors and distinguishes futures created on the same line.
+    ($metric: ident, $fut: expr, callsite: $callsite: literal) => {{
+        let location: &'static str = $callsite;
+
+        async move {
+            let _metrics_guard = mysten_metrics::get_metrics().map(|m| {
+                let gauge = m.$metric.with_label_values(&[location]);
+                gauge.inc();
+                mysten_metrics::scopeguard::guard(gauge, |gauge| gauge.dec())
+            });
+
+            $fut.await
+        }
+    }};
+
+    // For hot callsites, only instruments one in every `$sample_rate` futures and scales the
+    // reported count accordingly, trading accuracy for lower overhead.
+    ($metric: ident, $fut: expr, sample_one_in: $sample_rate: expr) => {{
+        static CALLS: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+        let location: &str = concat!(file!(), ':', line!());
+
+        async move {
+            let _metrics_guard = mysten_metrics::get_metrics().and_then(|m| {
+                mysten_metrics::sampled_gauge_guard(&m.$metric, location, &CALLS, $sample_rate)
+            });
+
+            $fut.await
+        }
+    }};
+}
+
+/// Increments `gauge` for `location` by `sample_rate`, once for every `sample_rate` calls counted
+/// by `calls`. Returns a guard that decrements the gauge by the same amount when dropped, or None
+/// if this call is not sampled.
+#[doc(hidden)]
+pub fn sampled_gauge_guard(
+    gauge: &IntGaugeVec,
+    location: &str,
+    calls: &AtomicU64,
+    sample_rate: u64,
+) -> Option<scopeguard::ScopeGuard<IntGauge, impl FnOnce(IntGauge)>> {
+    let sample_rate = sample_rate.max(1);
+    if calls.fetch_add(1, Ordering::Relaxed) % sample_rate != 0 {
+        return None;
+    }
+    let weight = sample_rate as i64;
+    let gauge = gauge.with_label_values(&[location]);
+    gauge.add(weight);
+    Some(scopeguard::guard(gauge, move |gauge| gauge.sub(weight)))
+}
+
+/// Returns a guard that observes the time elapsed since its creation in `histogram` for
+/// `location` when dropped.
+#[doc(hidden)]
+pub fn latency_guard(
+    histogram: &HistogramVec,
+    location: &str,
+) -> scopeguard::ScopeGuard<(Histogram, Instant), impl FnOnce((Histogram, Instant))> {
+    let histogram = histogram.with_label_values(&[location]);
+    scopeguard::guard((histogram, Instant::now()), |(histogram, start)| {
+        histogram.observe(start.elapsed().as_secs_f64())
+    })
 }
 
 #[macro_export]
 macro_rules! spawn_monitored_task {
+    ($name: literal, $fut: expr) => {
+        tokio::task::spawn(mysten_metrics::monitored_future!(
+            tasks,
+            $fut,
+            callsite: $name
+        ))
+    };
+
     ($fut: expr) => {
         tokio::task::spawn(mysten_metrics::monitored_future!(
             tasks, $fut, "", INFO, false
@@ -392,9 +514,39 @@ pub async fn metrics(
 
 #[cfg(test)]
 mod tests {
-    use crate::RegistryService;
+    use crate::{get_metrics, init_metrics_silent, sampled_gauge_guard, RegistryService};
     use prometheus::IntCounter;
     use prometheus::Registry;
+    use prometheus::{IntGaugeVec, Opts};
+    use std::sync::atomic::AtomicU64;
+
+    #[test]
+    fn sampled_gauge() {
+        let gauge = IntGaugeVec::new(Opts::new("futures", "futures_desc"), &["callsite"]).unwrap();
+        let calls = AtomicU64::new(0);
+
+        let guards: Vec<_> = (0..1000)
+            .map(|_| sampled_gauge_guard(&gauge, "callsite", &calls, 100))
+            .collect();
+
+        // Only 1 in 100 invocations is instrumented, but the reported count is scaled up.
+        assert_eq!(guards.iter().filter(|guard| guard.is_some()).count(), 10);
+        assert_eq!(gauge.with_label_values(&["callsite"]).get(), 1000);
+
+        drop(guards);
+        assert_eq!(gauge.with_label_values(&["callsite"]).get(), 0);
+    }
+
+    #[test]
+    fn init_metrics_silent_tolerates_double_init() {
+        let registry = Registry::new();
+
+        assert!(init_metrics_silent(&registry));
+        assert!(get_metrics().is_some());
+        // Does not panic, although the metrics are already registered with this registry.
+        assert!(!init_metrics_silent(&registry));
+        assert!(!init_metrics_silent(&Registry::new()));
+    }
 
     #[test]
     fn registry_service() {
@@ -0,0 +1,86 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use mysten_metrics::{get_metrics, init_metrics, monitored_future, spawn_monitored_task};
+use prometheus::{core::Collector, proto::Histogram, Registry};
+use tokio::sync::oneshot;
+
+/// The latency histogram of the only callsite monitored in this test binary, if it was sampled.
+fn sampled_latency() -> Option<Histogram> {
+    let families = get_metrics().unwrap().futures_latency.collect();
+    families[0]
+        .get_metric()
+        .first()
+        .map(|metric| metric.get_histogram().clone())
+}
+
+/// The `futures` gauge of the only callsite it counts in this test binary, which is sampled.
+fn sampled_futures() -> i64 {
+    let families = get_metrics().unwrap().futures.collect();
+    families[0]
+        .get_metric()
+        .first()
+        .map_or(0, |metric| metric.get_gauge().get_value() as i64)
+}
+
+#[tokio::test]
+async fn monitored_future_records_latency() {
+    init_metrics(&Registry::new());
+
+    let future = monitored_future!(latency: tokio::time::sleep(Duration::from_millis(50)));
+    assert!(sampled_latency().is_none());
+
+    tokio::spawn(future).await.unwrap();
+
+    let histogram = sampled_latency().unwrap();
+    assert_eq!(histogram.get_sample_count(), 1);
+    assert!(histogram.get_sample_sum() >= 0.05);
+}
+
+#[tokio::test]
+async fn named_task_is_labeled_with_its_name() {
+    init_metrics(&Registry::new());
+    let gauge = get_metrics()
+        .unwrap()
+        .tasks
+        .with_label_values(&["named_task"]);
+
+    let (started_tx, started_rx) = oneshot::channel();
+    let (finish_tx, finish_rx) = oneshot::channel::<()>();
+    let handle = spawn_monitored_task!("named_task", async move {
+        started_tx.send(()).unwrap();
+        finish_rx.await.unwrap();
+    });
+
+    started_rx.await.unwrap();
+    assert_eq!(gauge.get(), 1);
+
+    finish_tx.send(()).unwrap();
+    handle.await.unwrap();
+    assert_eq!(gauge.get(), 0);
+}
+
+#[tokio::test]
+async fn monitored_future_samples_its_callsite() {
+    init_metrics(&Registry::new());
+
+    let mut pending = vec![];
+    let mut gauge_values = vec![];
+    for _ in 0..6 {
+        let mut future = Box::pin(monitored_future!(
+            futures,
+            std::future::pending::<()>(),
+            sample_one_in: 3
+        ));
+        assert!(futures::poll!(&mut future).is_pending());
+        pending.push(future);
+        gauge_values.push(sampled_futures());
+    }
+    // Only the first of every 3 futures from the callsite is counted, for all 3 of them.
+    assert_eq!(gauge_values, vec![3, 3, 3, 6, 6, 6]);
+
+    drop(pending);
+    assert_eq!(sampled_futures(), 0);
+}
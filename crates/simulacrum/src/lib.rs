@@ -23,7 +23,7 @@ use sui_storage::blob::{Blob, BlobEncoding};
 use sui_swarm_config::genesis_config::AccountConfig;
 use sui_swarm_config::network_config::NetworkConfig;
 use sui_swarm_config::network_config_builder::ConfigBuilder;
-use sui_types::base_types::{AuthorityName, ObjectID, VersionNumber};
+use sui_types::base_types::{AuthorityName, ObjectID, ObjectInfo, VersionNumber};
 use sui_types::crypto::AuthoritySignature;
 use sui_types::digests::ConsensusCommitDigest;
 use sui_types::object::Object;
@@ -297,6 +297,11 @@ impl<R, S: store::SimulatorStore> Simulacrum<R, S> {
         &self.store
     }
 
+    /// Return a handle to the concrete store backing this Simulacrum.
+    pub fn store_inner(&self) -> &S {
+        &self.store
+    }
+
     pub fn keystore(&self) -> &KeyStore {
         &self.keystore
     }
@@ -457,13 +462,13 @@ impl<T, V: store::SimulatorStore> ReadStore for Simulacrum<T, V> {
     fn get_highest_verified_checkpoint(
         &self,
     ) -> sui_types::storage::error::Result<VerifiedCheckpoint> {
-        todo!()
+        self.get_latest_checkpoint()
     }
 
     fn get_highest_synced_checkpoint(
         &self,
     ) -> sui_types::storage::error::Result<VerifiedCheckpoint> {
-        todo!()
+        self.get_latest_checkpoint()
     }
 
     fn get_lowest_available_checkpoint(
@@ -545,6 +550,22 @@ impl<T, V: store::SimulatorStore> ReadStore for Simulacrum<T, V> {
     > {
         todo!()
     }
+
+    fn get_owned_objects(
+        &self,
+        owner: SuiAddress,
+        cursor: Option<ObjectID>,
+    ) -> sui_types::storage::error::Result<Box<dyn Iterator<Item = ObjectInfo> + '_>> {
+        // The store does not keep objects ordered by id, so they are sorted up front.
+        let mut objects = self
+            .store()
+            .owned_objects(owner)
+            .filter(|object| cursor.map_or(true, |cursor| object.id() > cursor))
+            .map(|object| ObjectInfo::new(&object.compute_object_reference(), &object))
+            .collect::<Vec<_>>();
+        objects.sort_by_key(|object| object.object_id);
+        Ok(Box::new(objects.into_iter()))
+    }
 }
 
 impl Simulacrum {
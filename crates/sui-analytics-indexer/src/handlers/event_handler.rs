@@ -20,8 +20,12 @@ use sui_types::digests::TransactionDigest;
 use sui_types::effects::TransactionEvents;
 use sui_types::event::Event;
 
+/// Placeholder stored in place of event JSON that exceeds the configured size limit.
+const TRUNCATED_EVENT_JSON: &str = "null";
+
 pub struct EventHandler {
     state: Mutex<State>,
+    max_json_bytes: Option<usize>,
 }
 
 struct State {
@@ -84,7 +88,7 @@ impl AnalyticsHandler<EventEntry> for EventHandler {
 }
 
 impl EventHandler {
-    pub fn new(store_path: &Path, rest_uri: &str) -> Self {
+    pub fn new(store_path: &Path, rest_uri: &str, max_json_bytes: Option<usize>) -> Self {
         let package_store = LocalDBPackageStore::new(&store_path.join("event"), rest_uri);
         let state = State {
             events: vec![],
@@ -93,8 +97,10 @@ impl EventHandler {
         };
         Self {
             state: Mutex::new(state),
+            max_json_bytes,
         }
     }
+
     async fn process_events(
         &self,
         epoch: u64,
@@ -119,7 +125,10 @@ impl EventHandler {
                 }
                 fields => (type_.clone(), fields),
             };
-            let event_json = sui_move_struct.to_json_value();
+            let (event_json, event_json_truncated) = cap_event_json(
+                sui_move_struct.to_json_value().to_string(),
+                self.max_json_bytes,
+            );
             let entry = EventEntry {
                 transaction_digest: digest.base58_encode(),
                 event_index: idx as u64,
@@ -131,7 +140,8 @@ impl EventHandler {
                 module: transaction_module.to_string(),
                 event_type: type_.to_string(),
                 bcs: Base64::encode(contents.clone()),
-                event_json: event_json.to_string(),
+                event_json,
+                event_json_truncated,
             };
 
             state.events.push(entry);
@@ -139,3 +149,35 @@ impl EventHandler {
         Ok(())
     }
 }
+
+/// Returns the event's JSON, or a placeholder if it is larger than `max_json_bytes`, along with
+/// whether it was replaced.
+fn cap_event_json(event_json: String, max_json_bytes: Option<usize>) -> (String, bool) {
+    match max_json_bytes {
+        Some(max) if event_json.len() > max => (TRUNCATED_EVENT_JSON.to_string(), true),
+        _ => (event_json, false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cap_event_json() {
+        let event_json = r#"{"value":"1234567890"}"#.to_string();
+
+        assert_eq!(
+            cap_event_json(event_json.clone(), None),
+            (event_json.clone(), false)
+        );
+        assert_eq!(
+            cap_event_json(event_json.clone(), Some(event_json.len())),
+            (event_json.clone(), false)
+        );
+        assert_eq!(
+            cap_event_json(event_json.clone(), Some(event_json.len() - 1)),
+            (TRUNCATED_EVENT_JSON.to_string(), true)
+        );
+    }
+}
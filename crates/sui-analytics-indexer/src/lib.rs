@@ -158,6 +158,9 @@ pub struct AnalyticsIndexerConfig {
     pub sf_checkpoint_col_id: Option<String>,
     #[clap(long, global = true)]
     pub report_sf_max_table_checkpoint: bool,
+    // Maximum size of an event's JSON in bytes, above which a null placeholder is stored instead
+    #[clap(long, default_value = None, global = true)]
+    pub max_event_json_bytes: Option<usize>,
 }
 
 #[async_trait::async_trait]
@@ -715,6 +718,7 @@ pub async fn make_event_processor(
     let handler: Box<dyn AnalyticsHandler<EventEntry>> = Box::new(EventHandler::new(
         &config.package_cache_path,
         &config.rest_url,
+        config.max_event_json_bytes,
     ));
     let starting_checkpoint_seq_num =
         get_starting_checkpoint_seq_num(config.clone(), FileType::Event).await?;
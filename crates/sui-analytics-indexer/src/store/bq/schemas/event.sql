@@ -10,7 +10,8 @@ CREATE TABLE IF NOT EXISTS chaindata.EVENT
     module             STRING        NOT NULL,
     event_type         STRING        NOT NULL,
     bcs                STRING        NOT NULL,
-    event_json         JSON
+    event_json         JSON,
+    event_json_truncated BOOL        NOT NULL
 )
 PARTITION BY RANGE_BUCKET(epoch, GENERATE_ARRAY(0, 100000, 10))
 CLUSTER BY transaction_digest, event_index
\ No newline at end of file
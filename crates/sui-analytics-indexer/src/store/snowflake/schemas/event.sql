@@ -10,7 +10,8 @@ CREATE OR REPLACE TABLE EVENT
     module             STRING        NOT NULL,
     event_type         STRING        NOT NULL,
     bcs                STRING        NOT NULL,
-    event_json         VARIANT
+    event_json         VARIANT,
+    event_json_truncated BOOLEAN     NOT NULL
 ) STAGE_FILE_FORMAT = parquet_format
     STAGE_COPY_OPTIONS =
 (
@@ -44,7 +45,8 @@ CREATE
                          module,
                          event_type,
                          bcs,
-                         event_json)
+                         event_json,
+                         event_json_truncated)
             from (SELECT t.$1:transaction_digest     as transaction_digest,
                          t.$1:event_index            as event_index,
                          t.$1:checkpoint             as checkpoint,
@@ -55,6 +57,7 @@ CREATE
                          t.$1:module                 as module,
                          t.$1:event_type             as event_type,
                          t.$1:bcs                    as bcs,
-                         parse_json(t.$1:event_json) as event_json
+                         parse_json(t.$1:event_json) as event_json,
+                         t.$1:event_json_truncated   as event_json_truncated
                   from @events_parquet_stage (file_format => 'parquet_format', pattern => '.*[.]parquet') t)
             file_format = parquet_format;
\ No newline at end of file
@@ -122,6 +122,8 @@ pub(crate) struct EventEntry {
     // TODO: review and possibly move back to Vec<u8>
     pub(crate) bcs: String,
     pub(crate) event_json: String,
+    // Whether `event_json` was replaced with a placeholder for exceeding the size limit
+    pub(crate) event_json_truncated: bool,
 }
 
 // Used in the transaction object table to identify the type of input object.
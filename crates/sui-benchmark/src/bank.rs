@@ -6,13 +6,14 @@ use crate::workloads::payload::Payload;
 use crate::workloads::workload::{Workload, WorkloadBuilder, MAX_BUDGET};
 use crate::workloads::{Gas, GasCoinConfig};
 use crate::ValidatorProxy;
-use anyhow::{Error, Result};
+use anyhow::{anyhow, Error, Result};
 use itertools::Itertools;
 use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 use sui_core::test_utils::{make_pay_sui_transaction, make_transfer_sui_transaction};
 use sui_types::base_types::SuiAddress;
 use sui_types::crypto::AccountKeyPair;
+use sui_types::gas_coin::GasCoin;
 use tracing::info;
 
 /// Bank is used for generating gas for running the benchmark.
@@ -30,6 +31,23 @@ impl BenchmarkBank {
             primary_coin,
         }
     }
+
+    /// Fails if the primary gas coin holds less than `min_reserve` MIST, so that an
+    /// underfunded benchmark is rejected before any load is sent.
+    pub async fn check_primary_coin_reserve(&self, min_reserve: u64) -> Result<()> {
+        let object = self.proxy.get_object(self.primary_coin.0 .0).await?;
+        let balance = GasCoin::try_from(&object)?.value();
+        if balance < min_reserve {
+            return Err(anyhow!(
+                "Primary gas coin {} of {} has a balance of {balance} MIST, below the required reserve of {min_reserve} MIST",
+                self.primary_coin.0 .0,
+                self.primary_coin.1,
+            ));
+        }
+        info!("Primary gas coin balance: {balance} MIST, required reserve: {min_reserve} MIST");
+        Ok(())
+    }
+
     pub async fn generate(
         &mut self,
         builders: Vec<Box<dyn WorkloadBuilder<dyn Payload>>>,
@@ -91,6 +91,12 @@ async fn main() -> Result<()> {
     let cloned_barrier = barrier.clone();
     let env = if opts.local { Env::Local } else { Env::Remote };
     let bench_setup = env.setup(cloned_barrier, &registry, &opts).await?;
+    if let Some(min_reserve) = opts.required_gas_reserve() {
+        bench_setup
+            .bank
+            .check_primary_coin_reserve(min_reserve)
+            .await?;
+    }
     let system_state_observer = {
         // Only need to get system state from one proxy as it is shared for the
         // whole network.
@@ -178,6 +184,11 @@ async fn main() -> Result<()> {
                     eprintln!("Benchmark Report:");
                     eprintln!("{}", benchmark_table);
 
+                    if !benchmark_stats.num_errors_by_workload.is_empty() {
+                        eprintln!("Error Report:");
+                        eprintln!("{}", benchmark_stats.errors_to_table());
+                    }
+
                     if stress_stat_collection {
                         eprintln!("Stress Performance Report:");
                         let stress_stats_table = stress_stats.to_table();
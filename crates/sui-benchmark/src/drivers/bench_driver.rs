@@ -44,11 +44,12 @@ use tokio::{time, time::Instant};
 use tracing::{debug, error, info, warn};
 
 use super::Interval;
-use super::{BenchmarkStats, StressStats};
+use super::{BenchmarkStats, ErrorCategory, StressStats};
 pub struct BenchMetrics {
     pub benchmark_duration: IntGauge,
     pub num_success: IntCounterVec,
     pub num_error: IntCounterVec,
+    pub num_error_by_category: IntCounterVec,
     pub num_submitted: IntCounterVec,
     pub num_in_flight: GaugeVec,
     pub latency_s: HistogramVec,
@@ -93,6 +94,13 @@ impl BenchMetrics {
                 registry,
             )
             .unwrap(),
+            num_error_by_category: register_int_counter_vec_with_registry!(
+                "num_error_by_category",
+                "Total number of transaction errors by error category",
+                &["workload", "category"],
+                registry,
+            )
+            .unwrap(),
             num_submitted: register_int_counter_vec_with_registry!(
                 "num_submitted",
                 "Total number of transaction submitted to sui",
@@ -170,8 +178,8 @@ enum NextOp {
         payload: Box<dyn Payload>,
     },
     // The transaction failed and could not be retried
-    Failure,
-    Retry(RetryType),
+    Failure(ErrorCategory),
+    Retry(RetryType, ErrorCategory),
 }
 
 async fn print_and_start_benchmark() -> &'static Instant {
@@ -378,6 +386,7 @@ impl Driver<(BenchmarkStats, StressStats)> for BenchDriver {
                 latency_ms: HistogramWrapper {
                     histogram: hdrhistogram::Histogram::<u64>::new_with_max(120_000, 3).unwrap(),
                 },
+                num_errors_by_workload: BTreeMap::new(),
             };
             let mut stat_collection: BTreeMap<usize, Stats> = BTreeMap::new();
             let mut counter = 0;
@@ -414,6 +423,7 @@ impl Driver<(BenchmarkStats, StressStats)> for BenchDriver {
                 let mut num_in_flight: u64 = 0;
                 let mut num_submitted: u64 = 0;
                 let mut num_no_gas = 0;
+                let mut interval_errors = BenchmarkStats::default();
                 for (_, v) in stat_collection.iter() {
                     let duration = v.bench_stats.duration.as_secs() as f32;
 
@@ -430,6 +440,7 @@ impl Driver<(BenchmarkStats, StressStats)> for BenchDriver {
                     num_no_gas += v.num_no_gas;
                     num_submitted += v.num_submitted;
                     num_in_flight += v.num_in_flight;
+                    interval_errors.add_errors(&v.bench_stats.num_errors_by_workload);
                     latency_histogram
                         .add(&v.bench_stats.latency_ms.histogram)
                         .unwrap();
@@ -443,6 +454,12 @@ impl Driver<(BenchmarkStats, StressStats)> for BenchDriver {
                 counter += 1;
                 if counter % num_workers == 0 {
                     stat = format!("TPS = {}, CPS = {}, latency_ms(min/p50/p99/max) = {}/{}/{}/{}, num_success_tx = {}, num_error_tx = {}, num_success_cmds = {}, no_gas = {}, submitted = {}, in_flight = {}", total_qps, total_cps, latency_histogram.min(), latency_histogram.value_at_quantile(0.5), latency_histogram.value_at_quantile(0.99), latency_histogram.max(), num_success_txes, num_error_txes, num_success_cmds, num_no_gas, num_submitted, num_in_flight);
+                    if !interval_errors.num_errors_by_workload.is_empty() {
+                        stat = format!(
+                            "{stat}, errors = {:?}",
+                            interval_errors.num_errors_by_workload
+                        );
+                    }
                     if show_progress {
                         eprintln!("{}", stat);
                     }
@@ -686,6 +703,7 @@ async fn run_bench_worker(
     let mut num_in_flight: u64 = 0;
     let mut num_submitted = 0;
     let mut worker_gas_used = 0;
+    let mut num_errors_by_category: BTreeMap<ErrorCategory, u64> = BTreeMap::new();
 
     let mut latency_histogram = hdrhistogram::Histogram::<u64>::new_with_max(120_000, 3).unwrap();
     let mut request_interval = time::interval(Duration::from_micros(request_delay_micros));
@@ -696,6 +714,10 @@ async fn run_bench_worker(
 
     let group_benchmark_run_interval = worker.duration;
     let mut free_pool: VecDeque<_> = worker.payload.into_iter().collect();
+    let workload = free_pool
+        .front()
+        .map(|payload| payload.to_string())
+        .unwrap_or_default();
 
     let mut stat_start_time: Instant = Instant::now();
 
@@ -760,6 +782,11 @@ async fn run_bench_worker(
             }
             Err(err) => {
                 error!("{}", err);
+                let category = ErrorCategory::classify(&err);
+                metrics_cloned
+                    .num_error_by_category
+                    .with_label_values(&[&payload.to_string(), &category.to_string()])
+                    .inc();
                 if err
                     .downcast::<QuorumDriverError>()
                     .and_then(|err| {
@@ -774,13 +801,13 @@ async fn run_bench_worker(
                     })
                     .is_err()
                 {
-                    NextOp::Failure
+                    NextOp::Failure(category)
                 } else {
                     metrics_cloned
                         .num_error
                         .with_label_values(&[&payload.to_string()])
                         .inc();
-                    NextOp::Retry(Box::new((transaction, payload)))
+                    NextOp::Retry(Box::new((transaction, payload)), category)
                 }
             }
         }
@@ -839,7 +866,8 @@ async fn run_bench_worker(
                             latency_ms:HistogramWrapper{
                                 histogram:latency_histogram.clone()
                             },
-                            total_gas_used: worker_gas_used
+                            total_gas_used: worker_gas_used,
+                            num_errors_by_workload: errors_by_workload(&workload, &num_errors_by_category),
                         },
                     })
                     .is_err()
@@ -852,6 +880,7 @@ async fn run_bench_worker(
                 num_no_gas = 0;
                 num_submitted = 0;
                 worker_gas_used = 0;
+                num_errors_by_category.clear();
                 stat_start_time = Instant::now();
                 latency_histogram.reset();
             }
@@ -905,17 +934,19 @@ async fn run_bench_worker(
             }
             Some(op) = futures.next() => {
                 match op {
-                    NextOp::Retry(b) => {
+                    NextOp::Retry(b, category) => {
                         retry_queue.push_back(b);
+                        *num_errors_by_category.entry(category).or_default() += 1;
 
                         // Update total benchmark progress
                         if update_progress(1) {
                             break;
                         }
                     }
-                    NextOp::Failure => {
+                    NextOp::Failure(category) => {
                         error!("Permanent failure to execute payload. May result in gas objects being leaked");
                         num_error_txes += 1;
+                        *num_errors_by_category.entry(category).or_default() += 1;
                         // Update total benchmark progress
                         if update_progress(1) {
                             break;
@@ -957,6 +988,7 @@ async fn run_bench_worker(
                 latency_ms: HistogramWrapper {
                     histogram: latency_histogram,
                 },
+                num_errors_by_workload: errors_by_workload(&workload, &num_errors_by_category),
             },
         })
         .is_err()
@@ -972,7 +1004,7 @@ async fn run_bench_worker(
     );
     while let Some(result) = futures.next().await {
         let p = match result {
-            NextOp::Failure => {
+            NextOp::Failure(_) => {
                 error!(
                     "Permanent failure to execute payload. May result in gas objects being leaked"
                 );
@@ -984,7 +1016,7 @@ async fn run_bench_worker(
                 gas_used: _,
                 payload,
             } => payload,
-            NextOp::Retry(b) => b.1,
+            NextOp::Retry(b, _) => b.1,
         };
         free_pool.push_back(p);
     }
@@ -996,6 +1028,17 @@ async fn run_bench_worker(
     Some(worker)
 }
 
+fn errors_by_workload(
+    workload: &str,
+    num_errors_by_category: &BTreeMap<ErrorCategory, u64>,
+) -> BTreeMap<String, BTreeMap<ErrorCategory, u64>> {
+    if num_errors_by_category.is_empty() {
+        BTreeMap::new()
+    } else {
+        BTreeMap::from([(workload.to_string(), num_errors_by_category.clone())])
+    }
+}
+
 /// Creates a new progress bar based on the provided duration. The method is agnostic to the actual
 /// usage - weather we want to track the overall benchmark duration or an individual benchmark run.
 fn create_progress_bar(duration: Interval) -> ProgressBar {
@@ -2,8 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use duration_str::parse;
+use std::collections::BTreeMap;
 use std::fmt::Formatter;
 use std::{str::FromStr, time::Duration};
+use sui_types::error::{SuiError, UserInputError};
+use sui_types::quorum_driver_types::QuorumDriverError;
 
 pub mod bench_driver;
 pub mod driver;
@@ -53,6 +56,76 @@ impl std::fmt::Display for Interval {
     }
 }
 
+/// Coarse category of a failed transaction, used to break down errors per workload.
+#[derive(
+    Debug, Clone, Copy, serde::Serialize, serde::Deserialize, Eq, PartialEq, Ord, PartialOrd,
+)]
+pub enum ErrorCategory {
+    Gas,
+    ObjectNotFound,
+    Quorum,
+    Timeout,
+    Other,
+}
+
+impl ErrorCategory {
+    pub fn classify(err: &anyhow::Error) -> Self {
+        if let Some(err) = err.downcast_ref::<QuorumDriverError>() {
+            return match err {
+                QuorumDriverError::NonRecoverableTransactionError { errors } => errors
+                    .first()
+                    .map(|(err, _, _)| Self::from_sui_error(err))
+                    .unwrap_or(Self::Quorum),
+                QuorumDriverError::QuorumDriverInternalError(err) => Self::from_sui_error(err),
+                QuorumDriverError::TimeoutBeforeFinality => Self::Timeout,
+                _ => Self::Quorum,
+            };
+        }
+        if let Some(err) = err.downcast_ref::<SuiError>() {
+            return Self::from_sui_error(err);
+        }
+        Self::Other
+    }
+
+    fn from_sui_error(err: &SuiError) -> Self {
+        match err {
+            SuiError::UserInputError { error } => match error {
+                UserInputError::MissingGasPayment
+                | UserInputError::GasObjectNotOwnedObject { .. }
+                | UserInputError::GasBudgetTooHigh { .. }
+                | UserInputError::GasBudgetTooLow { .. }
+                | UserInputError::GasBalanceTooLow { .. }
+                | UserInputError::GasPriceUnderRGP { .. }
+                | UserInputError::GasPriceTooHigh { .. }
+                | UserInputError::InvalidGasObject { .. }
+                | UserInputError::InsufficientBalanceToCoverMinimalGas => Self::Gas,
+                UserInputError::ObjectNotFound { .. }
+                | UserInputError::ObjectVersionUnavailableForConsumption { .. }
+                | UserInputError::ObjectDeleted { .. }
+                | UserInputError::DependentPackageNotFound { .. } => Self::ObjectNotFound,
+                _ => Self::Other,
+            },
+            SuiError::TimeoutError => Self::Timeout,
+            SuiError::QuorumFailedToGetEffectsQuorumWhenProcessingTransaction { .. }
+            | SuiError::CertificateRequiresQuorum
+            | SuiError::QuorumDriverCommunicationError { .. } => Self::Quorum,
+            _ => Self::Other,
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorCategory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ErrorCategory::Gas => "gas",
+            ErrorCategory::ObjectNotFound => "object_not_found",
+            ErrorCategory::Quorum => "quorum",
+            ErrorCategory::Timeout => "timeout",
+            ErrorCategory::Other => "other",
+        })
+    }
+}
+
 // wrapper which implements serde
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -131,6 +204,9 @@ pub struct BenchmarkStats {
     /// Total gas used
     pub total_gas_used: u64,
     pub latency_ms: HistogramWrapper,
+    /// Number of failed transactions per workload and error category
+    #[serde(default)]
+    pub num_errors_by_workload: BTreeMap<String, BTreeMap<ErrorCategory, u64>>,
 }
 
 impl BenchmarkStats {
@@ -144,7 +220,39 @@ impl BenchmarkStats {
             .histogram
             .add(&sample_stat.latency_ms.histogram)
             .unwrap();
+        self.add_errors(&sample_stat.num_errors_by_workload);
     }
+
+    pub fn add_errors(&mut self, errors: &BTreeMap<String, BTreeMap<ErrorCategory, u64>>) {
+        for (workload, categories) in errors {
+            let counts = self
+                .num_errors_by_workload
+                .entry(workload.clone())
+                .or_default();
+            for (category, count) in categories {
+                *counts.entry(*category).or_default() += count;
+            }
+        }
+    }
+
+    pub fn errors_to_table(&self) -> Table {
+        let mut table = Table::new();
+        table
+            .set_content_arrangement(ContentArrangement::Dynamic)
+            .set_width(200)
+            .set_header(vec!["workload", "error category", "count"]);
+        for (workload, categories) in &self.num_errors_by_workload {
+            for (category, count) in categories {
+                let mut row = Row::new();
+                row.add_cell(Cell::new(workload));
+                row.add_cell(Cell::new(category));
+                row.add_cell(Cell::new(count));
+                table.add_row(row);
+            }
+        }
+        table
+    }
+
     pub fn to_table(&self) -> Table {
         let mut table = Table::new();
         table
@@ -109,6 +109,34 @@ pub struct Opts {
     /// built at the same commit as the validators.
     #[clap(long, global = true)]
     pub protocol_version: Option<u64>,
+
+    /// Minimum balance, in MIST, the primary gas coin must hold for the benchmark to start.
+    /// If unset, it is estimated from the target qps and the run duration, and no check is
+    /// done for unbounded runs.
+    #[clap(long, global = true)]
+    pub min_gas_reserve: Option<u64>,
+}
+
+/// Conservative estimate of the gas, in MIST, used by a single benchmark transaction.
+pub const ESTIMATED_GAS_PER_TRANSACTION: u64 = 5_000_000;
+
+impl Opts {
+    /// Minimum balance the primary gas coin must hold to run the benchmark: `min_gas_reserve`
+    /// if set, otherwise an estimate for bounded runs.
+    pub fn required_gas_reserve(&self) -> Option<u64> {
+        self.min_gas_reserve.or_else(|| {
+            let num_transactions = match self.run_duration {
+                Interval::Count(count) => count,
+                interval if interval.is_unbounded() => return None,
+                Interval::Time(duration) => {
+                    let RunSpec::Bench { target_qps, .. } = &self.run_spec;
+                    let max_qps = target_qps.iter().copied().max().unwrap_or_default();
+                    max_qps.saturating_mul(duration.as_secs().max(1))
+                }
+            };
+            Some(num_transactions.saturating_mul(ESTIMATED_GAS_PER_TRANSACTION))
+        })
+    }
 }
 
 #[derive(Debug, Clone, Parser, Eq, PartialEq, EnumString)]
@@ -3,8 +3,9 @@
 
 #[cfg(msim)]
 mod test {
+    use clap::Parser;
     use rand::{distributions::uniform::SampleRange, thread_rng, Rng};
-    use std::collections::HashSet;
+    use std::collections::{BTreeMap, HashSet};
     use std::path::PathBuf;
     use std::str::FromStr;
     use std::sync::atomic::{AtomicBool, Ordering};
@@ -15,7 +16,10 @@ mod test {
     use sui_benchmark::workloads::adversarial::AdversarialPayloadCfg;
     use sui_benchmark::workloads::workload_configuration::WorkloadConfiguration;
     use sui_benchmark::{
-        drivers::{bench_driver::BenchDriver, driver::Driver, Interval},
+        drivers::{
+            bench_driver::BenchDriver, driver::Driver, BenchmarkStats, ErrorCategory, Interval,
+        },
+        options::Opts,
         util::get_ed25519_keypair_from_keystore,
         LocalValidatorAggregatorProxy, ValidatorProxy,
     };
@@ -37,6 +41,7 @@ mod test {
     use sui_surfer::surf_strategy::SurfStrategy;
     use sui_types::full_checkpoint_content::CheckpointData;
     use sui_types::messages_checkpoint::VerifiedCheckpoint;
+    use sui_types::transaction::{Transaction, TransactionData};
     use test_cluster::{TestCluster, TestClusterBuilder};
     use tracing::{error, info, trace};
     use typed_store::traits::Map;
@@ -98,6 +103,102 @@ mod test {
         test_simulated_load(test_cluster, 15).await;
     }
 
+    #[sim_test(config = "test_config()")]
+    async fn test_underfunded_primary_gas_rejected() {
+        let test_cluster = build_test_cluster(4, 0).await;
+        let sender = test_cluster.get_address_0();
+        let keystore_path = test_cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+        let genesis = test_cluster.swarm.config().genesis.clone();
+        let primary_gas = test_cluster
+            .wallet
+            .get_one_gas_object_owned_by_address(sender)
+            .await
+            .unwrap()
+            .unwrap();
+        let ed25519_keypair =
+            Arc::new(get_ed25519_keypair_from_keystore(keystore_path, &sender).unwrap());
+
+        let registry = prometheus::Registry::new();
+        let proxy: Arc<dyn ValidatorProxy + Send + Sync> =
+            Arc::new(LocalValidatorAggregatorProxy::from_genesis(&genesis, &registry, None).await);
+        let bank = BenchmarkBank::new(proxy, (primary_gas, sender, ed25519_keypair));
+
+        // A long run at a high rate needs more gas than the primary coin holds.
+        let opts = Opts::parse_from([
+            "stress",
+            "--local",
+            "true",
+            "--run-duration",
+            "100000h",
+            "bench",
+            "--target-qps",
+            "100000",
+        ]);
+        let min_reserve = opts.required_gas_reserve().unwrap();
+        let err = bank
+            .check_primary_coin_reserve(min_reserve)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("below the required reserve"));
+
+        // An explicit reserve overrides the estimate.
+        let opts = Opts::parse_from([
+            "stress",
+            "--min-gas-reserve",
+            "1000",
+            "--run-duration",
+            "1000000",
+            "bench",
+        ]);
+        assert_eq!(opts.required_gas_reserve(), Some(1000));
+        bank.check_primary_coin_reserve(1000).await.unwrap();
+
+        // No reserve is estimated for unbounded runs.
+        let opts = Opts::parse_from(["stress", "bench"]);
+        assert_eq!(opts.required_gas_reserve(), None);
+    }
+
+    #[sim_test(config = "test_config()")]
+    async fn test_under_gassed_transaction_error_category() {
+        let test_cluster = build_test_cluster(4, 0).await;
+        let sender = test_cluster.get_address_0();
+        let keystore_path = test_cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
+        let genesis = test_cluster.swarm.config().genesis.clone();
+        let gas = test_cluster
+            .wallet
+            .get_one_gas_object_owned_by_address(sender)
+            .await
+            .unwrap()
+            .unwrap();
+        let keypair = get_ed25519_keypair_from_keystore(keystore_path, &sender).unwrap();
+        let gas_price = test_cluster.get_reference_gas_price().await;
+
+        let registry = prometheus::Registry::new();
+        let proxy = LocalValidatorAggregatorProxy::from_genesis(&genesis, &registry, None).await;
+
+        // A gas budget of one unit is below the minimum budget.
+        let tx = Transaction::from_data_and_signer(
+            TransactionData::new_transfer_sui(sender, sender, None, gas, 1, gas_price),
+            vec![&keypair],
+        );
+        let err = proxy.execute_transaction_block(tx).await.unwrap_err();
+        let category = ErrorCategory::classify(&err);
+        assert_eq!(category, ErrorCategory::Gas);
+
+        // Errors reported by a worker accumulate in the bucket of their workload and category.
+        let worker_errors = BTreeMap::from([(
+            "transfer_object".to_string(),
+            BTreeMap::from([(category, 1)]),
+        )]);
+        let mut stats = BenchmarkStats::default();
+        stats.add_errors(&worker_errors);
+        stats.add_errors(&worker_errors);
+        assert_eq!(
+            stats.num_errors_by_workload["transfer_object"][&ErrorCategory::Gas],
+            2
+        );
+    }
+
     #[sim_test(config = "test_config()")]
     async fn test_simulated_load_restarts() {
         sui_protocol_config::ProtocolConfig::poison_get_for_min_version();
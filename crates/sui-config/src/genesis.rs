@@ -373,7 +373,10 @@ pub struct GenesisCeremonyParameters {
     pub chain_start_timestamp_ms: u64,
 
     /// protocol version that the chain starts at.
-    #[serde(default = "ProtocolVersion::max")]
+    #[serde(
+        default = "ProtocolVersion::max",
+        deserialize_with = "ProtocolVersion::deserialize_supported"
+    )]
     pub protocol_version: ProtocolVersion,
 
     #[serde(default = "GenesisCeremonyParameters::default_allow_insertion_of_extra_objects")]
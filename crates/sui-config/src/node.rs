@@ -452,10 +452,54 @@ pub struct CheckpointExecutorConfig {
     #[serde(default = "default_local_execution_timeout_sec")]
     pub local_execution_timeout_sec: u64,
 
+    /// While a checkpoint keeps failing to execute, its first failed attempt is logged at error
+    /// level, and then only one in every `retry_error_log_interval` attempts. Every attempt is
+    /// still counted in the `checkpoint_exec_errors` metric.
+    ///
+    /// If unspecified, this will default to `10`.
+    #[serde(default = "default_retry_error_log_interval")]
+    pub retry_error_log_interval: u64,
+
     /// Optional directory used for data ingestion pipeline
     /// When specified, each executed checkpoint will be saved in a local directory for post processing
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_ingestion_dir: Option<PathBuf>,
+
+    /// Optional number of worker threads of a dedicated runtime to execute checkpoints on.
+    /// When specified, checkpoint execution tasks are spawned onto that runtime instead of
+    /// the node's main runtime, so that catching up does not starve other work such as RPC.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dedicated_runtime_threads: Option<usize>,
+
+    /// If enabled, emits a debug-level span for every transaction of an executed checkpoint,
+    /// lasting until the transaction has executed and carrying its digest and gas cost breakdown.
+    /// Intended for debugging; off by default.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub trace_transaction_execution: bool,
+
+    /// Optional adaptive concurrency control. When specified, the number of checkpoints executed
+    /// concurrently is adjusted between `min_concurrency` and
+    /// `checkpoint_execution_max_concurrency` based on observed checkpoint execution latency.
+    /// Otherwise `checkpoint_execution_max_concurrency` is used as a fixed limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adaptive_concurrency: Option<AdaptiveConcurrencyConfig>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct AdaptiveConcurrencyConfig {
+    /// Lower bound on the number of checkpoints that can be concurrently executed.
+    ///
+    /// If unspecified, this will default to `10`.
+    #[serde(default = "default_adaptive_min_concurrency")]
+    pub min_concurrency: usize,
+
+    /// Checkpoint execution latency in milliseconds to stay under. Concurrency is raised by one
+    /// for each checkpoint executed faster than this, and halved for each one that is slower.
+    ///
+    /// If unspecified, this will default to `1000`.
+    #[serde(default = "default_adaptive_target_latency_ms")]
+    pub target_latency_ms: u64,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -550,12 +594,37 @@ fn default_local_execution_timeout_sec() -> u64 {
     30
 }
 
+fn default_retry_error_log_interval() -> u64 {
+    10
+}
+
+fn default_adaptive_min_concurrency() -> usize {
+    10
+}
+
+fn default_adaptive_target_latency_ms() -> u64 {
+    1000
+}
+
+impl Default for AdaptiveConcurrencyConfig {
+    fn default() -> Self {
+        Self {
+            min_concurrency: default_adaptive_min_concurrency(),
+            target_latency_ms: default_adaptive_target_latency_ms(),
+        }
+    }
+}
+
 impl Default for CheckpointExecutorConfig {
     fn default() -> Self {
         Self {
             checkpoint_execution_max_concurrency: default_checkpoint_execution_max_concurrency(),
             local_execution_timeout_sec: default_local_execution_timeout_sec(),
+            retry_error_log_interval: default_retry_error_log_interval(),
             data_ingestion_dir: None,
+            dedicated_runtime_threads: None,
+            trace_transaction_execution: false,
+            adaptive_concurrency: None,
         }
     }
 }
@@ -211,7 +211,13 @@ pub enum AggregatorProcessTransactionError {
         "Failed to execute transaction on a quorum of validators but state is still retryable. Validator errors: {:?}",
         errors
     )]
-    RetryableTransaction { errors: GroupedErrors },
+    RetryableTransaction {
+        errors: GroupedErrors,
+        /// Stake of the validators that signed the transaction or its effects.
+        collected_stake: StakeUnit,
+        /// Outcome of the request to each validator that responded.
+        responses: Vec<(AuthorityName, Result<(), SuiError>)>,
+    },
 
     #[error(
         "Failed to execute transaction on a quorum of validators due to conflicting transactions. Locked objects: {:?}. Validator errors: {:?}",
@@ -341,6 +347,8 @@ struct ProcessTransactionState {
     effects_map: MultiStakeAggregator<TransactionEffectsDigest, TransactionEffects, true>,
     // The list of errors gathered at any point
     errors: Vec<(SuiError, Vec<AuthorityName>, StakeUnit)>,
+    // The outcome of the request to each validator that responded
+    responses: Vec<(AuthorityName, Result<(), SuiError>)>,
     // This is exclusively non-retryable stake.
     non_retryable_stake: StakeUnit,
     // This includes both object and package not found sui errors.
@@ -1082,6 +1090,7 @@ where
             tx_signatures: StakeAggregator::new(committee.clone()),
             effects_map: MultiStakeAggregator::new(committee.clone()),
             errors: vec![],
+            responses: vec![],
             object_or_package_not_found_stake: 0,
             non_retryable_stake: 0,
             overloaded_stake: 0,
@@ -1119,8 +1128,9 @@ where
                                 self.record_process_transaction_metrics(tx_digest, &state);
                                 return ReduceOutput::Success(result);
                             }
-                            Ok(None) => {},
+                            Ok(None) => state.responses.push((name, Ok(()))),
                             Err(err) => {
+                                state.responses.push((name, Err(err.clone())));
                                 let concise_name = name.concise();
                                 debug!(?tx_digest, name=?concise_name, weight, "Error processing transaction from validator: {:?}", err);
                                 self.metrics
@@ -1319,6 +1329,11 @@ where
 
         // No conflicting transaction, the system is not overloaded and transaction state is still retryable.
         AggregatorProcessTransactionError::RetryableTransaction {
+            collected_stake: std::cmp::max(
+                state.tx_signatures.total_votes(),
+                state.effects_map.total_votes(),
+            ),
+            responses: state.responses,
             errors: group_errors(state.errors),
         }
     }
@@ -1812,6 +1827,7 @@ where
                                 input_objects: state.input_objects.take(),
                                 output_objects: state.output_objects.take(),
                                 auxiliary_data: state.auxiliary_data.take(),
+                                certificate_signers: None,
                             })
                         })
                     }
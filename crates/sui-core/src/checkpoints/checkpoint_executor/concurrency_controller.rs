@@ -0,0 +1,121 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use prometheus::IntGauge;
+use sui_config::node::CheckpointExecutorConfig;
+
+/// Decides how many checkpoints may be executed concurrently. The limit is fixed at
+/// `checkpoint_execution_max_concurrency` unless adaptive concurrency is configured, in which
+/// case it is adjusted with additive increase / multiplicative decrease: raised by one for every
+/// checkpoint executed under the target latency, and halved for every one over it.
+pub(crate) struct ConcurrencyController {
+    limit: usize,
+    adaptive: Option<AdaptiveLimits>,
+    limit_gauge: IntGauge,
+}
+
+struct AdaptiveLimits {
+    min: usize,
+    max: usize,
+    target_latency: Duration,
+}
+
+impl ConcurrencyController {
+    pub(crate) fn new(config: &CheckpointExecutorConfig, limit_gauge: IntGauge) -> Self {
+        let max = config.checkpoint_execution_max_concurrency;
+        let adaptive = config
+            .adaptive_concurrency
+            .as_ref()
+            .map(|adaptive| AdaptiveLimits {
+                min: adaptive.min_concurrency.clamp(1, max),
+                max,
+                target_latency: Duration::from_millis(adaptive.target_latency_ms),
+            });
+        // Adaptive concurrency starts low and ramps up while latency allows.
+        let limit = adaptive.as_ref().map_or(max, |adaptive| adaptive.min);
+        limit_gauge.set(limit as i64);
+        Self {
+            limit,
+            adaptive,
+            limit_gauge,
+        }
+    }
+
+    pub(crate) fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Adjusts the limit given how long a checkpoint took to execute.
+    pub(crate) fn record_latency(&mut self, latency: Duration) {
+        let Some(adaptive) = &self.adaptive else {
+            return;
+        };
+        self.limit = if latency <= adaptive.target_latency {
+            (self.limit + 1).min(adaptive.max)
+        } else {
+            (self.limit / 2).max(adaptive.min)
+        };
+        self.limit_gauge.set(self.limit as i64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sui_config::node::AdaptiveConcurrencyConfig;
+
+    fn controller(
+        adaptive_concurrency: Option<AdaptiveConcurrencyConfig>,
+    ) -> ConcurrencyController {
+        let config = CheckpointExecutorConfig {
+            checkpoint_execution_max_concurrency: 8,
+            adaptive_concurrency,
+            ..Default::default()
+        };
+        ConcurrencyController::new(&config, IntGauge::new("limit", "limit").unwrap())
+    }
+
+    #[test]
+    fn static_limit_ignores_latency() {
+        let mut controller = controller(None);
+        assert_eq!(controller.limit(), 8);
+        controller.record_latency(Duration::from_secs(60));
+        assert_eq!(controller.limit(), 8);
+    }
+
+    #[test]
+    fn adaptive_limit_follows_latency() {
+        let mut controller = controller(Some(AdaptiveConcurrencyConfig {
+            min_concurrency: 2,
+            target_latency_ms: 100,
+        }));
+        assert_eq!(controller.limit(), 2);
+        assert_eq!(controller.limit_gauge.get(), 2);
+
+        let fast = Duration::from_millis(50);
+        let slow = Duration::from_millis(150);
+
+        // Fast checkpoints raise the limit one at a time, up to the maximum.
+        let mut limits = vec![];
+        for _ in 0..8 {
+            controller.record_latency(fast);
+            limits.push(controller.limit());
+        }
+        assert_eq!(limits, vec![3, 4, 5, 6, 7, 8, 8, 8]);
+
+        // Slow checkpoints halve it, down to the minimum.
+        let mut limits = vec![];
+        for _ in 0..3 {
+            controller.record_latency(slow);
+            limits.push(controller.limit());
+        }
+        assert_eq!(limits, vec![4, 2, 2]);
+        assert_eq!(controller.limit_gauge.get(), 2);
+
+        // And it recovers once latency drops again.
+        controller.record_latency(fast);
+        assert_eq!(controller.limit(), 3);
+    }
+}
@@ -13,8 +13,10 @@ pub struct CheckpointExecutorMetrics {
     pub last_executed_checkpoint: IntGauge,
     pub last_executed_checkpoint_timestamp_ms: IntGauge,
     pub checkpoint_exec_errors: IntCounter,
+    pub checkpoint_exec_missing_effects_timeouts: IntCounter,
     pub checkpoint_exec_epoch: IntGauge,
     pub checkpoint_exec_inflight: IntGauge,
+    pub checkpoint_exec_concurrency_limit: IntGauge,
     pub checkpoint_exec_latency_us: Histogram,
     pub checkpoint_prepare_latency_us: Histogram,
     pub checkpoint_transaction_count: Histogram,
@@ -50,6 +52,12 @@ impl CheckpointExecutorMetrics {
                 registry
             )
             .unwrap(),
+            checkpoint_exec_missing_effects_timeouts: register_int_counter_with_registry!(
+                "checkpoint_exec_missing_effects_timeouts",
+                "Number of local execution timeouts reached while the next checkpoint to execute had missing effects",
+                registry
+            )
+            .unwrap(),
             checkpoint_exec_epoch: register_int_gauge_with_registry!(
                 "checkpoint_exec_epoch",
                 "Current epoch number in the checkpoint executor",
@@ -62,6 +70,12 @@ impl CheckpointExecutorMetrics {
                 registry
             )
             .unwrap(),
+            checkpoint_exec_concurrency_limit: register_int_gauge_with_registry!(
+                "checkpoint_exec_concurrency_limit",
+                "Current limit on the number of checkpoints being executed concurrently",
+                registry
+            )
+            .unwrap(),
             checkpoint_exec_latency_us: Histogram::new_in_registry(
                 "checkpoint_exec_latency_us",
                 "Latency of executing a checkpoint from enqueue to all effects available, in microseconds",
@@ -21,16 +21,19 @@
 use std::path::PathBuf;
 use std::{
     collections::HashMap,
+    future::Future,
     sync::Arc,
     time::{Duration, Instant},
 };
 
-use futures::stream::FuturesOrdered;
+use fastcrypto::encoding::{Encoding, Hex};
+use futures::{future::join_all, stream::FuturesOrdered};
 use itertools::izip;
-use mysten_metrics::{spawn_monitored_task, MonitoredFutureExt};
+use mysten_metrics::{monitored_future, spawn_monitored_task, MonitoredFutureExt};
 use prometheus::Registry;
 use sui_config::node::{CheckpointExecutorConfig, RunWithRange};
 use sui_macros::{fail_point, fail_point_async};
+use sui_types::committee::Committee;
 use sui_types::crypto::RandomnessRound;
 use sui_types::effects::{TransactionEffects, TransactionEffectsAPI};
 use sui_types::executable_transaction::VerifiedExecutableTransaction;
@@ -44,13 +47,15 @@ use sui_types::{
 use sui_types::{error::SuiResult, transaction::TransactionDataAPI};
 use tap::{TapFallible, TapOptional};
 use tokio::{
+    runtime::{Handle, Runtime},
     sync::broadcast::{self, error::RecvError},
     task::JoinHandle,
     time::timeout,
 };
 use tokio_stream::StreamExt;
-use tracing::{debug, error, info, instrument, trace, warn};
+use tracing::{debug, debug_span, error, field, info, instrument, trace, warn, Instrument};
 
+use self::concurrency_controller::ConcurrencyController;
 use self::metrics::CheckpointExecutorMetrics;
 use crate::authority::authority_per_epoch_store::AuthorityPerEpochStore;
 use crate::authority::AuthorityState;
@@ -62,17 +67,23 @@ use crate::{
     execution_cache::{ObjectCacheRead, TransactionCacheRead},
 };
 
+mod concurrency_controller;
 mod data_ingestion_handler;
 mod metrics;
 #[cfg(test)]
 pub(crate) mod tests;
 
+/// Checkpoints being executed, each resolving to its transaction digests and how long it took to
+/// execute.
 type CheckpointExecutionBuffer =
-    FuturesOrdered<JoinHandle<(VerifiedCheckpoint, Vec<TransactionDigest>)>>;
+    FuturesOrdered<JoinHandle<(VerifiedCheckpoint, Vec<TransactionDigest>, Duration)>>;
 
 /// The interval to log checkpoint progress, in # of checkpoints processed.
 const CHECKPOINT_PROGRESS_LOG_COUNT_INTERVAL: u64 = 5000;
 
+/// The max number of missing transaction digests to include when logging a stuck checkpoint.
+const MAX_LOGGED_MISSING_DIGESTS: usize = 10;
+
 #[derive(Debug, Clone, Copy)]
 pub struct CheckpointTimeoutConfig {
     pub timeout: Duration,
@@ -117,6 +128,76 @@ fn get_scheduling_timeout() -> CheckpointTimeoutConfig {
     SCHEDULING_TIMEOUT.with(|s| *s.get_or_init(inner))
 }
 
+/// Runtime dedicated to executing checkpoints, see
+/// `CheckpointExecutorConfig::dedicated_runtime_threads`.
+struct DedicatedRuntime(Option<Runtime>);
+
+impl DedicatedRuntime {
+    fn new(worker_threads: usize) -> Self {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(worker_threads)
+            .thread_name("checkpoint-executor")
+            .enable_all()
+            .build()
+            .expect("Failed to build checkpoint executor runtime");
+        Self(Some(runtime))
+    }
+
+    fn handle(&self) -> &Handle {
+        self.0
+            .as_ref()
+            .expect("runtime is only taken on drop")
+            .handle()
+    }
+}
+
+impl Drop for DedicatedRuntime {
+    fn drop(&mut self) {
+        // Dropping a runtime blocks, which is not allowed from within an async context.
+        if let Some(runtime) = self.0.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
+
+/// Persistence for the highest executed checkpoint watermark, which `CheckpointExecutor` reads
+/// on startup to decide where to resume execution, and advances as checkpoints finish executing.
+/// Defaults to the watermark kept in `CheckpointStore`, which is also what other node components
+/// read, so an alternative store is only appropriate where the executor is the sole consumer.
+pub trait WatermarkStore: Send + Sync {
+    fn get_highest_executed_checkpoint(&self) -> SuiResult<Option<VerifiedCheckpoint>>;
+
+    fn update_highest_executed_checkpoint(&self, checkpoint: &VerifiedCheckpoint) -> SuiResult;
+
+    fn get_highest_executed_checkpoint_seq_number(
+        &self,
+    ) -> SuiResult<Option<CheckpointSequenceNumber>> {
+        Ok(self
+            .get_highest_executed_checkpoint()?
+            .map(|checkpoint| *checkpoint.sequence_number()))
+    }
+}
+
+impl WatermarkStore for CheckpointStore {
+    fn get_highest_executed_checkpoint(&self) -> SuiResult<Option<VerifiedCheckpoint>> {
+        Ok(CheckpointStore::get_highest_executed_checkpoint(self)?)
+    }
+
+    fn update_highest_executed_checkpoint(&self, checkpoint: &VerifiedCheckpoint) -> SuiResult {
+        Ok(CheckpointStore::update_highest_executed_checkpoint(
+            self, checkpoint,
+        )?)
+    }
+
+    fn get_highest_executed_checkpoint_seq_number(
+        &self,
+    ) -> SuiResult<Option<CheckpointSequenceNumber>> {
+        Ok(CheckpointStore::get_highest_executed_checkpoint_seq_number(
+            self,
+        )?)
+    }
+}
+
 #[derive(PartialEq, Eq, Debug)]
 pub enum StopReason {
     EpochComplete,
@@ -129,12 +210,15 @@ pub struct CheckpointExecutor {
     // once that code is fully deprecated we can remove this
     state: Arc<AuthorityState>,
     checkpoint_store: Arc<CheckpointStore>,
+    watermark_store: Arc<dyn WatermarkStore>,
     object_cache_reader: Arc<dyn ObjectCacheRead>,
     transaction_cache_reader: Arc<dyn TransactionCacheRead>,
     tx_manager: Arc<TransactionManager>,
     accumulator: Arc<StateAccumulator>,
     config: CheckpointExecutorConfig,
     metrics: Arc<CheckpointExecutorMetrics>,
+    runtime: Option<DedicatedRuntime>,
+    concurrency: ConcurrencyController,
 }
 
 impl CheckpointExecutor {
@@ -146,16 +230,23 @@ impl CheckpointExecutor {
         config: CheckpointExecutorConfig,
         prometheus_registry: &Registry,
     ) -> Self {
+        let metrics = CheckpointExecutorMetrics::new(prometheus_registry);
         Self {
             mailbox,
             state: state.clone(),
+            watermark_store: checkpoint_store.clone(),
             checkpoint_store,
             object_cache_reader: state.get_object_cache_reader().clone(),
             transaction_cache_reader: state.get_transaction_cache_reader().clone(),
             tx_manager: state.transaction_manager().clone(),
             accumulator,
+            runtime: Self::dedicated_runtime(&config),
+            concurrency: ConcurrencyController::new(
+                &config,
+                metrics.checkpoint_exec_concurrency_limit.clone(),
+            ),
             config,
-            metrics: CheckpointExecutorMetrics::new(prometheus_registry),
+            metrics,
         }
     }
 
@@ -165,17 +256,42 @@ impl CheckpointExecutor {
         state: Arc<AuthorityState>,
         accumulator: Arc<StateAccumulator>,
     ) -> Self {
+        let config = CheckpointExecutorConfig::default();
+        let metrics = CheckpointExecutorMetrics::new_for_tests();
         Self {
             mailbox,
             state: state.clone(),
+            watermark_store: checkpoint_store.clone(),
             checkpoint_store,
             object_cache_reader: state.get_object_cache_reader().clone(),
             transaction_cache_reader: state.get_transaction_cache_reader().clone(),
             tx_manager: state.transaction_manager().clone(),
             accumulator,
-            config: Default::default(),
-            metrics: CheckpointExecutorMetrics::new_for_tests(),
+            concurrency: ConcurrencyController::new(
+                &config,
+                metrics.checkpoint_exec_concurrency_limit.clone(),
+            ),
+            config,
+            metrics,
+            runtime: None,
+        }
+    }
+
+    /// Persist the highest executed checkpoint watermark in `watermark_store` instead of the
+    /// `CheckpointStore`. Execution resumes from the watermark found there on startup.
+    pub fn with_watermark_store(mut self, watermark_store: Arc<dyn WatermarkStore>) -> Self {
+        self.watermark_store = watermark_store;
+        self
+    }
+
+    fn dedicated_runtime(config: &CheckpointExecutorConfig) -> Option<DedicatedRuntime> {
+        let worker_threads = config.dedicated_runtime_threads?;
+        if cfg!(msim) {
+            warn!("Dedicated checkpoint executor runtime is not supported in simulation");
+            return None;
         }
+        info!("Executing checkpoints on a dedicated runtime with {worker_threads} worker threads");
+        Some(DedicatedRuntime::new(worker_threads))
     }
 
     /// Ensure that all checkpoints in the current epoch will be executed.
@@ -210,7 +326,7 @@ impl CheckpointExecutor {
         // If we haven't executed anything in the past, we schedule checkpoint 0.
         // Otherwise we schedule the one after highest executed.
         let mut highest_executed = self
-            .checkpoint_store
+            .watermark_store
             .get_highest_executed_checkpoint()
             .unwrap();
 
@@ -286,7 +402,8 @@ impl CheckpointExecutor {
                 // watermark accordingly. Note that given that checkpoints are guaranteed to
                 // be processed (added to FuturesOrdered) in seq_number order, using FuturesOrdered
                 // guarantees that we will also ratchet the watermarks in order.
-                Some(Ok((checkpoint, tx_digests))) = pending.next() => {
+                Some(Ok((checkpoint, tx_digests, latency))) = pending.next() => {
+                    self.concurrency.record_latency(latency);
                     self.process_executed_checkpoint(&epoch_store, &checkpoint, &tx_digests).await;
                     highest_executed = Some(checkpoint.clone());
 
@@ -353,7 +470,7 @@ impl CheckpointExecutor {
         let seq = *checkpoint.sequence_number();
         debug!("Bumping highest_executed_checkpoint watermark to {seq:?}");
         if let Some(prev_highest) = self
-            .checkpoint_store
+            .watermark_store
             .get_highest_executed_checkpoint_seq_number()
             .unwrap()
         {
@@ -385,7 +502,7 @@ impl CheckpointExecutor {
                 .expect("Failed to delete contents digest -> sequence number mapping");
         }
 
-        self.checkpoint_store
+        self.watermark_store
             .update_highest_executed_checkpoint(checkpoint)
             .unwrap();
         self.metrics.last_executed_checkpoint.set(seq as i64);
@@ -445,7 +562,7 @@ impl CheckpointExecutor {
         };
 
         while *next_to_schedule <= *latest_synced_checkpoint.sequence_number()
-            && pending.len() < self.config.checkpoint_execution_max_concurrency
+            && pending.len() < self.concurrency.limit()
         {
             let checkpoint = self
                 .checkpoint_store
@@ -498,45 +615,59 @@ impl CheckpointExecutor {
 
         let metrics = self.metrics.clone();
         let local_execution_timeout_sec = self.config.local_execution_timeout_sec;
+        let retry_error_log_interval = self.config.retry_error_log_interval;
+        let trace_transaction_execution = self.config.trace_transaction_execution;
         let data_ingestion_dir = self.config.data_ingestion_dir.clone();
         let checkpoint_store = self.checkpoint_store.clone();
+        let watermark_store = self.watermark_store.clone();
         let object_cache_reader = self.object_cache_reader.clone();
         let transaction_cache_reader = self.transaction_cache_reader.clone();
         let tx_manager = self.tx_manager.clone();
         let accumulator = self.accumulator.clone();
         let state = self.state.clone();
 
-        pending.push_back(spawn_monitored_task!(async move {
+        let task = async move {
+            let exec_start = Instant::now();
             let epoch_store = epoch_store.clone();
+            let mut attempt = 0;
             let tx_digests = loop {
+                attempt += 1;
                 match execute_checkpoint(
                     checkpoint.clone(),
                     &state,
                     object_cache_reader.as_ref(),
                     transaction_cache_reader.as_ref(),
                     checkpoint_store.clone(),
+                    watermark_store.clone(),
                     epoch_store.clone(),
                     tx_manager.clone(),
                     accumulator.clone(),
                     local_execution_timeout_sec,
+                    trace_transaction_execution,
                     &metrics,
                     data_ingestion_dir.clone(),
                 )
                 .await
                 {
                     Err(err) => {
-                        error!(
-                            "Error while executing checkpoint, will retry in 1s: {:?}",
-                            err
-                        );
+                        if should_log_retry_error(attempt, retry_error_log_interval) {
+                            error!(
+                                "Error while executing checkpoint (attempt {}), will retry in 1s: {:?}",
+                                attempt, err
+                            );
+                        }
                         tokio::time::sleep(Duration::from_secs(1)).await;
                         metrics.checkpoint_exec_errors.inc();
                     }
                     Ok(tx_digests) => break tx_digests,
                 }
             };
-            (checkpoint, tx_digests)
-        }));
+            (checkpoint, tx_digests, exec_start.elapsed())
+        };
+        pending.push_back(match &self.runtime {
+            Some(runtime) => runtime.handle().spawn(monitored_future!(task)),
+            None => spawn_monitored_task!(task),
+        });
     }
 
     #[instrument(level = "info", skip_all)]
@@ -565,25 +696,34 @@ impl CheckpointExecutor {
                 .expect("Acquiring shared locks for change_epoch tx cannot fail");
         }
 
+        let transaction_executions = trace_transaction_executions(
+            self.config.trace_transaction_execution,
+            self.transaction_cache_reader.as_ref(),
+            &[change_epoch_tx_digest],
+        );
         self.tx_manager.enqueue_with_expected_effects_digest(
             vec![(change_epoch_tx.clone(), execution_digests.effects)],
             &epoch_store,
         );
-        handle_execution_effects(
-            &self.state,
-            vec![execution_digests],
-            vec![change_epoch_tx_digest],
-            checkpoint.clone(),
-            self.checkpoint_store.clone(),
-            self.object_cache_reader.as_ref(),
-            self.transaction_cache_reader.as_ref(),
-            epoch_store.clone(),
-            self.tx_manager.clone(),
-            self.accumulator.clone(),
-            self.config.local_execution_timeout_sec,
-            self.config.data_ingestion_dir.clone(),
-        )
-        .await;
+        futures::join!(
+            transaction_executions,
+            handle_execution_effects(
+                &self.state,
+                vec![execution_digests],
+                vec![change_epoch_tx_digest],
+                checkpoint.clone(),
+                self.checkpoint_store.clone(),
+                self.watermark_store.clone(),
+                self.object_cache_reader.as_ref(),
+                self.transaction_cache_reader.as_ref(),
+                epoch_store.clone(),
+                self.tx_manager.clone(),
+                self.accumulator.clone(),
+                self.config.local_execution_timeout_sec,
+                &self.metrics,
+                self.config.data_ingestion_dir.clone(),
+            ),
+        );
     }
 
     /// Check whether `checkpoint` is the last checkpoint of the current epoch. If so,
@@ -614,6 +754,23 @@ impl CheckpointExecutor {
                         "Reached end of epoch, executing change_epoch transaction",
                     );
 
+                    if let Some(next_epoch_committee) = checkpoint.next_epoch_committee() {
+                        match Committee::new_checked(
+                            cur_epoch + 1,
+                            next_epoch_committee.iter().cloned().collect(),
+                        ) {
+                            Ok(committee) => info!(
+                                next_epoch = cur_epoch + 1,
+                                committee_digest = Hex::encode(committee.digest()),
+                                "Next epoch committee",
+                            ),
+                            Err(err) => warn!(
+                                next_epoch = cur_epoch + 1,
+                                "Invalid next epoch committee: {err}",
+                            ),
+                        }
+                    }
+
                     self.execute_change_epoch_tx(
                         change_epoch_execution_digests,
                         change_epoch_tx_digest,
@@ -699,10 +856,12 @@ async fn execute_checkpoint(
     object_cache_reader: &dyn ObjectCacheRead,
     transaction_cache_reader: &dyn TransactionCacheRead,
     checkpoint_store: Arc<CheckpointStore>,
+    watermark_store: Arc<dyn WatermarkStore>,
     epoch_store: Arc<AuthorityPerEpochStore>,
     transaction_manager: Arc<TransactionManager>,
     accumulator: Arc<StateAccumulator>,
     local_execution_timeout_sec: u64,
+    trace_transaction_execution: bool,
     metrics: &Arc<CheckpointExecutorMetrics>,
     data_ingestion_dir: Option<PathBuf>,
 ) -> SuiResult<Vec<TransactionDigest>> {
@@ -735,10 +894,12 @@ async fn execute_checkpoint(
         object_cache_reader,
         transaction_cache_reader,
         checkpoint_store.clone(),
+        watermark_store,
         epoch_store.clone(),
         transaction_manager,
         accumulator,
         local_execution_timeout_sec,
+        trace_transaction_execution,
         checkpoint,
         metrics,
         prepare_start,
@@ -762,6 +923,52 @@ async fn execute_checkpoint(
     Ok(all_tx_digests)
 }
 
+/// Formats at most `max` of the `missing` digests, followed by the count of omitted ones.
+fn format_missing_digests(missing: &[TransactionDigest], max: usize) -> String {
+    if missing.len() <= max {
+        return format!("{:?}", missing);
+    }
+    format!("{:?} (and {} more)", &missing[..max], missing.len() - max)
+}
+
+/// Whether failed execution attempt `attempt` (starting from 1) of a checkpoint is logged: the
+/// first one, then one in every `interval`.
+fn should_log_retry_error(attempt: u64, interval: u64) -> bool {
+    (attempt - 1) % interval.max(1) == 0
+}
+
+/// Where a checkpoint stands relative to the highest executed checkpoint watermark.
+#[derive(Debug, PartialEq, Eq)]
+enum ExecutionProgress {
+    /// The watermark is already at or past the checkpoint.
+    Reexecuting {
+        highest_executed: CheckpointSequenceNumber,
+    },
+    /// Checkpoints before this one have yet to finish executing.
+    Pending {
+        highest_executed: CheckpointSequenceNumber,
+    },
+    /// The checkpoint is the next one to execute, and blocks further executions.
+    NextToExecute,
+}
+
+fn execution_progress(
+    sequence_number: CheckpointSequenceNumber,
+    watermark_store: &dyn WatermarkStore,
+) -> ExecutionProgress {
+    let highest_executed = watermark_store
+        .get_highest_executed_checkpoint_seq_number()
+        .unwrap()
+        .unwrap_or_default();
+    if sequence_number <= highest_executed {
+        ExecutionProgress::Reexecuting { highest_executed }
+    } else if sequence_number > highest_executed + 1 {
+        ExecutionProgress::Pending { highest_executed }
+    } else {
+        ExecutionProgress::NextToExecute
+    }
+}
+
 #[instrument(level = "error", skip_all, fields(seq = ?checkpoint.sequence_number(), epoch = ?epoch_store.epoch()))]
 async fn handle_execution_effects(
     state: &AuthorityState,
@@ -769,43 +976,43 @@ async fn handle_execution_effects(
     all_tx_digests: Vec<TransactionDigest>,
     checkpoint: VerifiedCheckpoint,
     checkpoint_store: Arc<CheckpointStore>,
+    watermark_store: Arc<dyn WatermarkStore>,
     object_cache_reader: &dyn ObjectCacheRead,
     transaction_cache_reader: &dyn TransactionCacheRead,
     epoch_store: Arc<AuthorityPerEpochStore>,
     transaction_manager: Arc<TransactionManager>,
     accumulator: Arc<StateAccumulator>,
     local_execution_timeout_sec: u64,
+    metrics: &Arc<CheckpointExecutorMetrics>,
     data_ingestion_dir: Option<PathBuf>,
 ) {
     // Once synced_txns have been awaited, all txns should have effects committed.
-    let mut periods = 1;
+    let mut log_schedule = MissingEffectsLogSchedule::default();
     let log_timeout_sec = Duration::from_secs(local_execution_timeout_sec);
     // Whether the checkpoint is next to execute and blocking additional executions.
     let mut blocking_execution = false;
-    loop {
-        let effects_future = transaction_cache_reader.notify_read_executed_effects(&all_tx_digests);
-
-        match timeout(log_timeout_sec, effects_future).await {
-            Err(_elapsed) => {
+    let effects = await_with_periodic_timeout(
+        transaction_cache_reader.notify_read_executed_effects(&all_tx_digests),
+        log_timeout_sec,
+        || {
                 // Reading this value every timeout should be ok.
-                let highest_seq = checkpoint_store
-                    .get_highest_executed_checkpoint_seq_number()
-                    .unwrap()
-                    .unwrap_or_default();
-                if checkpoint.sequence_number <= highest_seq {
-                    error!(
-                        "Re-executing checkpoint {} after higher checkpoint {} has executed!",
-                        checkpoint.sequence_number, highest_seq
-                    );
-                    continue;
-                }
-                if checkpoint.sequence_number > highest_seq + 1 {
-                    trace!(
-                        "Checkpoint {} is still executing. Highest executed = {}",
-                        checkpoint.sequence_number,
-                        highest_seq
-                    );
-                    continue;
+                match execution_progress(checkpoint.sequence_number, watermark_store.as_ref()) {
+                    ExecutionProgress::Reexecuting { highest_executed } => {
+                        error!(
+                            "Re-executing checkpoint {} after higher checkpoint {} has executed!",
+                            checkpoint.sequence_number, highest_executed
+                        );
+                        return;
+                    }
+                    ExecutionProgress::Pending { highest_executed } => {
+                        trace!(
+                            "Checkpoint {} is still executing. Highest executed = {}",
+                            checkpoint.sequence_number,
+                            highest_executed
+                        );
+                        return;
+                    }
+                    ExecutionProgress::NextToExecute => {}
                 }
                 if !blocking_execution {
                     trace!(
@@ -813,7 +1020,7 @@ async fn handle_execution_effects(
                         checkpoint.sequence_number
                     );
                     blocking_execution = true;
-                    continue;
+                    return;
                 }
 
                 // Only log details when the checkpoint is next to execute, but has not finished
@@ -836,62 +1043,177 @@ async fn handle_execution_effects(
 
                 if missing_digests.is_empty() {
                     // All effects just become available.
-                    continue;
+                    return;
                 }
+                metrics.checkpoint_exec_missing_effects_timeouts.inc();
 
-                warn!(
-                    "Transaction effects for checkpoint tx digests {:?} not present within {:?}. ",
-                    missing_digests,
-                    log_timeout_sec * periods,
-                );
-
-                // Print out more information for the 1st pending transaction, which should have
-                // all of its input available.
-                let pending_digest = missing_digests.first().unwrap();
-                if let Some(missing_input) = transaction_manager.get_missing_input(pending_digest) {
+                if log_schedule.should_log() {
                     warn!(
-                        "Transaction {pending_digest:?} has missing input objects {missing_input:?}",
-                    );
-                }
-                periods += 1;
-            }
-            Ok(Err(err)) => panic!("Failed to notify_read_executed_effects: {:?}", err),
-            Ok(Ok(effects)) => {
-                for (tx_digest, expected_digest, actual_effects) in
-                    izip!(&all_tx_digests, &execution_digests, &effects)
-                {
-                    let expected_effects_digest = &expected_digest.effects;
-                    assert_not_forked(
-                        &checkpoint,
-                        tx_digest,
-                        expected_effects_digest,
-                        &actual_effects.digest(),
-                        transaction_cache_reader,
+                        "Transaction effects for checkpoint tx digests {} not present within {:?}. ",
+                        format_missing_digests(&missing_digests, MAX_LOGGED_MISSING_DIGESTS),
+                        log_timeout_sec * log_schedule.periods(),
                     );
+
+                    // Print out more information for the 1st pending transaction, which should have
+                    // all of its input available.
+                    let pending_digest = missing_digests.first().unwrap();
+                    if let Some(missing_input) =
+                        transaction_manager.get_missing_input(pending_digest)
+                    {
+                        warn!(
+                            "Transaction {pending_digest:?} has missing input objects {missing_input:?}",
+                        );
+                    }
                 }
+                log_schedule.record_period();
+            },
+        )
+        .await;
 
-                // return Ok(effects);
+    let effects =
+        effects.unwrap_or_else(|err| panic!("Failed to notify_read_executed_effects: {:?}", err));
+    for (tx_digest, expected_digest, actual_effects) in
+        izip!(&all_tx_digests, &execution_digests, &effects)
+    {
+        let expected_effects_digest = &expected_digest.effects;
+        assert_not_forked(
+            &checkpoint,
+            tx_digest,
+            expected_effects_digest,
+            &actual_effects.digest(),
+            transaction_cache_reader,
+        );
+    }
 
-                // if end of epoch checkpoint, we must finalize the checkpoint after executing
-                // the change epoch tx, which is done after all other checkpoint execution
-                if checkpoint.end_of_epoch_data.is_none() {
-                    finalize_checkpoint(
-                        state,
-                        object_cache_reader,
-                        transaction_cache_reader,
-                        checkpoint_store.clone(),
-                        &all_tx_digests,
-                        epoch_store.clone(),
-                        checkpoint.clone(),
-                        accumulator.clone(),
-                        effects,
-                        data_ingestion_dir,
-                    )
+    // if end of epoch checkpoint, we must finalize the checkpoint after executing
+    // the change epoch tx, which is done after all other checkpoint execution
+    if checkpoint.end_of_epoch_data.is_none() {
+        finalize_checkpoint(
+            state,
+            object_cache_reader,
+            transaction_cache_reader,
+            checkpoint_store.clone(),
+            &all_tx_digests,
+            epoch_store.clone(),
+            checkpoint.clone(),
+            accumulator.clone(),
+            effects,
+            data_ingestion_dir,
+        )
+        .await
+        .expect("Finalizing checkpoint cannot fail");
+    }
+}
+
+/// When `enabled`, opens a span for each of `tx_digests` and returns a future waiting for the
+/// effects of each transaction within its span. Spans close as soon as their transaction has
+/// executed, so their durations show which transactions of a checkpoint were slow. Call it before
+/// enqueueing the transactions, and poll it alongside `handle_execution_effects`.
+fn trace_transaction_executions<'a>(
+    enabled: bool,
+    transaction_cache_reader: &'a dyn TransactionCacheRead,
+    tx_digests: &[TransactionDigest],
+) -> impl Future<Output = ()> + 'a {
+    let tx_digests = if enabled { tx_digests } else { &[] };
+    let executions: Vec<_> = tx_digests
+        .iter()
+        .map(|tx_digest| {
+            let tx_digest = *tx_digest;
+            let span = transaction_execution_span(&tx_digest);
+            async move {
+                match transaction_cache_reader
+                    .notify_read_executed_effects(&[tx_digest])
                     .await
-                    .expect("Finalizing checkpoint cannot fail");
+                {
+                    Ok(effects) => {
+                        record_transaction_gas_cost(&tracing::Span::current(), &effects[0]);
+                        debug!("Transaction effects are available");
+                    }
+                    Err(err) => debug!("Failed to read transaction effects: {:?}", err),
                 }
-                return;
             }
+            .instrument(span)
+        })
+        .collect();
+    async move {
+        join_all(executions).await;
+    }
+}
+
+/// Span for executing a checkpoint transaction, annotated with the transaction digest. The gas
+/// cost breakdown is recorded with `record_transaction_gas_cost` once its effects are available.
+fn transaction_execution_span(tx_digest: &TransactionDigest) -> tracing::Span {
+    debug_span!(
+        "checkpoint_transaction",
+        tx_digest = ?tx_digest,
+        computation_cost = field::Empty,
+        storage_cost = field::Empty,
+        storage_rebate = field::Empty,
+        non_refundable_storage_fee = field::Empty,
+        gas_used = field::Empty,
+    )
+}
+
+fn record_transaction_gas_cost(span: &tracing::Span, effects: &TransactionEffects) {
+    let gas_cost = effects.gas_cost_summary();
+    span.record("computation_cost", gas_cost.computation_cost);
+    span.record("storage_cost", gas_cost.storage_cost);
+    span.record("storage_rebate", gas_cost.storage_rebate);
+    span.record(
+        "non_refundable_storage_fee",
+        gas_cost.non_refundable_storage_fee,
+    );
+    span.record("gas_used", gas_cost.net_gas_usage());
+}
+
+/// Decides which timeouts waiting for a checkpoint's effects get logged. Missing effects are
+/// logged at exponentially increasing periods, so that a long stall does not spam the logs every
+/// timeout.
+struct MissingEffectsLogSchedule {
+    periods: u32,
+    next_log_period: u32,
+}
+
+impl Default for MissingEffectsLogSchedule {
+    fn default() -> Self {
+        Self {
+            periods: 1,
+            next_log_period: 1,
+        }
+    }
+}
+
+impl MissingEffectsLogSchedule {
+    /// Number of timeout periods waited so far, including the current one.
+    fn periods(&self) -> u32 {
+        self.periods
+    }
+
+    fn should_log(&self) -> bool {
+        self.periods >= self.next_log_period
+    }
+
+    /// Ends the current period, after it has been logged if `should_log` returned true.
+    fn record_period(&mut self) {
+        if self.should_log() {
+            self.next_log_period *= 2;
+        }
+        self.periods += 1;
+    }
+}
+
+/// Waits for `future` to complete, calling `on_timeout` every time `interval` elapses before it
+/// does. Uses tokio's clock, so it can be driven deterministically in tests with paused time.
+async fn await_with_periodic_timeout<F: Future>(
+    future: F,
+    interval: Duration,
+    mut on_timeout: impl FnMut(),
+) -> F::Output {
+    tokio::pin!(future);
+    loop {
+        match timeout(interval, &mut future).await {
+            Ok(output) => return output,
+            Err(_elapsed) => on_timeout(),
         }
     }
 }
@@ -1156,10 +1478,12 @@ async fn execute_transactions(
     object_cache_reader: &dyn ObjectCacheRead,
     transaction_cache_reader: &dyn TransactionCacheRead,
     checkpoint_store: Arc<CheckpointStore>,
+    watermark_store: Arc<dyn WatermarkStore>,
     epoch_store: Arc<AuthorityPerEpochStore>,
     transaction_manager: Arc<TransactionManager>,
     accumulator: Arc<StateAccumulator>,
     local_execution_timeout_sec: u64,
+    trace_transaction_execution: bool,
     checkpoint: VerifiedCheckpoint,
     metrics: &Arc<CheckpointExecutorMetrics>,
     prepare_start: Instant,
@@ -1221,23 +1545,32 @@ async fn execute_transactions(
     }
 
     let exec_start = Instant::now();
+    let transaction_executions = trace_transaction_executions(
+        trace_transaction_execution,
+        transaction_cache_reader,
+        &all_tx_digests,
+    );
     transaction_manager.enqueue_with_expected_effects_digest(executable_txns.clone(), &epoch_store);
 
-    handle_execution_effects(
-        state,
-        execution_digests,
-        all_tx_digests,
-        checkpoint.clone(),
-        checkpoint_store,
-        object_cache_reader,
-        transaction_cache_reader,
-        epoch_store,
-        transaction_manager,
-        accumulator,
-        local_execution_timeout_sec,
-        data_ingestion_dir,
-    )
-    .await;
+    futures::join!(
+        transaction_executions,
+        handle_execution_effects(
+            state,
+            execution_digests,
+            all_tx_digests,
+            checkpoint.clone(),
+            checkpoint_store,
+            watermark_store,
+            object_cache_reader,
+            transaction_cache_reader,
+            epoch_store,
+            transaction_manager,
+            accumulator,
+            local_execution_timeout_sec,
+            metrics,
+            data_ingestion_dir,
+        ),
+    );
 
     let exec_elapsed = exec_start.elapsed();
     metrics
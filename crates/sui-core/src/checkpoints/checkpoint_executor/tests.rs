@@ -98,6 +98,175 @@ pub async fn test_checkpoint_executor_crash_recovery() {
     executor_handle.abort();
 }
 
+/// Test that checkpoints executed on a dedicated runtime are still executed in order
+/// and advance the highest executed watermark.
+#[tokio::test]
+pub async fn test_checkpoint_executor_dedicated_runtime() {
+    let buffer_size = num_cpus::get() * 2;
+    let tempdir = tempdir().unwrap();
+    let checkpoint_store = CheckpointStore::new(tempdir.path());
+
+    let (state, _, accumulator, checkpoint_sender, committee): (
+        Arc<AuthorityState>,
+        CheckpointExecutor,
+        Arc<StateAccumulator>,
+        Sender<VerifiedCheckpoint>,
+        CommitteeFixture,
+    ) = init_executor_test(buffer_size, checkpoint_store.clone()).await;
+
+    let mut executor = CheckpointExecutor::new(
+        checkpoint_sender.subscribe(),
+        checkpoint_store.clone(),
+        state.clone(),
+        accumulator,
+        CheckpointExecutorConfig {
+            dedicated_runtime_threads: Some(2),
+            ..Default::default()
+        },
+        &Registry::new(),
+    );
+    assert!(executor.runtime.is_some());
+
+    let checkpoints = sync_new_checkpoints(
+        &checkpoint_store,
+        &checkpoint_sender,
+        2 * buffer_size,
+        None,
+        &committee,
+    );
+
+    let epoch_store = state.epoch_store_for_testing().clone();
+    let executor_handle =
+        spawn_monitored_task!(async move { executor.run_epoch(epoch_store, None).await });
+    tokio::time::sleep(Duration::from_secs(5)).await;
+
+    let highest_executed = checkpoint_store
+        .get_highest_executed_checkpoint()
+        .unwrap()
+        .expect("Expected highest executed to not be None");
+    assert_eq!(
+        highest_executed.digest(),
+        checkpoints.last().unwrap().digest()
+    );
+    assert_eq!(
+        *highest_executed.sequence_number(),
+        2 * (buffer_size as u64) - 1
+    );
+
+    executor_handle.abort();
+}
+
+#[derive(Default)]
+struct InMemoryWatermarkStore {
+    highest_executed: parking_lot::Mutex<Option<VerifiedCheckpoint>>,
+}
+
+impl WatermarkStore for InMemoryWatermarkStore {
+    fn get_highest_executed_checkpoint(&self) -> SuiResult<Option<VerifiedCheckpoint>> {
+        Ok(self.highest_executed.lock().clone())
+    }
+
+    fn update_highest_executed_checkpoint(&self, checkpoint: &VerifiedCheckpoint) -> SuiResult {
+        *self.highest_executed.lock() = Some(checkpoint.clone());
+        Ok(())
+    }
+}
+
+/// Test that the executor resumes from the watermark held by an injected `WatermarkStore`,
+/// and advances that watermark rather than the one in the `CheckpointStore`.
+#[tokio::test]
+pub async fn test_checkpoint_executor_watermark_store_recovery() {
+    let buffer_size = num_cpus::get() * 2;
+    let tempdir = tempdir().unwrap();
+    let checkpoint_store = CheckpointStore::new(tempdir.path());
+
+    let (state, executor, _accumulator, checkpoint_sender, committee): (
+        Arc<AuthorityState>,
+        CheckpointExecutor,
+        Arc<StateAccumulator>,
+        Sender<VerifiedCheckpoint>,
+        CommitteeFixture,
+    ) = init_executor_test(buffer_size, checkpoint_store.clone()).await;
+
+    let checkpoints = sync_new_checkpoints(
+        &checkpoint_store,
+        &checkpoint_sender,
+        2 * buffer_size,
+        None,
+        &committee,
+    );
+
+    // Pretend the first half of the checkpoints were executed before a restart.
+    let watermark_store = Arc::new(InMemoryWatermarkStore::default());
+    watermark_store
+        .update_highest_executed_checkpoint(&checkpoints[buffer_size - 1])
+        .unwrap();
+
+    let mut executor = executor.with_watermark_store(watermark_store.clone());
+    let epoch_store = state.epoch_store_for_testing().clone();
+    let executor_handle =
+        spawn_monitored_task!(async move { executor.run_epoch(epoch_store, None).await });
+    tokio::time::sleep(Duration::from_secs(5)).await;
+
+    let highest_executed = watermark_store
+        .get_highest_executed_checkpoint()
+        .unwrap()
+        .expect("Expected highest executed to not be None");
+    assert_eq!(
+        highest_executed.digest(),
+        checkpoints.last().unwrap().digest()
+    );
+    assert!(checkpoint_store
+        .get_highest_executed_checkpoint_seq_number()
+        .unwrap()
+        .is_none());
+
+    executor_handle.abort();
+}
+
+/// Test that execution progress, which decides when missing effects are reported, is judged
+/// against the injected `WatermarkStore` rather than the `CheckpointStore`.
+#[test]
+fn test_execution_progress_reads_watermark_store() {
+    let tempdir = tempdir().unwrap();
+    let checkpoint_store = CheckpointStore::new(tempdir.path());
+    let committee = CommitteeFixture::generate(rand::rngs::OsRng, 0, 4);
+    let (checkpoints, _, _, _) = committee.make_empty_checkpoints(2, None);
+
+    let watermark_store = InMemoryWatermarkStore::default();
+    watermark_store
+        .update_highest_executed_checkpoint(&checkpoints[1])
+        .unwrap();
+    assert!(checkpoint_store
+        .get_highest_executed_checkpoint_seq_number()
+        .unwrap()
+        .is_none());
+
+    assert_eq!(
+        execution_progress(1, &watermark_store),
+        ExecutionProgress::Reexecuting {
+            highest_executed: 1
+        }
+    );
+    assert_eq!(
+        execution_progress(2, &watermark_store),
+        ExecutionProgress::NextToExecute
+    );
+    assert_eq!(
+        execution_progress(3, &watermark_store),
+        ExecutionProgress::Pending {
+            highest_executed: 1
+        }
+    );
+    // The `CheckpointStore` watermark alone would report checkpoint 2 as still pending.
+    assert_eq!(
+        execution_progress(2, checkpoint_store.as_ref()),
+        ExecutionProgress::Pending {
+            highest_executed: 0
+        }
+    );
+}
+
 /// Test that checkpoint execution correctly signals end of epoch after
 /// receiving last checkpoint of epoch, then resumes executing cehckpoints
 /// from the next epoch if called after reconfig
@@ -476,3 +645,214 @@ fn sync_checkpoint(
         .unwrap();
     sender.send(checkpoint.clone()).unwrap();
 }
+
+#[test]
+fn test_format_missing_digests_truncates() {
+    let missing: Vec<_> = (0..1000).map(|_| TransactionDigest::random()).collect();
+
+    let formatted = format_missing_digests(&missing, MAX_LOGGED_MISSING_DIGESTS);
+    assert!(formatted.ends_with("(and 990 more)"));
+    for digest in &missing[..MAX_LOGGED_MISSING_DIGESTS] {
+        assert!(formatted.contains(&format!("{:?}", digest)));
+    }
+    for digest in &missing[MAX_LOGGED_MISSING_DIGESTS..] {
+        assert!(!formatted.contains(&format!("{:?}", digest)));
+    }
+
+    let few = &missing[..3];
+    assert_eq!(
+        format_missing_digests(few, MAX_LOGGED_MISSING_DIGESTS),
+        format!("{:?}", few)
+    );
+}
+
+#[test]
+fn test_retry_error_logs_are_sampled() {
+    let logged = |attempts: u64, interval: u64| {
+        (1..=attempts)
+            .filter(|attempt| should_log_retry_error(*attempt, interval))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(logged(25, 10), vec![1, 11, 21]);
+    // A sustained outage of an hour of 1s retries only logs a bounded number of errors.
+    assert_eq!(logged(3600, 10).len(), 360);
+    // Intervals of 0 and 1 log every attempt.
+    assert_eq!(logged(5, 1), vec![1, 2, 3, 4, 5]);
+    assert_eq!(logged(5, 0), vec![1, 2, 3, 4, 5]);
+}
+
+/// Test that while the next checkpoint to execute is missing effects, every local execution
+/// timeout is counted, and the missing effects are logged at exponentially increasing periods.
+#[tokio::test]
+async fn test_missing_effects_timeouts_with_paused_time() {
+    let tempdir = tempdir().unwrap();
+    let checkpoint_store = CheckpointStore::new(tempdir.path());
+    let (state, _executor, accumulator, _checkpoint_sender, committee): (
+        Arc<AuthorityState>,
+        CheckpointExecutor,
+        Arc<StateAccumulator>,
+        Sender<VerifiedCheckpoint>,
+        CommitteeFixture,
+    ) = init_executor_test(1, checkpoint_store.clone()).await;
+
+    // The second checkpoint is next to execute, but its transaction is never executed.
+    let (checkpoints, _, _, _) = committee.make_empty_checkpoints(2, None);
+    let watermark_store = Arc::new(InMemoryWatermarkStore::default());
+    watermark_store
+        .update_highest_executed_checkpoint(&checkpoints[0])
+        .unwrap();
+    let execution_digests = vec![ExecutionDigests::random()];
+    let all_tx_digests = vec![execution_digests[0].transaction];
+    let metrics = CheckpointExecutorMetrics::new_for_tests();
+
+    let recorder = SpanRecorder::default();
+    let events = recorder.events.clone();
+    let _guard = tracing::subscriber::set_default(recorder);
+    tokio::time::pause();
+
+    let execution = handle_execution_effects(
+        &state,
+        execution_digests,
+        all_tx_digests,
+        checkpoints[1].clone(),
+        checkpoint_store,
+        watermark_store,
+        state.get_object_cache_reader().as_ref(),
+        state.get_transaction_cache_reader().as_ref(),
+        state.epoch_store_for_testing().clone(),
+        state.transaction_manager().clone(),
+        accumulator,
+        /* local_execution_timeout_sec */ 1,
+        &metrics,
+        None,
+    );
+    tokio::pin!(execution);
+
+    // Check halfway through each local execution timeout.
+    let mut timeouts_counted = vec![];
+    let mut warnings_logged = vec![];
+    let mut wait = Duration::from_millis(500);
+    for _ in 0..10 {
+        assert!(timeout(wait, &mut execution).await.is_err());
+        wait = Duration::from_secs(1);
+        timeouts_counted.push(metrics.checkpoint_exec_missing_effects_timeouts.get());
+        warnings_logged.push(
+            events
+                .lock()
+                .iter()
+                .filter(|(level, message)| {
+                    *level == tracing::Level::WARN
+                        && message.starts_with("Transaction effects for checkpoint tx digests")
+                })
+                .count(),
+        );
+    }
+
+    // The first timeout only finds the checkpoint blocking execution, and every later one is
+    // counted once. Only the 1st, 2nd, 4th and 8th of those are logged.
+    assert_eq!(timeouts_counted, vec![0, 0, 1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(warnings_logged, vec![0, 0, 1, 2, 2, 3, 3, 3, 3, 4]);
+}
+
+/// Records the name, level and fields of every span, and the level and message of every event,
+/// created while it is the default subscriber.
+#[derive(Default)]
+struct SpanRecorder {
+    spans: Arc<parking_lot::Mutex<Vec<(String, tracing::Level, Vec<(String, String)>)>>>,
+    events: Arc<parking_lot::Mutex<Vec<(tracing::Level, String)>>>,
+}
+
+struct FieldVisitor<'a>(&'a mut Vec<(String, String)>);
+
+impl tracing::field::Visit for FieldVisitor<'_> {
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        self.0
+            .push((field.name().to_string(), format!("{:?}", value)));
+    }
+}
+
+impl tracing::Subscriber for SpanRecorder {
+    fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+        let mut fields = vec![];
+        span.record(&mut FieldVisitor(&mut fields));
+        let mut spans = self.spans.lock();
+        spans.push((
+            span.metadata().name().to_string(),
+            *span.metadata().level(),
+            fields,
+        ));
+        tracing::span::Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, span: &tracing::span::Id, values: &tracing::span::Record<'_>) {
+        let mut spans = self.spans.lock();
+        let (_, _, fields) = &mut spans[span.into_u64() as usize - 1];
+        values.record(&mut FieldVisitor(fields));
+    }
+
+    fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {}
+
+    fn event(&self, event: &tracing::Event<'_>) {
+        let mut fields = vec![];
+        event.record(&mut FieldVisitor(&mut fields));
+        let message = fields
+            .into_iter()
+            .find(|(field, _)| field == "message")
+            .map(|(_, message)| message)
+            .unwrap_or_default();
+        self.events
+            .lock()
+            .push((*event.metadata().level(), message));
+    }
+
+    fn enter(&self, _span: &tracing::span::Id) {}
+
+    fn exit(&self, _span: &tracing::span::Id) {}
+}
+
+#[test]
+fn test_transaction_execution_span_fields() {
+    let recorder = SpanRecorder::default();
+    let spans = recorder.spans.clone();
+
+    let tx_digest = TransactionDigest::random();
+    let effects = TransactionEffects::default();
+    tracing::subscriber::with_default(recorder, || {
+        let span = transaction_execution_span(&tx_digest);
+        // The gas cost is only known once the transaction has executed.
+        assert_eq!(spans.lock()[0].2.len(), 1);
+        record_transaction_gas_cost(&span, &effects);
+    });
+
+    let spans = spans.lock();
+    assert_eq!(spans.len(), 1);
+    let (name, level, fields) = &spans[0];
+    assert_eq!(name, "checkpoint_transaction");
+    assert_eq!(*level, tracing::Level::DEBUG);
+
+    let field = |name: &str| {
+        fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value.clone())
+            .unwrap_or_else(|| panic!("span is missing field {name}"))
+    };
+    let gas_cost = effects.gas_cost_summary();
+    assert_eq!(field("tx_digest"), format!("{:?}", tx_digest));
+    assert_eq!(
+        field("computation_cost"),
+        gas_cost.computation_cost.to_string()
+    );
+    assert_eq!(field("storage_cost"), gas_cost.storage_cost.to_string());
+    assert_eq!(field("storage_rebate"), gas_cost.storage_rebate.to_string());
+    assert_eq!(
+        field("non_refundable_storage_fee"),
+        gas_cost.non_refundable_storage_fee.to_string()
+    );
+    assert_eq!(field("gas_used"), gas_cost.net_gas_usage().to_string());
+}
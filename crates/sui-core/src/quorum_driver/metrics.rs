@@ -3,9 +3,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use prometheus::{
-    register_histogram_vec_with_registry, register_int_counter_vec_with_registry,
-    register_int_counter_with_registry, register_int_gauge_with_registry, HistogramVec, IntCounter,
-    IntCounterVec, IntGauge, Registry,
+    register_histogram_vec_with_registry, register_histogram_with_registry,
+    register_int_counter_vec_with_registry, register_int_counter_with_registry,
+    register_int_gauge_with_registry, HistogramVec, IntCounter, IntCounterVec, IntGauge, Registry,
 };
 
 use mysten_metrics::histogram::Histogram;
@@ -18,6 +18,10 @@ const FINALITY_LATENCY_SEC_BUCKETS: &[f64] = &[
     25.0,
 ];
 
+const QUEUE_LATENCY_SEC_BUCKETS: &[f64] = &[
+    0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
 #[derive(Clone)]
 pub struct QuorumDriverMetrics {
     pub(crate) total_requests: IntCounter,
@@ -38,6 +42,10 @@ pub struct QuorumDriverMetrics {
     pub(crate) current_transactions_in_retry: IntGauge,
 
     pub(crate) settlement_finality_latency: HistogramVec,
+
+    pub(crate) task_queue_depth: IntGauge,
+    pub(crate) task_queue_latency: prometheus::Histogram,
+    pub(crate) task_stage_latency: HistogramVec,
 }
 
 impl QuorumDriverMetrics {
@@ -128,6 +136,27 @@ impl QuorumDriverMetrics {
                 registry,
             )
             .unwrap(),
+            task_queue_depth: register_int_gauge_with_registry!(
+                "quorum_driver_task_queue_depth",
+                "Current number of tasks waiting in the QuorumDriver task queue",
+                registry,
+            )
+            .unwrap(),
+            task_queue_latency: register_histogram_with_registry!(
+                "quorum_driver_task_queue_latency",
+                "Time a task ready to be processed spends in the QuorumDriver task queue, in seconds",
+                QUEUE_LATENCY_SEC_BUCKETS.to_vec(),
+                registry,
+            )
+            .unwrap(),
+            task_stage_latency: register_histogram_vec_with_registry!(
+                "quorum_driver_task_stage_latency",
+                "Latency of each stage of processing a QuorumDriver task, in seconds",
+                &["stage"],
+                FINALITY_LATENCY_SEC_BUCKETS.to_vec(),
+                registry,
+            )
+            .unwrap(),
         }
     }
 
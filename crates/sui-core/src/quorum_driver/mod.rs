@@ -7,21 +7,26 @@ pub use metrics::*;
 pub mod reconfig_observer;
 
 use arc_swap::ArcSwap;
+use lru::LruCache;
+use parking_lot::Mutex;
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::{Debug, Formatter};
 use std::net::SocketAddr;
+use std::num::NonZeroUsize;
 use std::sync::Arc;
 use std::time::Duration;
 use sui_types::base_types::{AuthorityName, ObjectRef, TransactionDigest};
-use sui_types::committee::{Committee, EpochId, StakeUnit};
+use sui_types::committee::{Committee, CommitteeTrait, EpochId, StakeUnit};
+use sui_types::effects::TransactionEffects;
 use sui_types::messages_grpc::HandleCertificateRequestV3;
 use sui_types::quorum_driver_types::{
-    ExecuteTransactionRequestV3, QuorumDriverEffectsQueueResult, QuorumDriverError,
-    QuorumDriverResponse, QuorumDriverResult,
+    CertificateSigners, ExecuteTransactionRequestType, ExecuteTransactionRequestV3,
+    ExecuteTransactionResponse, FinalizedEffects, QuorumDriverEffectsQueueResult,
+    QuorumDriverError, QuorumDriverResponse, QuorumDriverResult,
 };
-use tap::TapFallible;
-use tokio::sync::Semaphore;
-use tokio::time::{sleep_until, Instant};
+use tap::{Tap, TapFallible};
+use tokio::sync::{Notify, Semaphore};
+use tokio::time::{sleep_until, Instant, MissedTickBehavior};
 
 use tokio::sync::mpsc::{self, Receiver, Sender};
 use tokio::task::JoinHandle;
@@ -51,6 +56,12 @@ mod tests;
 const TASK_QUEUE_SIZE: usize = 2000;
 const EFFECTS_QUEUE_SIZE: usize = 10000;
 const TX_MAX_RETRY_TIMES: u32 = 10;
+/// Backoff before the first retry of a transaction, doubled for every subsequent retry.
+const TX_RETRY_BASE_BACKOFF: Duration = Duration::from_millis(200);
+/// Number of transactions whose status `QuorumDriver::query_transaction_status` remembers.
+const TASK_STATUS_CACHE_SIZE: usize = 10000;
+/// How long `QuorumDriverHandler::shutdown` waits for tasks already being processed to finish.
+const SHUTDOWN_DRAIN_TIMEOUT: Duration = Duration::from_secs(10);
 
 #[derive(Clone)]
 pub struct QuorumDriverTask {
@@ -72,6 +83,19 @@ impl Debug for QuorumDriverTask {
     }
 }
 
+/// Status of a transaction submitted to the quorum driver.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum QuorumTaskStatus {
+    /// The transaction is queued or being processed, and no certificate is formed yet.
+    Pending,
+    /// A certificate is formed, and its effects are not certified yet.
+    CertFormed,
+    /// The effects of the transaction are certified.
+    Executed,
+    /// Processing failed with a non-retryable error, or ran out of retries.
+    Failed(QuorumDriverError),
+}
+
 pub struct QuorumDriver<A: Clone> {
     validators: ArcSwap<AuthorityAggregator<A>>,
     task_sender: Sender<QuorumDriverTask>,
@@ -79,6 +103,13 @@ pub struct QuorumDriver<A: Clone> {
     notifier: Arc<NotifyRead<TransactionDigest, QuorumDriverResult>>,
     metrics: Arc<QuorumDriverMetrics>,
     max_retry_times: u32,
+    retry_base_backoff: Duration,
+    /// How long a waiter registered with `notifier` may go unresolved before it is failed with
+    /// `QuorumDriverError::TimeoutBeforeFinality`. Waiters never expire if unset.
+    waiter_ttl: Option<Duration>,
+    /// Status of the most recently submitted transactions, for callers that do not wait on
+    /// `notifier`.
+    task_statuses: Mutex<LruCache<TransactionDigest, QuorumTaskStatus>>,
 }
 
 impl<A: Clone> QuorumDriver<A> {
@@ -89,6 +120,8 @@ impl<A: Clone> QuorumDriver<A> {
         notifier: Arc<NotifyRead<TransactionDigest, QuorumDriverResult>>,
         metrics: Arc<QuorumDriverMetrics>,
         max_retry_times: u32,
+        retry_base_backoff: Duration,
+        waiter_ttl: Option<Duration>,
     ) -> Self {
         Self {
             validators,
@@ -97,13 +130,53 @@ impl<A: Clone> QuorumDriver<A> {
             notifier,
             metrics,
             max_retry_times,
+            retry_base_backoff,
+            waiter_ttl,
+            task_statuses: Self::new_task_statuses(),
         }
     }
 
+    fn new_task_statuses() -> Mutex<LruCache<TransactionDigest, QuorumTaskStatus>> {
+        Mutex::new(LruCache::new(
+            NonZeroUsize::new(TASK_STATUS_CACHE_SIZE).unwrap(),
+        ))
+    }
+
     pub fn authority_aggregator(&self) -> &ArcSwap<AuthorityAggregator<A>> {
         &self.validators
     }
 
+    /// Returns the status of the transaction with `tx_digest`, or `None` if it was not submitted
+    /// to this quorum driver, or too long ago to be remembered.
+    pub fn query_transaction_status(
+        &self,
+        tx_digest: &TransactionDigest,
+    ) -> Option<QuorumTaskStatus> {
+        self.task_statuses.lock().get(tx_digest).cloned()
+    }
+
+    fn set_transaction_status(&self, tx_digest: TransactionDigest, status: QuorumTaskStatus) {
+        self.task_statuses.lock().put(tx_digest, status);
+    }
+
+    /// Fails waiters that have been registered for longer than `ttl` with
+    /// `QuorumDriverError::TimeoutBeforeFinality`, and records the timeout as their status.
+    fn expire_stale_waiters(&self, ttl: Duration) {
+        let err = QuorumDriverError::TimeoutBeforeFinality;
+        let expired = self.notifier.expire(ttl, &Err(err.clone()));
+        if expired.is_empty() {
+            return;
+        }
+        debug!(
+            "Expired waiters of {} transactions after {:?}",
+            expired.len(),
+            ttl
+        );
+        for tx_digest in expired {
+            self.set_transaction_status(tx_digest, QuorumTaskStatus::Failed(err.clone()));
+        }
+    }
+
     pub fn clone_committee(&self) -> Arc<Committee> {
         self.validators.load().committee.clone()
     }
@@ -120,6 +193,7 @@ impl<A: Clone> QuorumDriver<A> {
             .tap_ok(|_| {
                 debug!(?task, "Enqueued task.");
                 self.metrics.current_requests_in_flight.inc();
+                self.metrics.task_queue_depth.inc();
                 self.metrics.total_enqueued.inc();
                 if task.retry_times > 0 {
                     if task.retry_times == 1 {
@@ -136,26 +210,25 @@ impl<A: Clone> QuorumDriver<A> {
     }
 
     /// Enqueue the task again if it hasn't maxed out the total retry attempts.
-    /// If it has, notify failure.
+    /// If it has, notify failure with `last_error`, or with
+    /// `FailedWithTransientErrorAfterMaximumAttempts` if there is none.
     async fn enqueue_again_maybe(
         &self,
         request: ExecuteTransactionRequestV3,
         tx_cert: Option<CertifiedTransaction>,
         old_retry_times: u32,
         client_addr: Option<SocketAddr>,
+        last_error: Option<QuorumDriverError>,
     ) -> SuiResult<()> {
         if old_retry_times >= self.max_retry_times {
             // max out the retry times, notify failure
             info!(tx_digest=?request.transaction.digest(), "Failed to reach finality after attempting for {} times", old_retry_times+1);
-            self.notify(
-                &request.transaction,
-                &Err(
-                    QuorumDriverError::FailedWithTransientErrorAfterMaximumAttempts {
-                        total_attempts: old_retry_times + 1,
-                    },
-                ),
-                old_retry_times + 1,
+            let error = last_error.unwrap_or(
+                QuorumDriverError::FailedWithTransientErrorAfterMaximumAttempts {
+                    total_attempts: old_retry_times + 1,
+                },
             );
+            self.notify(&request.transaction, &Err(error), old_retry_times + 1);
             return Ok(());
         }
         self.backoff_and_enqueue(request, tx_cert, old_retry_times, client_addr, None)
@@ -173,7 +246,8 @@ impl<A: Clone> QuorumDriver<A> {
         min_backoff_duration: Option<Duration>,
     ) -> SuiResult<()> {
         let next_retry_after = Instant::now()
-            + Duration::from_millis(200 * u64::pow(2, old_retry_times))
+            + self
+                .retry_backoff(old_retry_times)
                 .max(min_backoff_duration.unwrap_or(Duration::from_secs(0)));
         sleep_until(next_retry_after).await;
 
@@ -187,6 +261,7 @@ impl<A: Clone> QuorumDriver<A> {
             _other => None,
         };
 
+        let transaction = request.transaction.clone();
         self.enqueue_task(QuorumDriverTask {
             request,
             tx_cert,
@@ -195,6 +270,46 @@ impl<A: Clone> QuorumDriver<A> {
             client_addr,
         })
         .await
+        .tap_err(|err| self.notify_enqueue_failure(&transaction, err, old_retry_times + 1))
+    }
+
+    /// Records a newly submitted transaction whose task could not be enqueued as failed, instead
+    /// of leaving it pending. The error itself is returned to the submitter.
+    fn record_submission_failure(&self, tx_digest: TransactionDigest, err: &SuiError) {
+        self.set_transaction_status(
+            tx_digest,
+            QuorumTaskStatus::Failed(QuorumDriverError::QuorumDriverInternalError(err.clone())),
+        );
+    }
+
+    /// Fails the waiter of a transaction whose task could not be enqueued, e.g. because the
+    /// quorum driver is shutting down, as nothing else would resolve it. This also records the
+    /// transaction as failed.
+    fn notify_enqueue_failure(
+        &self,
+        transaction: &Transaction,
+        err: &SuiError,
+        total_attempts: u32,
+    ) {
+        self.notify(
+            transaction,
+            &Err(QuorumDriverError::QuorumDriverInternalError(err.clone())),
+            total_attempts,
+        );
+    }
+
+    fn observe_stage_latency(&self, stage: &str, start: Instant) {
+        self.metrics
+            .task_stage_latency
+            .with_label_values(&[stage])
+            .observe(start.elapsed().as_secs_f64());
+    }
+
+    /// Backoff before retrying a transaction that has already been retried `old_retry_times`
+    /// times.
+    fn retry_backoff(&self, old_retry_times: u32) -> Duration {
+        self.retry_base_backoff
+            .saturating_mul(2u32.saturating_pow(old_retry_times))
     }
 
     pub fn notify(
@@ -223,6 +338,13 @@ impl<A: Clone> QuorumDriver<A> {
         if total_attempts > 1 {
             self.metrics.current_transactions_in_retry.dec();
         }
+        self.set_transaction_status(
+            *tx_digest,
+            match response {
+                Ok(_) => QuorumTaskStatus::Executed,
+                Err(err) => QuorumTaskStatus::Failed(err.clone()),
+            },
+        );
         // On fullnode we expect the send to always succeed because TransactionOrchestrator should be subscribing
         // to this queue all the time. However the if QuorumDriver is used elsewhere log may be noisy.
         if let Err(err) = self.effects_subscribe_sender.send(effects_queue_result) {
@@ -245,7 +367,10 @@ where
         debug!(?tx_digest, "Received transaction execution request.");
         self.metrics.total_requests.inc();
 
-        let ticket = self.notifier.register_one(tx_digest);
+        let tx_digest = *tx_digest;
+        let ticket = self.notifier.register_one(&tx_digest);
+        // Set before enqueueing, as the task may complete before `enqueue_task` returns.
+        self.set_transaction_status(tx_digest, QuorumTaskStatus::Pending);
         self.enqueue_task(QuorumDriverTask {
             request,
             tx_cert: None,
@@ -253,7 +378,8 @@ where
             next_retry_after: Instant::now(),
             client_addr: None,
         })
-        .await?;
+        .await
+        .tap_err(|err| self.record_submission_failure(tx_digest, err))?;
         Ok(ticket)
     }
 
@@ -271,6 +397,8 @@ where
         );
         self.metrics.total_requests.inc();
 
+        let tx_digest = *tx_digest;
+        self.set_transaction_status(tx_digest, QuorumTaskStatus::Pending);
         self.enqueue_task(QuorumDriverTask {
             request,
             tx_cert: None,
@@ -279,6 +407,31 @@ where
             client_addr,
         })
         .await
+        .tap_err(|err| self.record_submission_failure(tx_digest, err))
+    }
+
+    /// Submits an already formed `certificate`, which is executed without going through the
+    /// transaction signing stage first.
+    pub async fn submit_certificate(
+        &self,
+        certificate: CertifiedTransaction,
+    ) -> SuiResult<Registration<TransactionDigest, QuorumDriverResult>> {
+        let tx_digest = *certificate.digest();
+        debug!(?tx_digest, "Received certificate execution request.");
+        self.metrics.total_requests.inc();
+
+        let ticket = self.notifier.register_one(&tx_digest);
+        self.set_transaction_status(tx_digest, QuorumTaskStatus::CertFormed);
+        self.enqueue_task(QuorumDriverTask {
+            request: ExecuteTransactionRequestV3::new_v2(certificate.clone().into_unsigned()),
+            tx_cert: Some(certificate),
+            retry_times: 0,
+            next_retry_after: Instant::now(),
+            client_addr: None,
+        })
+        .await
+        .tap_err(|err| self.record_submission_failure(tx_digest, err))?;
+        Ok(ticket)
     }
 
     pub(crate) async fn process_transaction(
@@ -391,9 +544,24 @@ where
                 }))
             }
 
-            Err(AggregatorProcessTransactionError::RetryableTransaction { errors }) => {
-                debug!(?tx_digest, ?errors, "Retryable transaction error");
-                Err(None)
+            Err(AggregatorProcessTransactionError::RetryableTransaction {
+                errors,
+                collected_stake,
+                responses,
+            }) => {
+                debug!(
+                    ?tx_digest,
+                    ?errors,
+                    collected_stake,
+                    "Retryable transaction error"
+                );
+                // Retried by `handle_error`, and reported if retries are exhausted. `handle_error`
+                // records the actual attempt count.
+                Err(Some(QuorumDriverError::QuorumNotReached {
+                    total_attempts: 1,
+                    collected_stake,
+                    responses,
+                }))
             }
 
             Err(
@@ -478,7 +646,8 @@ where
         let auth_agg = self.validators.load();
         let _cert_guard = GaugeGuard::acquire(&auth_agg.metrics.inflight_certificates);
         let tx_digest = *request.certificate.digest();
-        let response = auth_agg
+        let certificate_signers = certificate_signers(&request.certificate, &auth_agg.committee);
+        let mut response = auth_agg
             .process_certificate(request.clone(), client_addr)
             .instrument(tracing::debug_span!("aggregator_process_cert", ?tx_digest))
             .await
@@ -504,6 +673,7 @@ where
                 }
             })?;
 
+        response.certificate_signers = certificate_signers;
         Ok(response)
     }
 
@@ -607,12 +777,33 @@ where
     }
 }
 
+/// The signers of `certificate` and their voting power in `committee`, or `None` if the certificate
+/// names an authority that is not part of the committee.
+fn certificate_signers(
+    certificate: &CertifiedTransaction,
+    committee: &Committee,
+) -> Option<CertificateSigners> {
+    let signers = certificate
+        .auth_sig()
+        .authorities(committee)
+        .map(|name| name.copied())
+        .collect::<SuiResult<Vec<_>>>()
+        .ok()?;
+    let signed_stake = signers.iter().map(|name| committee.weight(name)).sum();
+    Some(CertificateSigners {
+        signers,
+        signed_stake,
+    })
+}
+
 pub struct QuorumDriverHandler<A: Clone> {
     quorum_driver: Arc<QuorumDriver<A>>,
     effects_subscriber: tokio::sync::broadcast::Receiver<QuorumDriverEffectsQueueResult>,
     quorum_driver_metrics: Arc<QuorumDriverMetrics>,
     reconfig_observer: Arc<dyn ReconfigObserver<A> + Sync + Send>,
-    _processor_handle: JoinHandle<()>,
+    shutdown_notify: Arc<Notify>,
+    processor_handle: JoinHandle<()>,
+    reconfig_observer_handle: JoinHandle<()>,
 }
 
 impl<A> QuorumDriverHandler<A>
@@ -625,6 +816,8 @@ where
         reconfig_observer: Arc<dyn ReconfigObserver<A> + Sync + Send>,
         metrics: Arc<QuorumDriverMetrics>,
         max_retry_times: u32,
+        retry_base_backoff: Duration,
+        waiter_ttl: Option<Duration>,
     ) -> Self {
         let (task_tx, task_rx) = mpsc::channel::<QuorumDriverTask>(TASK_QUEUE_SIZE);
         let (subscriber_tx, subscriber_rx) =
@@ -636,32 +829,39 @@ where
             notifier,
             metrics.clone(),
             max_retry_times,
+            retry_base_backoff,
+            waiter_ttl,
         ));
+        Self::spawn_waiter_sweeper(&quorum_driver);
         let metrics_clone = metrics.clone();
+        let shutdown_notify = Arc::new(Notify::new());
         let processor_handle = {
             let quorum_driver_clone = quorum_driver.clone();
             spawn_monitored_task!(Self::task_queue_processor(
                 quorum_driver_clone,
                 task_rx,
-                metrics_clone
+                metrics_clone,
+                shutdown_notify.clone(),
             ))
         };
         let reconfig_observer_clone = reconfig_observer.clone();
-        {
+        let reconfig_observer_handle = {
             let quorum_driver_clone = quorum_driver.clone();
             spawn_monitored_task!({
                 async move {
                     let mut reconfig_observer_clone = reconfig_observer_clone.clone_boxed();
                     reconfig_observer_clone.run(quorum_driver_clone).await;
                 }
-            });
+            })
         };
         Self {
             quorum_driver,
             effects_subscriber: subscriber_rx,
             quorum_driver_metrics: metrics,
             reconfig_observer,
-            _processor_handle: processor_handle,
+            shutdown_notify,
+            processor_handle,
+            reconfig_observer_handle,
         }
     }
 
@@ -684,6 +884,68 @@ where
         self.quorum_driver.submit_transaction(request).await
     }
 
+    /// Submits `transaction` and waits until its effects are certified, or until `timeout`
+    /// elapses, in which case `SuiError::TimeoutError` is returned.
+    pub async fn execute_and_wait(
+        &self,
+        transaction: Transaction,
+        timeout: Duration,
+    ) -> SuiResult<TransactionEffects> {
+        let tx_digest = *transaction.digest();
+        let ticket = self
+            .submit_transaction(ExecuteTransactionRequestV3::new_v2(transaction))
+            .await?;
+        match tokio::time::timeout(timeout, ticket).await {
+            Ok(Ok(QuorumDriverResponse { effects_cert, .. })) => Ok(effects_cert.into_message()),
+            Ok(Err(QuorumDriverError::QuorumDriverInternalError(err))) => Err(err),
+            Ok(Err(err)) => Err(SuiError::QuorumDriverCommunicationError {
+                error: err.to_string(),
+            }),
+            Err(_) => {
+                debug!(?tx_digest, "Timed out waiting for transaction effects");
+                Err(SuiError::TimeoutError)
+            }
+        }
+    }
+
+    /// Executes a `certificate` formed elsewhere, e.g. obtained from another node, skipping the
+    /// transaction signing stage. The certificate must be signed by a quorum of the current
+    /// committee. The quorum driver does not execute transactions locally, so only
+    /// `ExecuteTransactionRequestType::WaitForEffectsCert` is supported.
+    pub async fn execute_certificate(
+        &self,
+        certificate: CertifiedTransaction,
+        request_type: ExecuteTransactionRequestType,
+    ) -> SuiResult<ExecuteTransactionResponse> {
+        if matches!(
+            request_type,
+            ExecuteTransactionRequestType::WaitForLocalExecution
+        ) {
+            return Err(SuiError::UnsupportedFeatureError {
+                error: "Certificates submitted to the quorum driver are not executed locally"
+                    .to_string(),
+            });
+        }
+        certificate.verify_committee_sigs_only(&self.quorum_driver.clone_committee())?;
+
+        let ticket = self.quorum_driver.submit_certificate(certificate).await?;
+        match ticket.await {
+            Ok(QuorumDriverResponse {
+                effects_cert,
+                events,
+                ..
+            }) => Ok(ExecuteTransactionResponse::EffectsCert(Box::new((
+                FinalizedEffects::new_from_effects_cert(effects_cert.into()),
+                events.unwrap_or_default(),
+                false,
+            )))),
+            Err(QuorumDriverError::QuorumDriverInternalError(err)) => Err(err),
+            Err(err) => Err(SuiError::QuorumDriverCommunicationError {
+                error: err.to_string(),
+            }),
+        }
+    }
+
     /// Create a new `QuorumDriverHandler` based on the same AuthorityAggregator.
     /// Note: the new `QuorumDriverHandler` will have a new `ArcSwap<AuthorityAggregator>`
     /// that is NOT tied to the original one. So if there are multiple QuorumDriver(Handler)
@@ -700,17 +962,23 @@ where
             notifier: Arc::new(NotifyRead::new()),
             metrics: self.quorum_driver_metrics.clone(),
             max_retry_times: self.quorum_driver.max_retry_times,
+            retry_base_backoff: self.quorum_driver.retry_base_backoff,
+            waiter_ttl: self.quorum_driver.waiter_ttl,
+            task_statuses: QuorumDriver::<A>::new_task_statuses(),
         });
+        Self::spawn_waiter_sweeper(&quorum_driver);
         let metrics = self.quorum_driver_metrics.clone();
+        let shutdown_notify = Arc::new(Notify::new());
         let processor_handle = {
             let quorum_driver_copy = quorum_driver.clone();
             spawn_monitored_task!(Self::task_queue_processor(
                 quorum_driver_copy,
                 task_rx,
                 metrics,
+                shutdown_notify.clone(),
             ))
         };
-        {
+        let reconfig_observer_handle = {
             let quorum_driver_copy = quorum_driver.clone();
             let reconfig_observer = self.reconfig_observer.clone();
             spawn_monitored_task!({
@@ -726,14 +994,56 @@ where
             effects_subscriber: subscriber_rx,
             quorum_driver_metrics: self.quorum_driver_metrics.clone(),
             reconfig_observer: self.reconfig_observer.clone(),
-            _processor_handle: processor_handle,
+            shutdown_notify,
+            processor_handle,
+            reconfig_observer_handle,
+        }
+    }
+
+    /// Stops the reconfig observer and the task queue processor. New tasks are rejected from this
+    /// point on, tasks already queued are still processed, and the processor waits up to
+    /// `SHUTDOWN_DRAIN_TIMEOUT` for in-flight tasks to finish before terminating. Retries of
+    /// in-flight tasks cannot be enqueued anymore, so their waiters are failed with
+    /// `QuorumDriverError::QuorumDriverInternalError`.
+    pub async fn shutdown(self) {
+        self.reconfig_observer_handle.abort();
+        self.shutdown_notify.notify_one();
+        if let Err(err) = self.processor_handle.await {
+            error!("Quorum driver task queue processor failed: {:?}", err);
         }
     }
 
+    /// Periodically expires waiters older than the quorum driver's `waiter_ttl`, if it has one.
+    /// The sweep runs once per TTL, so a waiter is resolved between one and two TTLs after it was
+    /// registered. The task exits once the quorum driver is dropped.
+    fn spawn_waiter_sweeper(quorum_driver: &Arc<QuorumDriver<A>>) {
+        let Some(ttl) = quorum_driver.waiter_ttl else {
+            return;
+        };
+        let quorum_driver = Arc::downgrade(quorum_driver);
+        spawn_monitored_task!(async move {
+            let mut interval = tokio::time::interval(ttl);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            // The first tick completes immediately, when there is nothing to expire yet.
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                let Some(quorum_driver) = quorum_driver.upgrade() else {
+                    return;
+                };
+                quorum_driver.expire_stale_waiters(ttl);
+            }
+        });
+    }
+
     pub fn clone_quorum_driver(&self) -> Arc<QuorumDriver<A>> {
         self.quorum_driver.clone()
     }
 
+    /// Subscribes to the results of all transactions processed from now on. Every subscriber
+    /// receives every result. Results are buffered per subscriber up to `EFFECTS_QUEUE_SIZE`; a
+    /// subscriber that falls further behind gets `RecvError::Lagged` and skips the oldest results,
+    /// rather than holding up the quorum driver.
     pub fn subscribe_to_effects(
         &self,
     ) -> tokio::sync::broadcast::Receiver<QuorumDriverEffectsQueueResult> {
@@ -748,6 +1058,15 @@ where
         self.quorum_driver.current_epoch()
     }
 
+    /// Returns the status of a transaction submitted to this handler, e.g. for callers that used
+    /// `ImmediateReturn` and did not wait for the outcome.
+    pub fn query_transaction_status(
+        &self,
+        tx_digest: &TransactionDigest,
+    ) -> Option<QuorumTaskStatus> {
+        self.quorum_driver.query_transaction_status(tx_digest)
+    }
+
     /// Process a QuorumDriverTask.
     /// The function has no return value - the corresponding actions of task result
     /// are performed in this call.
@@ -769,12 +1088,14 @@ where
             None => match quorum_driver
                 .process_transaction(transaction.clone(), client_addr)
                 .await
+                .tap(|_| quorum_driver.observe_stage_latency("process_transaction", timer))
             {
                 Ok(ProcessTransactionResult::Certified {
                     certificate,
                     newly_formed,
                 }) => {
                     debug!(?tx_digest, "Transaction processing succeeded");
+                    quorum_driver.set_transaction_status(tx_digest, QuorumTaskStatus::CertFormed);
                     (certificate, newly_formed)
                 }
                 Ok(ProcessTransactionResult::Executed(effects_cert, events)) => {
@@ -788,6 +1109,7 @@ where
                         input_objects: None,
                         output_objects: None,
                         auxiliary_data: None,
+                        certificate_signers: None,
                     };
                     quorum_driver.notify(transaction, &Ok(response), old_retry_times + 1);
                     return;
@@ -808,6 +1130,7 @@ where
             Some(tx_cert) => (tx_cert, false),
         };
 
+        let cert_timer = Instant::now();
         let response = match quorum_driver
             .process_certificate(
                 HandleCertificateRequestV3 {
@@ -820,6 +1143,7 @@ where
                 client_addr,
             )
             .await
+            .tap(|_| quorum_driver.observe_stage_latency("process_certificate", cert_timer))
         {
             Ok(response) => {
                 debug!(?tx_digest, "Certificate processing succeeded");
@@ -883,6 +1207,26 @@ where
                     tx_cert,
                     old_retry_times,
                     client_addr,
+                    None,
+                ));
+            }
+            Some(QuorumDriverError::QuorumNotReached {
+                collected_stake,
+                responses,
+                ..
+            }) => {
+                let qd_error = QuorumDriverError::QuorumNotReached {
+                    total_attempts: old_retry_times + 1,
+                    collected_stake,
+                    responses,
+                };
+                debug!(?tx_digest, "Failed to {action}: {} - Retrying", qd_error);
+                spawn_monitored_task!(quorum_driver.enqueue_again_maybe(
+                    request.clone(),
+                    tx_cert,
+                    old_retry_times,
+                    client_addr,
+                    Some(qd_error),
                 ));
             }
             Some(QuorumDriverError::SystemOverloadRetryAfter {
@@ -914,9 +1258,26 @@ where
         quorum_driver: Arc<QuorumDriver<A>>,
         mut task_receiver: Receiver<QuorumDriverTask>,
         metrics: Arc<QuorumDriverMetrics>,
+        shutdown_notify: Arc<Notify>,
     ) {
         let limit = Arc::new(Semaphore::new(TASK_QUEUE_SIZE));
-        while let Some(task) = task_receiver.recv().await {
+        let mut shutting_down = false;
+        loop {
+            let task = tokio::select! {
+                _ = shutdown_notify.notified(), if !shutting_down => {
+                    // Reject new tasks, but keep receiving until the queue is drained.
+                    task_receiver.close();
+                    shutting_down = true;
+                    continue;
+                }
+                task = task_receiver.recv() => task,
+            };
+            let Some(task) = task else {
+                break;
+            };
+            metrics.task_queue_depth.dec();
+            metrics.current_requests_in_flight.dec();
+
             // hold semaphore permit until task completes. unwrap ok because we never close
             // the semaphore in this context.
             let limit = limit.clone();
@@ -930,16 +1291,34 @@ where
                 .is_none()
             {
                 // Not ready for next attempt yet, re-enqueue
-                let _ = quorum_driver.enqueue_task(task).await;
+                let transaction = task.request.transaction.clone();
+                let total_attempts = task.retry_times + 1;
+                let _ = quorum_driver.enqueue_task(task).await.tap_err(|err| {
+                    quorum_driver.notify_enqueue_failure(&transaction, err, total_attempts)
+                });
                 continue;
             }
-            metrics.current_requests_in_flight.dec();
+            metrics
+                .task_queue_latency
+                .observe(task.next_retry_after.elapsed().as_secs_f64());
             let qd = quorum_driver.clone();
             spawn_monitored_task!(async move {
                 let _guard = permit;
                 QuorumDriverHandler::process_task(qd, task).await
             });
         }
+
+        // Every in-flight task holds a permit, so acquiring all of them waits for those tasks to
+        // finish.
+        if tokio::time::timeout(
+            SHUTDOWN_DRAIN_TIMEOUT,
+            limit.acquire_many(TASK_QUEUE_SIZE as u32),
+        )
+        .await
+        .is_err()
+        {
+            warn!("Timed out waiting for in-flight quorum driver tasks to finish");
+        }
     }
 }
 
@@ -949,6 +1328,8 @@ pub struct QuorumDriverHandlerBuilder<A: Clone> {
     notifier: Option<Arc<NotifyRead<TransactionDigest, QuorumDriverResult>>>,
     reconfig_observer: Option<Arc<dyn ReconfigObserver<A> + Sync + Send>>,
     max_retry_times: u32,
+    retry_base_backoff: Duration,
+    waiter_ttl: Option<Duration>,
 }
 
 impl<A> QuorumDriverHandlerBuilder<A>
@@ -962,6 +1343,8 @@ where
             notifier: None,
             reconfig_observer: None,
             max_retry_times: TX_MAX_RETRY_TIMES,
+            retry_base_backoff: TX_RETRY_BASE_BACKOFF,
+            waiter_ttl: None,
         }
     }
 
@@ -987,6 +1370,23 @@ where
         self
     }
 
+    /// Retry transient failures up to `max_retry_times` times, backing off for
+    /// `retry_base_backoff` before the first retry and doubling the backoff for every
+    /// subsequent one.
+    pub fn with_retry_config(mut self, max_retry_times: u32, retry_base_backoff: Duration) -> Self {
+        self.max_retry_times = max_retry_times;
+        self.retry_base_backoff = retry_base_backoff;
+        self
+    }
+
+    /// Fail waiters for transaction results with `QuorumDriverError::TimeoutBeforeFinality` once
+    /// they have gone unresolved for `waiter_ttl`, so that waiters for transactions that never
+    /// finalize do not accumulate.
+    pub fn with_waiter_ttl(mut self, waiter_ttl: Duration) -> Self {
+        self.waiter_ttl = Some(waiter_ttl);
+        self
+    }
+
     pub fn start(self) -> QuorumDriverHandler<A> {
         QuorumDriverHandler::new(
             self.validators,
@@ -997,6 +1397,8 @@ where
                 .expect("Reconfig observer is missing"),
             self.metrics,
             self.max_retry_times,
+            self.retry_base_backoff,
+            self.waiter_ttl,
         )
     }
 }
@@ -2,12 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::quorum_driver::reconfig_observer::DummyReconfigObserver;
-use crate::quorum_driver::{AuthorityAggregator, QuorumDriverHandlerBuilder};
+use crate::quorum_driver::{AuthorityAggregator, QuorumDriverHandlerBuilder, QuorumTaskStatus};
 use crate::test_authority_clients::LocalAuthorityClient;
 use crate::test_authority_clients::LocalAuthorityClientFaultConfig;
 use crate::test_utils::make_transfer_sui_transaction;
 use crate::{quorum_driver::QuorumDriverMetrics, test_utils::init_local_authorities};
 use mysten_common::sync::notify_read::{NotifyRead, Registration};
+use std::collections::BTreeMap;
 use std::net::SocketAddr;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
@@ -15,11 +16,14 @@ use std::time::Duration;
 use sui_macros::{register_fail_point, sim_test};
 use sui_types::base_types::SuiAddress;
 use sui_types::base_types::TransactionDigest;
+use sui_types::committee::CommitteeTrait;
 use sui_types::crypto::{deterministic_random_account_key, get_key_pair, AccountKeyPair};
 use sui_types::effects::TransactionEffectsAPI;
+use sui_types::error::SuiError;
 use sui_types::object::{generate_test_gas_objects, Object};
 use sui_types::quorum_driver_types::{
-    ExecuteTransactionRequestV3, QuorumDriverError, QuorumDriverResponse, QuorumDriverResult,
+    ExecuteTransactionRequestType, ExecuteTransactionRequestV3, ExecuteTransactionResponse,
+    QuorumDriverError, QuorumDriverResponse, QuorumDriverResult,
 };
 use sui_types::transaction::Transaction;
 use tokio::time::timeout;
@@ -128,6 +132,225 @@ async fn test_quorum_driver_submit_transaction_no_ticket() {
     handle.await.unwrap();
 }
 
+#[tokio::test]
+async fn test_quorum_driver_execute_and_wait() {
+    let (aggregator, tx) = setup().await;
+    let digest = *tx.digest();
+
+    let quorum_driver_handler = QuorumDriverHandlerBuilder::new(
+        Arc::new(aggregator),
+        Arc::new(QuorumDriverMetrics::new_for_tests()),
+    )
+    .with_reconfig_observer(Arc::new(DummyReconfigObserver {}))
+    .start();
+
+    let effects = quorum_driver_handler
+        .execute_and_wait(tx, Duration::from_secs(30))
+        .await
+        .unwrap();
+    assert_eq!(*effects.transaction_digest(), digest);
+    assert!(effects.status().is_ok());
+}
+
+#[tokio::test]
+async fn test_quorum_driver_shutdown() {
+    let (aggregator, tx) = setup().await;
+    let digest = *tx.digest();
+
+    let quorum_driver_handler = QuorumDriverHandlerBuilder::new(
+        Arc::new(aggregator),
+        Arc::new(QuorumDriverMetrics::new_for_tests()),
+    )
+    .with_reconfig_observer(Arc::new(DummyReconfigObserver {}))
+    .start();
+    let quorum_driver = quorum_driver_handler.clone_quorum_driver();
+
+    let ticket = quorum_driver_handler
+        .submit_transaction(ExecuteTransactionRequestV3::new_v2(tx.clone()))
+        .await
+        .unwrap();
+
+    // Shutdown only returns once the processor task has terminated.
+    timeout(Duration::from_secs(30), quorum_driver_handler.shutdown())
+        .await
+        .expect("Quorum driver should shut down");
+
+    // Work submitted before the shutdown is drained.
+    verify_ticket_response(ticket, &digest).await;
+
+    // No new work is accepted, and the rejected submission is not left pending.
+    let err = quorum_driver
+        .submit_transaction(ExecuteTransactionRequestV3::new_v2(tx))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, SuiError::QuorumDriverCommunicationError { .. }),
+        "{:?}",
+        err
+    );
+    assert_eq!(
+        quorum_driver.query_transaction_status(&digest),
+        Some(QuorumTaskStatus::Failed(
+            QuorumDriverError::QuorumDriverInternalError(err)
+        ))
+    );
+}
+
+#[tokio::test]
+async fn test_quorum_driver_shutdown_fails_pending_retries() {
+    let (mut aggregator, tx) = setup().await;
+    let digest = *tx.digest();
+
+    // Every validator rejects the transaction on the server/client epoch mismatch, so the quorum
+    // driver keeps retrying it.
+    let mut committee = aggregator.clone_inner_committee_test_only();
+    committee.epoch = 10;
+    aggregator.committee = Arc::new(committee);
+
+    let quorum_driver_handler = QuorumDriverHandlerBuilder::new(
+        Arc::new(aggregator),
+        Arc::new(QuorumDriverMetrics::new_for_tests()),
+    )
+    .with_reconfig_observer(Arc::new(DummyReconfigObserver {}))
+    .with_retry_config(10, Duration::from_secs(1))
+    .start();
+    let quorum_driver = quorum_driver_handler.clone_quorum_driver();
+
+    let ticket = quorum_driver_handler
+        .submit_transaction(ExecuteTransactionRequestV3::new_v2(tx))
+        .await
+        .unwrap();
+
+    // Let the first attempt fail, so that the transaction is backing off before its retry.
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    timeout(Duration::from_secs(30), quorum_driver_handler.shutdown())
+        .await
+        .expect("Quorum driver should shut down");
+
+    // The retry cannot be enqueued after the shutdown, which resolves the waiter.
+    match timeout(Duration::from_secs(10), ticket).await {
+        Ok(Err(QuorumDriverError::QuorumDriverInternalError(
+            SuiError::QuorumDriverCommunicationError { .. },
+        ))) => (),
+        result => panic!("Expected the retry to fail to enqueue, got {:?}", result),
+    }
+    assert!(matches!(
+        quorum_driver.query_transaction_status(&digest),
+        Some(QuorumTaskStatus::Failed(
+            QuorumDriverError::QuorumDriverInternalError(
+                SuiError::QuorumDriverCommunicationError { .. }
+            )
+        ))
+    ));
+}
+
+#[tokio::test]
+async fn test_quorum_driver_execute_and_wait_timeout() {
+    let (mut aggregator, tx) = setup().await;
+
+    // Make validators stall the transaction by always asking to retry much later.
+    let fault_config = LocalAuthorityClientFaultConfig {
+        overload_retry_after_handle_transaction: Some(Duration::from_secs(30)),
+        ..Default::default()
+    };
+    let mut clients = aggregator.clone_inner_clients_test_only();
+    for client in &mut clients.values_mut() {
+        client.authority_client_mut().fault_config = fault_config;
+    }
+    let clients = clients.into_iter().map(|(k, v)| (k, Arc::new(v))).collect();
+    aggregator.authority_clients = Arc::new(clients);
+
+    let quorum_driver_handler = QuorumDriverHandlerBuilder::new(
+        Arc::new(aggregator),
+        Arc::new(QuorumDriverMetrics::new_for_tests()),
+    )
+    .with_reconfig_observer(Arc::new(DummyReconfigObserver {}))
+    .start();
+
+    let err = quorum_driver_handler
+        .execute_and_wait(tx, Duration::from_secs(2))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, SuiError::TimeoutError), "{:?}", err);
+}
+
+#[tokio::test]
+async fn test_quorum_driver_reports_quorum_not_reached() {
+    let (mut aggregator, tx) = setup().await;
+
+    // Two of the four validators fail, which leaves the transaction short of a quorum. Their
+    // errors are retryable: non-retryable errors from two validators would reach the validity
+    // threshold, and fail the transaction outright.
+    let fault_config = LocalAuthorityClientFaultConfig {
+        fail_retryable_before_handle_transaction: true,
+        ..Default::default()
+    };
+    let mut clients = aggregator.clone_inner_clients_test_only();
+    let failing: Vec<_> = clients.keys().take(2).cloned().collect();
+    for name in &failing {
+        clients
+            .get_mut(name)
+            .unwrap()
+            .authority_client_mut()
+            .fault_config = fault_config;
+    }
+    let clients = clients.into_iter().map(|(k, v)| (k, Arc::new(v))).collect();
+    aggregator.authority_clients = Arc::new(clients);
+
+    let quorum_driver_handler = QuorumDriverHandlerBuilder::new(
+        Arc::new(aggregator),
+        Arc::new(QuorumDriverMetrics::new_for_tests()),
+    )
+    .with_reconfig_observer(Arc::new(DummyReconfigObserver {}))
+    .with_retry_config(1, Duration::from_millis(10))
+    .start();
+
+    let ticket = quorum_driver_handler
+        .submit_transaction(ExecuteTransactionRequestV3::new_v2(tx))
+        .await
+        .unwrap();
+    let Ok(Err(error)) = timeout(Duration::from_secs(10), ticket).await else {
+        panic!("Expected QuorumNotReached");
+    };
+    let message = error.to_string();
+    let QuorumDriverError::QuorumNotReached {
+        total_attempts,
+        collected_stake,
+        responses,
+    } = error
+    else {
+        panic!("Expected QuorumNotReached, got {message}");
+    };
+
+    // Validator responses are summarized rather than printed one by one.
+    let rpc_error = SuiError::RpcError(
+        "Mock retryable error before handle_transaction".to_owned(),
+        "Unavailable".to_owned(),
+    );
+    assert_eq!(
+        message,
+        format!(
+            "Transaction failed to reach a quorum of validators after 2 attempts, collected 5000 stake. \
+             2 of 4 validators signed; 2 returned: {rpc_error}."
+        )
+    );
+    assert_eq!(total_attempts, 2);
+    assert_eq!(collected_stake, 5000);
+    let responses: BTreeMap<_, _> = responses.into_iter().collect();
+    assert_eq!(responses.len(), 4);
+    for (name, response) in &responses {
+        if failing.contains(name) {
+            assert!(
+                matches!(response, Err(SuiError::RpcError(msg, _)) if msg == "Mock retryable error before handle_transaction"),
+                "{:?}",
+                response
+            );
+        } else {
+            assert!(response.is_ok(), "{:?}", response);
+        }
+    }
+}
+
 async fn verify_ticket_response<'a>(
     ticket: Registration<'a, TransactionDigest, QuorumDriverResult>,
     tx_digest: &TransactionDigest,
@@ -136,6 +359,70 @@ async fn verify_ticket_response<'a>(
     assert_eq!(effects_cert.data().transaction_digest(), tx_digest);
 }
 
+#[tokio::test]
+async fn test_quorum_driver_query_transaction_status() {
+    let (aggregator, tx) = setup().await;
+    let digest = *tx.digest();
+
+    let quorum_driver_handler = QuorumDriverHandlerBuilder::new(
+        Arc::new(aggregator),
+        Arc::new(QuorumDriverMetrics::new_for_tests()),
+    )
+    .with_reconfig_observer(Arc::new(DummyReconfigObserver {}))
+    .start();
+    assert_eq!(
+        quorum_driver_handler.query_transaction_status(&digest),
+        None
+    );
+
+    let mut effects_subscriber = quorum_driver_handler.subscribe_to_effects();
+    quorum_driver_handler
+        .submit_transaction_no_ticket(ExecuteTransactionRequestV3::new_v2(tx), None)
+        .await
+        .unwrap();
+    assert!(quorum_driver_handler
+        .query_transaction_status(&digest)
+        .is_some());
+
+    effects_subscriber.recv().await.unwrap().unwrap();
+    assert_eq!(
+        quorum_driver_handler.query_transaction_status(&digest),
+        Some(QuorumTaskStatus::Executed)
+    );
+}
+
+#[tokio::test]
+async fn test_quorum_driver_task_queue_depth() {
+    let (aggregator, tx) = setup().await;
+    let digest = *tx.digest();
+    let metrics = Arc::new(QuorumDriverMetrics::new_for_tests());
+
+    let quorum_driver_handler =
+        QuorumDriverHandlerBuilder::new(Arc::new(aggregator), metrics.clone())
+            .with_reconfig_observer(Arc::new(DummyReconfigObserver {}))
+            .start();
+    assert_eq!(metrics.task_queue_depth.get(), 0);
+
+    // The task queue processor does not get to run before this test yields, so the task is still
+    // queued.
+    let ticket = quorum_driver_handler
+        .submit_transaction(ExecuteTransactionRequestV3::new_v2(tx))
+        .await
+        .unwrap();
+    assert_eq!(metrics.task_queue_depth.get(), 1);
+
+    verify_ticket_response(ticket, &digest).await;
+    assert_eq!(metrics.task_queue_depth.get(), 0);
+    assert_eq!(metrics.task_queue_latency.get_sample_count(), 1);
+    assert_eq!(
+        metrics
+            .task_stage_latency
+            .with_label_values(&["process_transaction"])
+            .get_sample_count(),
+        1
+    );
+}
+
 #[tokio::test]
 async fn test_quorum_driver_with_given_notify_read() {
     let (aggregator, tx) = setup().await;
@@ -179,6 +466,7 @@ async fn test_quorum_driver_with_given_notify_read() {
 async fn test_quorum_driver_update_validators_and_max_retry_times() {
     telemetry_subscribers::init_for_testing();
     let (mut aggregator, tx) = setup().await;
+    let digest = *tx.digest();
     let arc_aggregator = Arc::new(aggregator.clone());
 
     let quorum_driver_handler = Arc::new(
@@ -206,9 +494,20 @@ async fn test_quorum_driver_update_validators_and_max_retry_times() {
             .unwrap();
         // We have a timeout here to make the test fail fast if fails
         match tokio::time::timeout(Duration::from_secs(20), ticket).await {
-            Ok(Err(QuorumDriverError::FailedWithTransientErrorAfterMaximumAttempts { total_attempts })) => assert_eq!(total_attempts, 4),
-            _ => panic!("The transaction should err on SafeClient epoch check mismatch, be retried 3 times and raise QuorumDriverError::FailedWithTransientErrorAfterMaximumAttempts error"),
+            Ok(Err(QuorumDriverError::QuorumNotReached { total_attempts, collected_stake, responses })) => {
+                assert_eq!(total_attempts, 4);
+                assert_eq!(collected_stake, 0);
+                assert_eq!(responses.len(), 4);
+                assert!(responses.iter().all(|(_, response)| response.is_err()));
+            }
+            _ => panic!("The transaction should err on SafeClient epoch check mismatch, be retried 3 times and raise QuorumDriverError::QuorumNotReached error"),
         };
+        assert!(matches!(
+            quorum_driver.query_transaction_status(&digest),
+            Some(QuorumTaskStatus::Failed(
+                QuorumDriverError::QuorumNotReached { .. }
+            ))
+        ));
     });
 
     // Update authority aggregator with a new epoch number, and let quorum driver know.
@@ -226,6 +525,70 @@ async fn test_quorum_driver_update_validators_and_max_retry_times() {
     handle.await.unwrap();
 }
 
+#[tokio::test]
+async fn test_quorum_driver_retry_config() {
+    let (mut aggregator, tx) = setup().await;
+
+    // Every validator rejects the transaction on the server/client epoch mismatch, so the quorum
+    // driver retries until it runs out of retries.
+    let mut committee = aggregator.clone_inner_committee_test_only();
+    committee.epoch = 10;
+    aggregator.committee = Arc::new(committee);
+
+    let quorum_driver_handler = QuorumDriverHandlerBuilder::new(
+        Arc::new(aggregator),
+        Arc::new(QuorumDriverMetrics::new_for_tests()),
+    )
+    .with_reconfig_observer(Arc::new(DummyReconfigObserver {}))
+    .with_retry_config(5, Duration::from_millis(10))
+    .start();
+
+    let quorum_driver = quorum_driver_handler.clone_quorum_driver();
+    assert_eq!(quorum_driver.retry_backoff(0), Duration::from_millis(10));
+    assert_eq!(quorum_driver.retry_backoff(4), Duration::from_millis(160));
+
+    // With the default 200ms base backoff, 5 retries would take over 6s.
+    let ticket = quorum_driver_handler
+        .submit_transaction(ExecuteTransactionRequestV3::new_v2(tx))
+        .await
+        .unwrap();
+    match timeout(Duration::from_secs(3), ticket).await {
+        Ok(Err(QuorumDriverError::QuorumNotReached { .. })) => (),
+        result => panic!("Expected QuorumNotReached after retrying, got {:?}", result),
+    }
+}
+
+#[tokio::test]
+async fn test_quorum_driver_expires_stale_waiters() {
+    let (aggregator, tx) = setup().await;
+    let notifier = Arc::new(NotifyRead::new());
+    let quorum_driver_handler = QuorumDriverHandlerBuilder::new(
+        Arc::new(aggregator),
+        Arc::new(QuorumDriverMetrics::new_for_tests()),
+    )
+    .with_notifier(notifier.clone())
+    .with_reconfig_observer(Arc::new(DummyReconfigObserver {}))
+    .with_waiter_ttl(Duration::from_millis(100))
+    .start();
+
+    // Register interest in a transaction that is never submitted, so it is never notified.
+    let digest = *tx.digest();
+    let ticket = notifier.register_one(&digest);
+    assert_eq!(notifier.num_pending(), 1);
+
+    match timeout(Duration::from_secs(5), ticket).await {
+        Ok(Err(QuorumDriverError::TimeoutBeforeFinality)) => (),
+        result => panic!("Expected TimeoutBeforeFinality, got {:?}", result),
+    }
+    assert_eq!(notifier.num_pending(), 0);
+    assert!(matches!(
+        quorum_driver_handler.query_transaction_status(&digest),
+        Some(QuorumTaskStatus::Failed(
+            QuorumDriverError::TimeoutBeforeFinality
+        ))
+    ));
+}
+
 #[tokio::test]
 async fn test_quorum_driver_object_locked() -> Result<(), anyhow::Error> {
     let gas_objects = generate_test_gas_objects();
@@ -606,3 +969,87 @@ async fn test_quorum_driver_handling_overload_and_retry() {
         }
     }
 }
+
+#[tokio::test]
+async fn test_quorum_driver_reports_certificate_signers() {
+    let (aggregator, tx) = setup().await;
+    let digest = *tx.digest();
+    let committee = aggregator.committee.clone();
+
+    let quorum_driver_handler = Arc::new(
+        QuorumDriverHandlerBuilder::new(
+            Arc::new(aggregator),
+            Arc::new(QuorumDriverMetrics::new_for_tests()),
+        )
+        .with_reconfig_observer(Arc::new(DummyReconfigObserver {}))
+        .start(),
+    );
+    let mut subscriber = quorum_driver_handler.subscribe_to_effects();
+    let ticket = quorum_driver_handler
+        .submit_transaction(ExecuteTransactionRequestV3::new_v2(tx))
+        .await
+        .unwrap();
+    verify_ticket_response(ticket, &digest).await;
+
+    let (
+        tx,
+        QuorumDriverResponse {
+            certificate_signers,
+            ..
+        },
+    ) = subscriber.recv().await.unwrap().unwrap();
+    assert_eq!(tx.digest(), &digest);
+
+    let certificate_signers = certificate_signers.expect("transaction was certified");
+    assert!(certificate_signers.signed_stake >= committee.quorum_threshold());
+    assert_eq!(
+        certificate_signers.signed_stake,
+        certificate_signers
+            .signers
+            .iter()
+            .map(|name| committee.weight(name))
+            .sum::<u64>()
+    );
+}
+
+#[tokio::test]
+async fn test_quorum_driver_execute_certificate() {
+    let (aggregator, tx) = setup().await;
+    let digest = *tx.digest();
+    let certificate = aggregator
+        .process_transaction(tx, None)
+        .await
+        .unwrap()
+        .into_cert_for_testing();
+
+    let quorum_driver_handler = QuorumDriverHandlerBuilder::new(
+        Arc::new(aggregator),
+        Arc::new(QuorumDriverMetrics::new_for_tests()),
+    )
+    .with_reconfig_observer(Arc::new(DummyReconfigObserver {}))
+    .start();
+
+    // Certificates from another epoch are rejected before being submitted.
+    let mut wrong_epoch_certificate = certificate.clone();
+    wrong_epoch_certificate.auth_sig_mut_for_testing().epoch += 1;
+    let err = quorum_driver_handler
+        .execute_certificate(
+            wrong_epoch_certificate,
+            ExecuteTransactionRequestType::WaitForEffectsCert,
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, SuiError::WrongEpoch { .. }), "{err:?}");
+
+    let ExecuteTransactionResponse::EffectsCert(response) = quorum_driver_handler
+        .execute_certificate(
+            certificate,
+            ExecuteTransactionRequestType::WaitForEffectsCert,
+        )
+        .await
+        .unwrap();
+    let (effects, _, is_executed_locally) = *response;
+    assert_eq!(*effects.effects.transaction_digest(), digest);
+    assert!(effects.effects.status().is_ok());
+    assert!(!is_executed_locally);
+}
@@ -5,7 +5,8 @@ use parking_lot::Mutex;
 use std::sync::Arc;
 use sui_types::storage::ObjectStore;
 
-use sui_types::base_types::TransactionDigest;
+use sui_storage::indexes::IndexStore;
+use sui_types::base_types::{ObjectID, ObjectInfo, SuiAddress, TransactionDigest};
 use sui_types::committee::Committee;
 use sui_types::committee::EpochId;
 use sui_types::digests::TransactionEventsDigest;
@@ -27,6 +28,7 @@ use sui_types::transaction::VerifiedTransaction;
 use crate::checkpoints::CheckpointStore;
 use crate::epoch::committee_store::CommitteeStore;
 use crate::execution_cache::ExecutionCacheTraitPointers;
+use tracing::error;
 
 #[derive(Clone)]
 pub struct RocksDbStore {
@@ -34,6 +36,8 @@ pub struct RocksDbStore {
 
     committee_store: Arc<CommitteeStore>,
     checkpoint_store: Arc<CheckpointStore>,
+    // owner index, only maintained by full nodes with index processing enabled
+    indexes: Option<Arc<IndexStore>>,
     // in memory checkpoint watermark sequence numbers
     highest_verified_checkpoint: Arc<Mutex<Option<u64>>>,
     highest_synced_checkpoint: Arc<Mutex<Option<u64>>>,
@@ -49,11 +53,18 @@ impl RocksDbStore {
             cache_traits,
             committee_store,
             checkpoint_store,
+            indexes: None,
             highest_verified_checkpoint: Arc::new(Mutex::new(None)),
             highest_synced_checkpoint: Arc::new(Mutex::new(None)),
         }
     }
 
+    /// Serve `ReadStore::get_owned_objects` from the owner index in `indexes`.
+    pub fn with_indexes(mut self, indexes: Arc<IndexStore>) -> Self {
+        self.indexes = Some(indexes);
+        self
+    }
+
     pub fn get_objects(&self, object_keys: &[ObjectKey]) -> Result<Vec<Option<Object>>, SuiError> {
         self.cache_traits
             .object_cache_reader
@@ -242,6 +253,20 @@ impl ReadStore for RocksDbStore {
     {
         todo!()
     }
+
+    fn get_owned_objects(
+        &self,
+        owner: SuiAddress,
+        cursor: Option<ObjectID>,
+    ) -> Result<Box<dyn Iterator<Item = ObjectInfo> + '_>, StorageError> {
+        let indexes = self.indexes.as_ref().ok_or_else(|| {
+            StorageError::missing("objects are not indexed by owner on this node")
+        })?;
+        let objects = indexes
+            .get_owner_objects_iterator(owner, cursor.unwrap_or(ObjectID::ZERO), None)
+            .map_err(StorageError::custom)?;
+        Ok(Box::new(objects))
+    }
 }
 
 impl ObjectStore for RocksDbStore {
@@ -275,7 +300,14 @@ impl WriteStore for RocksDbStore {
         {
             let next_committee = next_epoch_committee.iter().cloned().collect();
             let committee =
-                Committee::new(checkpoint.epoch().checked_add(1).unwrap(), next_committee);
+                Committee::new_checked(checkpoint.epoch().checked_add(1).unwrap(), next_committee)
+                    .map_err(|e| {
+                        error!(
+                            checkpoint = checkpoint.sequence_number,
+                            "Invalid next epoch committee in end of epoch checkpoint: {e:?}"
+                        );
+                        StorageError::custom(e)
+                    })?;
             self.insert_committee(committee)?;
         }
 
@@ -338,3 +370,57 @@ impl WriteStore for RocksDbStore {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::authority::test_authority_builder::TestAuthorityBuilder;
+    use crate::execution_cache::build_execution_cache_from_env;
+    use prometheus::Registry;
+    use sui_swarm_config::test_utils::CommitteeFixture;
+    use sui_types::base_types::AuthorityName;
+    use sui_types::committee::ProtocolVersion;
+    use sui_types::messages_checkpoint::ECMHLiveObjectSetDigest;
+
+    #[tokio::test]
+    async fn insert_checkpoint_rejects_invalid_next_epoch_committee() {
+        let state = TestAuthorityBuilder::new().build().await;
+        let committee = CommitteeFixture::generate(rand::rngs::OsRng, 0, 4);
+        let committee_store = Arc::new(CommitteeStore::new_for_testing(committee.committee()));
+        let tempdir = tempfile::tempdir().unwrap();
+        let checkpoint_store = CheckpointStore::new(tempdir.path());
+        let store = RocksDbStore::new(
+            build_execution_cache_from_env(&Registry::default(), &state.database_for_testing()),
+            committee_store.clone(),
+            checkpoint_store.clone(),
+        );
+
+        let (checkpoints, _, _, _) = committee.make_empty_checkpoints(1, None);
+        let empty = vec![];
+        let zero_stake = committee
+            .committee()
+            .voting_rights
+            .iter()
+            .map(|(name, _)| (*name, 0))
+            .collect();
+        let mut invalid_key = committee.committee().voting_rights.clone();
+        invalid_key[0].0 = AuthorityName::ZERO;
+        for next_epoch_committee in [empty, zero_stake, invalid_key] {
+            let (_, _, checkpoint) = committee.make_end_of_epoch_checkpoint(
+                checkpoints[0].clone(),
+                Some(EndOfEpochData {
+                    next_epoch_committee,
+                    next_epoch_protocol_version: ProtocolVersion::MIN,
+                    epoch_commitments: vec![ECMHLiveObjectSetDigest::default().into()],
+                }),
+            );
+
+            assert!(store.insert_checkpoint(&checkpoint).is_err());
+            assert!(committee_store.get_committee(&1).unwrap().is_none());
+            assert!(checkpoint_store
+                .get_checkpoint_by_digest(checkpoint.digest())
+                .unwrap()
+                .is_none());
+        }
+    }
+}
@@ -36,6 +36,7 @@ use sui_types::{
 #[derive(Clone, Copy, Default)]
 pub struct LocalAuthorityClientFaultConfig {
     pub fail_before_handle_transaction: bool,
+    pub fail_retryable_before_handle_transaction: bool,
     pub fail_after_handle_transaction: bool,
     pub fail_before_handle_confirmation: bool,
     pub fail_after_handle_confirmation: bool,
@@ -64,6 +65,12 @@ impl AuthorityAPI for LocalAuthorityClient {
         if self.fault_config.fail_before_handle_transaction {
             return Err(SuiError::from("Mock error before handle_transaction"));
         }
+        if self.fault_config.fail_retryable_before_handle_transaction {
+            return Err(SuiError::RpcError(
+                "Mock retryable error before handle_transaction".to_owned(),
+                "Unavailable".to_owned(),
+            ));
+        }
         let state = self.state.clone();
         let epoch_store = self.state.load_epoch_store_one_call_per_task();
         let transaction = epoch_store
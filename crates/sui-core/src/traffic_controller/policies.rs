@@ -4,7 +4,7 @@
 
 use std::{collections::HashMap, net::IpAddr, sync::Arc};
 
-use count_min_sketch::CountMinSketch32;
+use count_min_sketch::{CountMinSketch16, CountMinSketch32};
 use mysten_metrics::spawn_monitored_task;
 use parking_lot::RwLock;
 use std::collections::VecDeque;
@@ -12,7 +12,9 @@ use std::fmt::Debug;
 use std::hash::Hash;
 use std::time::Duration;
 use std::time::{Instant, SystemTime};
-use sui_types::traffic_control::{FreqThresholdConfig, PolicyConfig, PolicyType, Weight};
+use sui_types::traffic_control::{
+    FreqThresholdConfig, PolicyConfig, PolicyType, SketchWidth, Weight,
+};
 use tracing::info;
 
 #[derive(Hash, Eq, PartialEq, Debug)]
@@ -24,17 +26,77 @@ enum IpType {
 #[derive(Hash, Eq, PartialEq, Debug)]
 struct SketchKey(IpAddr, IpType);
 
+/// A Count Min Sketch whose counters have the number of bits given by a `SketchWidth`.
+enum CountMinSketch {
+    Bits16(CountMinSketch16<SketchKey>),
+    Bits32(CountMinSketch32<SketchKey>),
+}
+
+impl CountMinSketch {
+    fn new(width: SketchWidth, capacity: usize, probability: f64, tolerance: f64) -> Self {
+        match width {
+            SketchWidth::Bits16 => Self::Bits16(
+                CountMinSketch16::new(capacity, probability, tolerance)
+                    .expect("Failed to create CountMinSketch16"),
+            ),
+            SketchWidth::Bits32 => Self::Bits32(
+                CountMinSketch32::new(capacity, probability, tolerance)
+                    .expect("Failed to create CountMinSketch32"),
+            ),
+        }
+    }
+
+    fn estimate_memory(
+        width: SketchWidth,
+        capacity: usize,
+        probability: f64,
+        tolerance: f64,
+    ) -> usize {
+        match width {
+            SketchWidth::Bits16 => {
+                CountMinSketch16::<IpAddr>::estimate_memory(capacity, probability, tolerance)
+                    .expect("Failed to estimate memory for CountMinSketch16")
+            }
+            SketchWidth::Bits32 => {
+                CountMinSketch32::<IpAddr>::estimate_memory(capacity, probability, tolerance)
+                    .expect("Failed to estimate memory for CountMinSketch32")
+            }
+        }
+    }
+
+    fn increment(&mut self, key: &SketchKey) {
+        match self {
+            Self::Bits16(sketch) => sketch.increment(key),
+            Self::Bits32(sketch) => sketch.increment(key),
+        }
+    }
+
+    fn estimate(&self, key: &SketchKey) -> u32 {
+        match self {
+            Self::Bits16(sketch) => sketch.estimate(key) as u32,
+            Self::Bits32(sketch) => sketch.estimate(key),
+        }
+    }
+
+    fn clear(&mut self) {
+        match self {
+            Self::Bits16(sketch) => sketch.clear(),
+            Self::Bits32(sketch) => sketch.clear(),
+        }
+    }
+}
+
 pub struct TrafficSketch {
     /// Circular buffer Count Min Sketches representing a sliding window
-    /// of traffic data. Note that the 32 in CountMinSketch32 represents
-    /// the number of bits used to represent the count in the sketch. Since
-    /// we only count on a sketch for a window of `update_interval`, we only
+    /// of traffic data. The `SketchWidth` of the sketches is the number
+    /// of bits used to represent the count in the sketch. Since we only
+    /// count on a sketch for a window of `update_interval`, we only
     /// need enough precision to represent the max expected unique IP addresses
     /// we may see in that window. For a 10 second period, we might conservatively
-    /// expect 100,000, which can be represented in 17 bits, but not 16. We can
-    /// potentially lower the memory consumption by using CountMinSketch16, which
-    /// will reliably support up to ~65,000 unique IP addresses in the window.
-    sketches: VecDeque<CountMinSketch32<SketchKey>>,
+    /// expect 100,000, which can be represented in 17 bits, but not 16. Using
+    /// `SketchWidth::Bits16` halves the memory consumption, and will reliably
+    /// support up to ~65,000 unique IP addresses in the window.
+    sketches: VecDeque<CountMinSketch>,
     window_size: Duration,
     update_interval: Duration,
     last_reset_time: Instant,
@@ -48,6 +110,7 @@ impl TrafficSketch {
         sketch_capacity: usize,
         sketch_probability: f64,
         sketch_tolerance: f64,
+        sketch_width: SketchWidth,
     ) -> Self {
         // intentionally round down via integer division. We can't have a partial sketch
         let num_sketches = window_size.as_secs() / update_interval.as_secs();
@@ -75,24 +138,22 @@ impl TrafficSketch {
         );
         assert!(num_sketches <= 10, "Given parameters require too many sketches to be stored. Reduce window size or increase update interval.");
         let mem_estimate = (num_sketches as usize)
-            * CountMinSketch32::<IpAddr>::estimate_memory(
+            * CountMinSketch::estimate_memory(
+                sketch_width,
                 sketch_capacity,
                 sketch_probability,
                 sketch_tolerance,
-            )
-            .expect("Failed to estimate memory for CountMinSketch32");
+            );
         assert!(mem_estimate < 128_000_000, "Memory estimate for traffic sketch exceeds 128MB. Reduce window size or increase update interval.");
 
         let mut sketches = VecDeque::with_capacity(num_sketches as usize);
         for _ in 0..num_sketches {
-            sketches.push_back(
-                CountMinSketch32::<SketchKey>::new(
-                    sketch_capacity,
-                    sketch_probability,
-                    sketch_tolerance,
-                )
-                .expect("Failed to create CountMinSketch32"),
-            );
+            sketches.push_back(CountMinSketch::new(
+                sketch_width,
+                sketch_capacity,
+                sketch_probability,
+                sketch_tolerance,
+            ));
         }
         Self {
             sketches,
@@ -171,6 +232,7 @@ pub trait Policy {
 // not object safe, so we can't use a trait object instead
 pub enum TrafficControlPolicy {
     FreqThreshold(FreqThresholdPolicy),
+    ProxyAwareFreq(ProxyAwareFreqPolicy),
     NoOp(NoOpPolicy),
     // Test policies below this point
     TestNConnIP(TestNConnIPPolicy),
@@ -182,6 +244,7 @@ impl Policy for TrafficControlPolicy {
         match self {
             TrafficControlPolicy::NoOp(policy) => policy.handle_tally(tally),
             TrafficControlPolicy::FreqThreshold(policy) => policy.handle_tally(tally),
+            TrafficControlPolicy::ProxyAwareFreq(policy) => policy.handle_tally(tally),
             TrafficControlPolicy::TestNConnIP(policy) => policy.handle_tally(tally),
             TrafficControlPolicy::TestPanicOnInvocation(policy) => policy.handle_tally(tally),
         }
@@ -191,6 +254,7 @@ impl Policy for TrafficControlPolicy {
         match self {
             TrafficControlPolicy::NoOp(policy) => policy.policy_config(),
             TrafficControlPolicy::FreqThreshold(policy) => policy.policy_config(),
+            TrafficControlPolicy::ProxyAwareFreq(policy) => policy.policy_config(),
             TrafficControlPolicy::TestNConnIP(policy) => policy.policy_config(),
             TrafficControlPolicy::TestPanicOnInvocation(policy) => policy.policy_config(),
         }
@@ -210,6 +274,9 @@ impl TrafficControlPolicy {
             PolicyType::FreqThreshold(freq_threshold_config) => Self::FreqThreshold(
                 FreqThresholdPolicy::new(policy_config, freq_threshold_config),
             ),
+            PolicyType::ProxyAwareFreqThreshold(freq_threshold_config) => Self::ProxyAwareFreq(
+                ProxyAwareFreqPolicy::new(policy_config, freq_threshold_config),
+            ),
             PolicyType::TestNConnIP(n) => {
                 Self::TestNConnIP(TestNConnIPPolicy::new(policy_config, n).await)
             }
@@ -227,6 +294,8 @@ pub struct FreqThresholdPolicy {
     sketch: TrafficSketch,
     connection_threshold: u64,
     proxy_threshold: u64,
+    /// No IPs are blocked before this time.
+    enforce_after: Instant,
 }
 
 impl FreqThresholdPolicy {
@@ -240,6 +309,8 @@ impl FreqThresholdPolicy {
             sketch_capacity,
             sketch_probability,
             sketch_tolerance,
+            sketch_width,
+            warmup_window_secs,
         }: FreqThresholdConfig,
     ) -> Self {
         let sketch = TrafficSketch::new(
@@ -248,12 +319,14 @@ impl FreqThresholdPolicy {
             sketch_capacity,
             sketch_probability,
             sketch_tolerance,
+            sketch_width,
         );
         Self {
             config,
             sketch,
             connection_threshold,
             proxy_threshold,
+            enforce_after: Instant::now() + Duration::from_secs(warmup_window_secs),
         }
     }
 
@@ -280,6 +353,9 @@ impl FreqThresholdPolicy {
         } else {
             None
         };
+        if Instant::now() < self.enforce_after {
+            return PolicyResponse::default();
+        }
         PolicyResponse {
             block_connection_ip,
             block_proxy_ip,
@@ -291,6 +367,88 @@ impl FreqThresholdPolicy {
     }
 }
 
+pub struct ProxyAwareFreqPolicy {
+    config: PolicyConfig,
+    sketch: TrafficSketch,
+    connection_threshold: u64,
+    proxy_threshold: u64,
+    /// No IPs are blocked before this time.
+    enforce_after: Instant,
+}
+
+impl ProxyAwareFreqPolicy {
+    pub fn new(
+        config: PolicyConfig,
+        FreqThresholdConfig {
+            connection_threshold,
+            proxy_threshold,
+            window_size_secs,
+            update_interval_secs,
+            sketch_capacity,
+            sketch_probability,
+            sketch_tolerance,
+            sketch_width,
+            warmup_window_secs,
+        }: FreqThresholdConfig,
+    ) -> Self {
+        let sketch = TrafficSketch::new(
+            Duration::from_secs(window_size_secs),
+            Duration::from_secs(update_interval_secs),
+            sketch_capacity,
+            sketch_probability,
+            sketch_tolerance,
+            sketch_width,
+        );
+        Self {
+            config,
+            sketch,
+            connection_threshold,
+            proxy_threshold,
+            enforce_after: Instant::now() + Duration::from_secs(warmup_window_secs),
+        }
+    }
+
+    fn handle_tally(&mut self, tally: TrafficTally) -> PolicyResponse {
+        // Requests forwarded by a proxy are counted against the client they were forwarded
+        // for, so that a noisy client does not get the proxy, and every other client behind
+        // it, blocked.
+        let (ip, key, threshold) = match (tally.proxy_ip, tally.connection_ip) {
+            (Some(proxy_ip), _) => (
+                proxy_ip,
+                SketchKey(proxy_ip, IpType::Proxy),
+                self.proxy_threshold,
+            ),
+            (None, Some(connection_ip)) => (
+                connection_ip,
+                SketchKey(connection_ip, IpType::Connection),
+                self.connection_threshold,
+            ),
+            (None, None) => return PolicyResponse::default(),
+        };
+        self.sketch.increment_count(&key);
+        if Instant::now() < self.enforce_after
+            || self.sketch.get_request_rate(&key) < threshold as f64
+        {
+            return PolicyResponse::default();
+        }
+        if tally.proxy_ip.is_some() {
+            PolicyResponse {
+                block_connection_ip: None,
+                block_proxy_ip: Some(ip),
+            }
+        } else {
+            PolicyResponse {
+                block_connection_ip: Some(ip),
+                block_proxy_ip: None,
+            }
+        }
+    }
+
+    fn policy_config(&self) -> &PolicyConfig {
+        &self.config
+    }
+}
+
 ////////////// *** Test policies below this point *** //////////////
 
 #[derive(Clone)]
@@ -501,6 +659,99 @@ mod tests {
         }
     }
 
+    #[sim_test]
+    async fn test_proxy_aware_freq_policy() {
+        // Block clients behind a proxy at an average frequency of 2 requests per
+        // second as observed over a 5 second window.
+        let mut policy = TrafficControlPolicy::ProxyAwareFreq(ProxyAwareFreqPolicy::new(
+            PolicyConfig::default(),
+            FreqThresholdConfig {
+                connection_threshold: 5,
+                proxy_threshold: 2,
+                window_size_secs: 5,
+                update_interval_secs: 1,
+                ..Default::default()
+            },
+        ));
+        // alice and bob are distinct clients behind the same proxy, so their requests
+        // reach the validator from the same connection IP, forwarded for different client IPs
+        let proxy = Some(IpAddr::V4(Ipv4Addr::new(8, 7, 6, 5)));
+        let alice = TrafficTally {
+            connection_ip: proxy,
+            proxy_ip: Some(IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4))),
+            error_weight: Weight::zero(),
+            timestamp: SystemTime::now(),
+        };
+        let bob = TrafficTally {
+            connection_ip: proxy,
+            proxy_ip: Some(IpAddr::V4(Ipv4Addr::new(4, 3, 2, 1))),
+            error_weight: Weight::zero(),
+            timestamp: SystemTime::now(),
+        };
+
+        // alice sends a couple of requests, well below the threshold
+        for _ in 0..2 {
+            let response = policy.handle_tally(alice.clone());
+            assert_eq!(response.block_connection_ip, None);
+            assert_eq!(response.block_proxy_ip, None);
+        }
+
+        // bob spams 10 requests at once and is blocked, without blocking the proxy
+        for _ in 0..9 {
+            let response = policy.handle_tally(bob.clone());
+            assert_eq!(response.block_connection_ip, None);
+            assert_eq!(response.block_proxy_ip, None);
+        }
+        let response = policy.handle_tally(bob.clone());
+        assert_eq!(response.block_connection_ip, None);
+        assert_eq!(response.block_proxy_ip, bob.proxy_ip);
+
+        // alice shares the proxy with bob, but is counted separately and is not blocked
+        let response = policy.handle_tally(alice.clone());
+        assert_eq!(response.block_connection_ip, None);
+        assert_eq!(response.block_proxy_ip, None);
+    }
+
+    #[sim_test]
+    async fn test_freq_threshold_policy_warmup() {
+        let mut policy = TrafficControlPolicy::FreqThreshold(FreqThresholdPolicy::new(
+            PolicyConfig::default(),
+            FreqThresholdConfig {
+                connection_threshold: 5,
+                proxy_threshold: 2,
+                window_size_secs: 5,
+                update_interval_secs: 1,
+                warmup_window_secs: 5,
+                ..Default::default()
+            },
+        ));
+        let bob = TrafficTally {
+            connection_ip: Some(IpAddr::V4(Ipv4Addr::new(8, 7, 6, 5))),
+            proxy_ip: Some(IpAddr::V4(Ipv4Addr::new(4, 3, 2, 1))),
+            error_weight: Weight::zero(),
+            timestamp: SystemTime::now(),
+        };
+
+        // bob's burst during warmup is recorded but not blocked
+        for _ in 0..10 {
+            let response = policy.handle_tally(bob.clone());
+            assert_eq!(response.block_connection_ip, None);
+            assert_eq!(response.block_proxy_ip, None);
+        }
+
+        // once warmup is over and the burst has left the window, the same
+        // burst is blocked
+        tokio::time::sleep(tokio::time::Duration::from_secs(6)).await;
+        for _ in 0..9 {
+            let response = policy.handle_tally(bob.clone());
+            assert_eq!(response.block_connection_ip, None);
+            assert_eq!(response.block_proxy_ip, None);
+        }
+        let response = policy.handle_tally(bob.clone());
+        assert_eq!(response.block_connection_ip, None);
+        assert_eq!(response.block_proxy_ip, bob.proxy_ip);
+    }
+
     #[sim_test]
     async fn test_traffic_sketch_mem_estimate() {
         // Test for getting a rough estimate of memory usage for the traffic sketch
@@ -508,16 +759,68 @@ mod tests {
         // With default parameters, memory estimate is 113 MB.
         let window_size = Duration::from_secs(30);
         let update_interval = Duration::from_secs(5);
-        let mem_estimate = CountMinSketch32::<IpAddr>::estimate_memory(
-            DEFAULT_SKETCH_CAPACITY,
-            DEFAULT_SKETCH_PROBABILITY,
-            DEFAULT_SKETCH_TOLERANCE,
-        )
-        .unwrap()
-            * (window_size.as_secs() / update_interval.as_secs()) as usize;
+        let mem_estimate = |width| {
+            CountMinSketch::estimate_memory(
+                width,
+                DEFAULT_SKETCH_CAPACITY,
+                DEFAULT_SKETCH_PROBABILITY,
+                DEFAULT_SKETCH_TOLERANCE,
+            ) * (window_size.as_secs() / update_interval.as_secs()) as usize
+        };
+        let mem_estimate_32 = mem_estimate(SketchWidth::Bits32);
+        assert!(
+            mem_estimate_32 < 128_000_000,
+            "Memory estimate {mem_estimate_32} for traffic sketch exceeds 128MB."
+        );
+        // 16 bit counters take about half the memory.
+        let mem_estimate_16 = mem_estimate(SketchWidth::Bits16);
         assert!(
-            mem_estimate < 128_000_000,
-            "Memory estimate {mem_estimate} for traffic sketch exceeds 128MB."
+            mem_estimate_16 < mem_estimate_32 * 6 / 10,
+            "Memory estimate {mem_estimate_16} for 16 bit traffic sketch is not about half of {mem_estimate_32}."
         );
     }
+
+    #[sim_test]
+    async fn test_freq_threshold_policy_16_bit_sketch() {
+        let mut policy = TrafficControlPolicy::FreqThreshold(FreqThresholdPolicy::new(
+            PolicyConfig::default(),
+            FreqThresholdConfig {
+                connection_threshold: 5,
+                proxy_threshold: 2,
+                window_size_secs: 5,
+                update_interval_secs: 1,
+                sketch_width: SketchWidth::Bits16,
+                ..Default::default()
+            },
+        ));
+        let alice = TrafficTally {
+            connection_ip: Some(IpAddr::V4(Ipv4Addr::new(8, 7, 6, 5))),
+            proxy_ip: Some(IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4))),
+            error_weight: Weight::zero(),
+            timestamp: SystemTime::now(),
+        };
+        let bob = TrafficTally {
+            connection_ip: Some(IpAddr::V4(Ipv4Addr::new(8, 7, 6, 5))),
+            proxy_ip: Some(IpAddr::V4(Ipv4Addr::new(4, 3, 2, 1))),
+            error_weight: Weight::zero(),
+            timestamp: SystemTime::now(),
+        };
+
+        // alice stays below the threshold
+        for _ in 0..2 {
+            let response = policy.handle_tally(alice.clone());
+            assert_eq!(response.block_proxy_ip, None);
+            assert_eq!(response.block_connection_ip, None);
+        }
+
+        // bob spams 10 requests at once and is blocked
+        for _ in 0..9 {
+            let response = policy.handle_tally(bob.clone());
+            assert_eq!(response.block_connection_ip, None);
+            assert_eq!(response.block_proxy_ip, None);
+        }
+        let response = policy.handle_tally(bob.clone());
+        assert_eq!(response.block_connection_ip, None);
+        assert_eq!(response.block_proxy_ip, bob.proxy_ip);
+    }
 }
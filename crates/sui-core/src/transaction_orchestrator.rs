@@ -340,6 +340,7 @@ where
                     input_objects,
                     output_objects,
                     auxiliary_data,
+                    ..
                 } = response;
                 Ok(ExecuteTransactionResponseV3 {
                     effects: FinalizedEffects::new_from_effects_cert(effects_cert.into()),
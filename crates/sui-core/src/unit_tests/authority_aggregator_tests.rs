@@ -1941,6 +1941,50 @@ async fn test_handle_overload_response() {
     .await;
 }
 
+// Tests that when a quorum is not reached, the retryable error reports the collected stake and
+// the outcome for each validator.
+#[tokio::test]
+async fn test_process_transaction_reports_responses_without_quorum() {
+    let (authorities, mut clients, authority_keys) = make_fake_authorities();
+
+    let (sender, sender_kp): (_, AccountKeyPair) = get_key_pair();
+    let gas_object = random_object_ref();
+    let tx = VerifiedTransaction::new_unchecked(make_transfer_sui_transaction(
+        gas_object,
+        SuiAddress::default(),
+        None,
+        sender,
+        &sender_kp,
+        666, // this is a dummy value which does not matter
+    ));
+
+    // Validators 0 and 1 sign the transaction, 2 and 3 return a retryable error.
+    set_tx_info_response_with_signed_tx(&mut clients, &authority_keys, &tx, 0);
+    set_retryable_tx_info_response_error(&mut clients, &authority_keys[2..]);
+
+    let agg = get_genesis_agg(authorities.clone(), clients.clone());
+    let Err(AggregatorProcessTransactionError::RetryableTransaction {
+        collected_stake,
+        responses,
+        ..
+    }) = agg
+        .process_transaction(tx.into(), Some(make_socket_addr()))
+        .await
+    else {
+        panic!("Expected a RetryableTransaction error");
+    };
+
+    assert_eq!(collected_stake, 5000);
+    let responses: BTreeMap<_, _> = responses.into_iter().collect();
+    assert_eq!(responses.len(), 4);
+    for (name, _) in &authority_keys[..2] {
+        assert!(responses[name].is_ok());
+    }
+    for (name, _) in &authority_keys[2..] {
+        assert!(matches!(responses[name], Err(SuiError::RpcError(..))));
+    }
+}
+
 // Tests that authority aggregator can aggregate SuiError::ValidatorOverloadedRetryAfter into
 // AggregatorProcessTransactionError::SystemOverloadRetryAfter.
 #[tokio::test]
@@ -2430,7 +2474,7 @@ async fn assert_resp_err<E, F>(
                 assert!(errors.iter().map(|e| &e.0).all(sui_err_checker));
             }
 
-            AggregatorProcessTransactionError::RetryableTransaction { errors } => {
+            AggregatorProcessTransactionError::RetryableTransaction { errors, .. } => {
                 assert!(errors.iter().map(|e| &e.0).all(sui_err_checker));
             }
 
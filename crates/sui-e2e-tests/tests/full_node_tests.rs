@@ -859,10 +859,7 @@ async fn test_full_node_transaction_orchestrator_basic() -> Result<(), anyhow::E
     let digest = *txn.digest();
     let res = transaction_orchestrator
         .execute_transaction_block(
-            ExecuteTransactionRequest {
-                transaction: txn,
-                request_type: ExecuteTransactionRequestType::WaitForLocalExecution,
-            },
+            ExecuteTransactionRequest::wait_for_local_execution(txn),
             None,
         )
         .await
@@ -890,13 +887,7 @@ async fn test_full_node_transaction_orchestrator_basic() -> Result<(), anyhow::E
     let txn = txns.swap_remove(0);
     let digest = *txn.digest();
     let res = transaction_orchestrator
-        .execute_transaction_block(
-            ExecuteTransactionRequest {
-                transaction: txn,
-                request_type: ExecuteTransactionRequestType::WaitForEffectsCert,
-            },
-            None,
-        )
+        .execute_transaction_block(ExecuteTransactionRequest::wait_for_effects_cert(txn), None)
         .await
         .unwrap_or_else(|e| panic!("Failed to execute transaction {:?}: {:?}", digest, e));
 
@@ -1295,10 +1286,7 @@ async fn test_pass_back_no_object() -> Result<(), anyhow::Error> {
     let digest = *tx.digest();
     let _res = transaction_orchestrator
         .execute_transaction_block(
-            ExecuteTransactionRequest {
-                transaction: tx,
-                request_type: ExecuteTransactionRequestType::WaitForLocalExecution,
-            },
+            ExecuteTransactionRequest::wait_for_local_execution(tx),
             None,
         )
         .await
@@ -278,10 +278,7 @@ async fn test_tx_across_epoch_boundaries() {
     tokio::task::spawn(async move {
         match to
             .execute_transaction_block(
-                ExecuteTransactionRequest {
-                    transaction: tx.clone(),
-                    request_type: ExecuteTransactionRequestType::WaitForEffectsCert,
-                },
+                ExecuteTransactionRequest::wait_for_effects_cert(tx.clone()),
                 Some(make_socket_addr()),
             )
             .await
@@ -325,10 +322,7 @@ async fn execute_with_orchestrator(
 ) -> Result<ExecuteTransactionResponse, QuorumDriverError> {
     orchestrator
         .execute_transaction_block(
-            ExecuteTransactionRequest {
-                transaction: txn,
-                request_type,
-            },
+            ExecuteTransactionRequest::new(txn, request_type),
             Some(make_socket_addr()),
         )
         .await
@@ -40,6 +40,15 @@ pub enum FaucetError {
     #[error("Coin amounts sent are incorrect:`{0}`")]
     CoinAmountTransferredIncorrect(String),
 
+    #[error("Requested amount {requested} is larger than the maximum of {max}")]
+    AmountTooLarge { requested: u64, max: u64 },
+
+    #[error("Requested amount must be greater than zero")]
+    ZeroAmount,
+
+    #[error("Coin type `{0}` is not supported by this faucet")]
+    UnsupportedCoinType(String),
+
     #[error("Internal error: {0}")]
     Internal(String),
 }
@@ -9,7 +9,7 @@ use uuid::Uuid;
 mod simple_faucet;
 mod write_ahead_log;
 pub use self::simple_faucet::SimpleFaucet;
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use std::{net::Ipv4Addr, path::PathBuf};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -66,8 +66,23 @@ pub trait Faucet {
     async fn get_batch_send_status(&self, task_id: Uuid) -> Result<BatchSendStatus, FaucetError>;
 }
 
+/// Order in which the faucet's gas coins are queued for use when it starts. Coins with equal keys
+/// are ordered by `ObjectID`, so the order does not depend on how the coins were listed.
+/// Coins returned to the pool after a request are queued behind the remaining ones.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CoinSelectionStrategy {
+    /// Coins with the smallest balance first.
+    #[default]
+    SmallestFirst,
+    /// Coins with the largest balance first.
+    LargestFirst,
+    /// Coins with the lowest version first.
+    Oldest,
+}
+
 pub const DEFAULT_AMOUNT: u64 = 1_000_000_000;
 pub const DEFAULT_NUM_OF_COINS: usize = 1;
+pub const DEFAULT_MAX_AMOUNT: u64 = 10 * DEFAULT_AMOUNT;
 
 #[derive(Parser, Clone)]
 #[clap(
@@ -88,6 +103,10 @@ pub struct FaucetConfig {
     #[clap(long, default_value_t = DEFAULT_NUM_OF_COINS)]
     pub num_coins: usize,
 
+    /// Largest amount a request may ask for in each coin, instead of `amount`.
+    #[clap(long, default_value_t = DEFAULT_MAX_AMOUNT)]
+    pub max_amount: u64,
+
     #[clap(long, default_value_t = 10)]
     pub request_buffer_size: usize,
 
@@ -114,6 +133,9 @@ pub struct FaucetConfig {
 
     #[clap(long, action = clap::ArgAction::Set, default_value_t = false)]
     pub batch_enabled: bool,
+
+    #[clap(long, value_enum, default_value_t = CoinSelectionStrategy::SmallestFirst)]
+    pub coin_selection_strategy: CoinSelectionStrategy,
 }
 
 impl Default for FaucetConfig {
@@ -123,6 +145,7 @@ impl Default for FaucetConfig {
             host_ip: Ipv4Addr::new(127, 0, 0, 1),
             amount: 1_000_000_000,
             num_coins: 1,
+            max_amount: DEFAULT_MAX_AMOUNT,
             request_buffer_size: 10,
             max_request_per_second: 10,
             wallet_client_timeout_secs: 60,
@@ -132,6 +155,17 @@ impl Default for FaucetConfig {
             batch_request_size: 500,
             ttl_expiration: 300,
             batch_enabled: false,
+            coin_selection_strategy: CoinSelectionStrategy::SmallestFirst,
         }
     }
 }
+
+impl FaucetConfig {
+    /// Total amount sent for the largest request this faucet accepts, which every coin in its
+    /// gas pool must be able to cover.
+    pub fn max_request_total(&self) -> u64 {
+        self.amount
+            .max(self.max_amount)
+            .saturating_mul(self.num_coins as u64)
+    }
+}
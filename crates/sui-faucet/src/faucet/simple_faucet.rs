@@ -8,6 +8,7 @@ use async_trait::async_trait;
 use mysten_metrics::spawn_monitored_task;
 use prometheus::Registry;
 use shared_crypto::intent::Intent;
+use std::cmp::Reverse;
 use std::collections::HashMap;
 #[cfg(test)]
 use std::collections::HashSet;
@@ -29,7 +30,7 @@ use sui_sdk::wallet_context::WalletContext;
 use sui_types::object::Owner;
 use sui_types::quorum_driver_types::ExecuteTransactionRequestType;
 use sui_types::{
-    base_types::{ObjectID, SuiAddress, TransactionDigest},
+    base_types::{ObjectID, SequenceNumber, SuiAddress, TransactionDigest},
     gas_coin::GasCoin,
     transaction::{Transaction, TransactionData},
 };
@@ -43,8 +44,8 @@ use uuid::Uuid;
 
 use super::write_ahead_log::WriteAheadLog;
 use crate::{
-    BatchFaucetReceipt, BatchSendStatus, BatchSendStatusType, CoinInfo, Faucet, FaucetConfig,
-    FaucetError, FaucetReceipt,
+    BatchFaucetReceipt, BatchSendStatus, BatchSendStatusType, CoinInfo, CoinSelectionStrategy,
+    Faucet, FaucetConfig, FaucetError, FaucetReceipt,
 };
 
 pub struct SimpleFaucet {
@@ -107,14 +108,19 @@ impl SimpleFaucet {
             .map_err(|err| FaucetError::Wallet(err.to_string()))?;
         info!("SimpleFaucet::new with active address: {active_address}");
 
-        let coins = wallet
+        let mut coins = wallet
             .gas_objects(active_address)
             .await
             .map_err(|e| FaucetError::Wallet(e.to_string()))?
             .iter()
             // Ok to unwrap() since `get_gas_objects` guarantees gas
-            .map(|q| GasCoin::try_from(&q.1).unwrap())
-            .filter(|coin| coin.0.balance.value() >= (config.amount * config.num_coins as u64))
+            .map(|q| (GasCoin::try_from(&q.1).unwrap(), q.1.version))
+            .filter(|(coin, _)| coin.0.balance.value() >= config.max_request_total())
+            .collect::<Vec<_>>();
+        sort_gas_coins(&mut coins, config.coin_selection_strategy);
+        let coins = coins
+            .into_iter()
+            .map(|(coin, _)| coin)
             .collect::<Vec<GasCoin>>();
         let metrics = FaucetMetrics::new(prometheus_registry);
 
@@ -946,6 +952,21 @@ impl Faucet for SimpleFaucet {
     }
 }
 
+/// Orders `coins` (alongside their versions) according to `strategy`, breaking ties by `ObjectID`.
+fn sort_gas_coins(coins: &mut [(GasCoin, SequenceNumber)], strategy: CoinSelectionStrategy) {
+    match strategy {
+        CoinSelectionStrategy::SmallestFirst => {
+            coins.sort_by_key(|(coin, _)| (coin.value(), *coin.id()))
+        }
+        CoinSelectionStrategy::LargestFirst => {
+            coins.sort_by_key(|(coin, _)| (Reverse(coin.value()), *coin.id()))
+        }
+        CoinSelectionStrategy::Oldest => {
+            coins.sort_by_key(|(coin, version)| (*version, *coin.id()))
+        }
+    }
+}
+
 pub async fn batch_gather(
     request_consumer: &mut Receiver<(Uuid, SuiAddress, Vec<u64>)>,
     requests: &mut Vec<(Uuid, SuiAddress, Vec<u64>)>,
@@ -1173,6 +1194,49 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_smallest_first_coin_selection() {
+        let test_cluster = TestClusterBuilder::new().build().await;
+        let address = test_cluster.get_address_0();
+        let mut context = test_cluster.wallet;
+        let gases = get_current_gases(address, &mut context).await;
+
+        // Split out coins that are smaller than all the others, but still large enough to serve
+        // a request.
+        SuiClientCommands::SplitCoin {
+            coin_id: *gases[0].id(),
+            amounts: Some(vec![3_000_000_000, 2_000_000_000]),
+            count: None,
+            opts: OptsWithGas::for_testing(None, 50_000_000),
+        }
+        .execute(&mut context)
+        .await
+        .expect("split failed");
+
+        let gases = get_current_gases(address, &mut context).await;
+        let smallest = gases.iter().min_by_key(|gas| gas.value()).unwrap();
+        assert_eq!(smallest.value(), 2_000_000_000);
+        let smallest = *smallest.id();
+
+        let tmp = tempfile::tempdir().unwrap();
+        let prom_registry = Registry::new();
+        let config = FaucetConfig {
+            coin_selection_strategy: CoinSelectionStrategy::SmallestFirst,
+            ..Default::default()
+        };
+        let faucet = SimpleFaucet::new(
+            context,
+            &prom_registry,
+            &tmp.path().join("faucet.wal"),
+            config,
+        )
+        .await
+        .unwrap();
+        faucet.shutdown_batch_send_task();
+
+        assert_eq!(faucet.pop_gas_coin(Uuid::new_v4()).await, Some(smallest));
+    }
+
     #[tokio::test]
     async fn test_transfer_state() {
         let test_cluster = TestClusterBuilder::new().build().await;
@@ -1509,7 +1573,7 @@ mod tests {
         // split out a coin that has a very small balance such that
         // this coin will be not used later on. This is the new default amount for faucet due to gas changes
         let config = FaucetConfig::default();
-        let tiny_value = (config.num_coins as u64 * config.amount) + 1;
+        let tiny_value = config.max_request_total() + 1;
         let res = SuiClientCommands::SplitCoin {
             coin_id: *gases[0].id(),
             amounts: Some(vec![tiny_value]),
@@ -1591,7 +1655,7 @@ mod tests {
         let config = FaucetConfig::default();
 
         // The coin that is split off stays because we don't try to refresh the coin vector
-        let reasonable_value = (config.num_coins as u64 * config.amount) * 10;
+        let reasonable_value = config.max_request_total();
         SuiClientCommands::SplitCoin {
             coin_id: *gases[0].id(),
             amounts: Some(vec![reasonable_value]),
@@ -1659,7 +1723,7 @@ mod tests {
         let gases = get_current_gases(address, &mut context).await;
         let config = FaucetConfig::default();
 
-        let tiny_value = (config.num_coins as u64 * config.amount) + 1;
+        let tiny_value = config.max_request_total() + 1;
         let _res = SuiClientCommands::SplitCoin {
             coin_id: *gases[0].id(),
             amounts: Some(vec![tiny_value]),
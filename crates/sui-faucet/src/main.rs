@@ -148,16 +148,16 @@ async fn batch_request_gas(
             ))),
         );
     };
+    let amounts = match request.amounts(&state.config) {
+        Ok(amounts) => amounts,
+        Err(e) => return (StatusCode::BAD_REQUEST, Json(BatchFaucetResponse::from(e))),
+    };
 
     if state.config.batch_enabled {
         let result = spawn_monitored_task!(async move {
             state
                 .faucet
-                .batch_send(
-                    id,
-                    request.recipient,
-                    &vec![state.config.amount; state.config.num_coins],
-                )
+                .batch_send(id, request.recipient, &amounts)
                 .await
         })
         .await
@@ -180,14 +180,7 @@ async fn batch_request_gas(
         // TODO (jian): remove this feature gate when batch has proven to be baked long enough
         info!(uuid = ?id, "Falling back to v1 implementation");
         let result = spawn_monitored_task!(async move {
-            state
-                .faucet
-                .send(
-                    id,
-                    request.recipient,
-                    &vec![state.config.amount; state.config.num_coins],
-                )
-                .await
+            state.faucet.send(id, request.recipient, &amounts).await
         })
         .await
         .unwrap();
@@ -246,17 +239,14 @@ async fn request_gas(
     info!(uuid = ?id, "Got new gas request.");
     let result = match payload {
         FaucetRequest::FixedAmountRequest(requests) => {
+            let amounts = match requests.amounts(&state.config) {
+                Ok(amounts) => amounts,
+                Err(e) => return (StatusCode::BAD_REQUEST, Json(FaucetResponse::from(e))),
+            };
             // We spawn a tokio task for this such that connection drop will not interrupt
             // it and impact the recycling of coins
             spawn_monitored_task!(async move {
-                state
-                    .faucet
-                    .send(
-                        id,
-                        requests.recipient,
-                        &vec![state.config.amount; state.config.num_coins],
-                    )
-                    .await
+                state.faucet.send(id, requests.recipient, &amounts).await
             })
             .await
             .unwrap()
@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use serde::{Deserialize, Serialize};
-use sui_types::base_types::SuiAddress;
+use sui_types::{base_types::SuiAddress, gas_coin::GAS, parse_sui_type_tag};
+
+use crate::{FaucetConfig, FaucetError};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum FaucetRequest {
@@ -13,6 +15,40 @@ pub enum FaucetRequest {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct FixedAmountRequest {
     pub recipient: SuiAddress,
+    /// Amount of each coin sent, instead of the faucet's configured amount.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>,
+    /// Type of the coins requested. Only `0x2::sui::SUI` is supported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coin_type: Option<String>,
+}
+
+impl FixedAmountRequest {
+    /// Amounts of the coins to send for this request under `config`: `num_coins` coins of the
+    /// requested amount, or of the configured amount when none is requested. The faucet's gas
+    /// coins are all large enough to cover the amounts accepted here, see
+    /// `FaucetConfig::max_request_total`.
+    pub fn amounts(&self, config: &FaucetConfig) -> Result<Vec<u64>, FaucetError> {
+        if let Some(coin_type) = &self.coin_type {
+            match parse_sui_type_tag(coin_type) {
+                Ok(type_tag) if type_tag == GAS::type_tag() => {}
+                _ => return Err(FaucetError::UnsupportedCoinType(coin_type.clone())),
+            }
+        }
+
+        let amount = match self.amount {
+            Some(0) => return Err(FaucetError::ZeroAmount),
+            Some(requested) if requested > config.max_amount => {
+                return Err(FaucetError::AmountTooLarge {
+                    requested,
+                    max: config.max_amount,
+                })
+            }
+            Some(requested) => requested,
+            None => config.amount,
+        };
+        Ok(vec![amount; config.num_coins])
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -24,6 +60,8 @@ impl FaucetRequest {
     pub fn new_fixed_amount_request(recipient: impl Into<SuiAddress>) -> Self {
         Self::FixedAmountRequest(FixedAmountRequest {
             recipient: recipient.into(),
+            amount: None,
+            coin_type: None,
         })
     }
 
@@ -33,3 +71,72 @@ impl FaucetRequest {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(amount: Option<u64>, coin_type: Option<&str>) -> FixedAmountRequest {
+        FixedAmountRequest {
+            recipient: SuiAddress::random_for_testing_only(),
+            amount,
+            coin_type: coin_type.map(str::to_owned),
+        }
+    }
+
+    #[test]
+    fn requested_amount_is_bounded() {
+        let config = FaucetConfig {
+            amount: 100,
+            num_coins: 2,
+            max_amount: 1_000,
+            ..Default::default()
+        };
+
+        assert_eq!(request(None, None).amounts(&config), Ok(vec![100, 100]));
+        assert_eq!(
+            request(Some(1_000), None).amounts(&config),
+            Ok(vec![1_000, 1_000])
+        );
+        assert_eq!(
+            request(Some(1_001), None).amounts(&config),
+            Err(FaucetError::AmountTooLarge {
+                requested: 1_001,
+                max: 1_000
+            })
+        );
+        assert_eq!(
+            request(Some(0), None).amounts(&config),
+            Err(FaucetError::ZeroAmount)
+        );
+        assert_eq!(config.max_request_total(), 2_000);
+    }
+
+    #[test]
+    fn only_sui_coins_are_supported() {
+        let config = FaucetConfig::default();
+        let sui = vec![config.amount; config.num_coins];
+
+        assert_eq!(
+            request(None, Some("0x2::sui::SUI")).amounts(&config),
+            Ok(sui.clone())
+        );
+        assert_eq!(
+            request(
+                None,
+                Some(
+                    "0x0000000000000000000000000000000000000000000000000000000000000002::sui::SUI"
+                )
+            )
+            .amounts(&config),
+            Ok(sui)
+        );
+
+        for coin_type in ["0x2::coin::USDC", "not a type"] {
+            assert_eq!(
+                request(None, Some(coin_type)).amounts(&config),
+                Err(FaucetError::UnsupportedCoinType(coin_type.to_owned()))
+            );
+        }
+    }
+}
@@ -40,6 +40,12 @@ pub enum Command {
         /// Host to bind the server to
         #[clap(long)]
         host: Option<String>,
+        /// Path to the PEM-encoded certificate chain to serve HTTPS with
+        #[clap(long, requires = "tls_key")]
+        tls_cert: Option<PathBuf>,
+        /// Path to the PEM-encoded private key to serve HTTPS with
+        #[clap(long, requires = "tls_cert")]
+        tls_key: Option<PathBuf>,
         /// Port to bind the prom server to
         #[clap(long)]
         prom_port: Option<u16>,
@@ -6,7 +6,7 @@ use crate::types::big_int::BigInt;
 use async_graphql::*;
 use fastcrypto_zkp::bn254::zk_login_api::ZkLoginEnv;
 use serde::{Deserialize, Serialize};
-use std::{collections::BTreeSet, fmt::Display, time::Duration};
+use std::{collections::BTreeSet, fmt::Display, path::PathBuf, time::Duration};
 use sui_json_rpc::name_service::NameServiceConfig;
 // TODO: calculate proper cost limits
 
@@ -80,6 +80,15 @@ pub struct ConnectionConfig {
     pub(crate) db_pool_size: u32,
     pub(crate) prom_url: String,
     pub(crate) prom_port: u16,
+    /// Serve over HTTPS with this certificate and key, instead of plain HTTP.
+    pub(crate) tls: Option<TlsConfig>,
+}
+
+/// Paths to the PEM-encoded certificate chain and private key the server terminates TLS with.
+#[derive(Serialize, Clone, Deserialize, Debug, Eq, PartialEq)]
+pub struct TlsConfig {
+    pub(crate) cert_path: PathBuf,
+    pub(crate) key_path: PathBuf,
 }
 
 /// Configuration on features supported by the GraphQL service, passed in a TOML-based file. These
@@ -134,6 +143,12 @@ pub struct Limits {
     pub default_page_size: u64,
     #[serde(default)]
     pub max_page_size: u64,
+    /// Overrides `max_page_size` for connections of objects, when set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub object_max_page_size: Option<u64>,
+    /// Overrides `max_page_size` for connections of events, when set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_max_page_size: Option<u64>,
     #[serde(default)]
     pub mutation_timeout_ms: u64,
     #[serde(default)]
@@ -235,12 +250,26 @@ pub struct InternalFeatureConfig {
     pub(crate) apollo_tracing: bool,
     #[serde(default)]
     pub(crate) open_telemetry: bool,
+    /// Serve the bulk object export endpoint. Requests must authenticate with
+    /// `object_export_token`, and the endpoint is not served if no token is configured.
+    #[serde(default)]
+    pub(crate) object_export: bool,
+    #[serde(default)]
+    pub(crate) object_export_token: Option<String>,
 }
 
 #[derive(Serialize, Clone, Deserialize, Debug, Eq, PartialEq, Default)]
 pub struct TxExecFullNodeConfig {
     #[serde(default)]
     pub(crate) node_rpc_url: Option<String>,
+    /// Maximum number of requests in flight to the fullnode at once. Defaults to
+    /// `MAX_CONCURRENT_REQUESTS`.
+    #[serde(default)]
+    pub(crate) max_concurrent_requests: Option<usize>,
+    /// Timeout of requests to the fullnode, in milliseconds. Defaults to
+    /// `RPC_TIMEOUT_ERR_SLEEP_RETRY_PERIOD`.
+    #[serde(default)]
+    pub(crate) request_timeout_ms: Option<u64>,
 }
 
 #[derive(Serialize, Clone, Deserialize, Debug, Eq, PartialEq, Default)]
@@ -354,7 +383,20 @@ impl ServiceConfig {
 
 impl TxExecFullNodeConfig {
     pub fn new(node_rpc_url: Option<String>) -> Self {
-        Self { node_rpc_url }
+        Self {
+            node_rpc_url,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn max_concurrent_requests(&self) -> usize {
+        self.max_concurrent_requests
+            .unwrap_or(MAX_CONCURRENT_REQUESTS)
+    }
+
+    pub(crate) fn request_timeout(&self) -> Duration {
+        self.request_timeout_ms
+            .map_or(RPC_TIMEOUT_ERR_SLEEP_RETRY_PERIOD, Duration::from_millis)
     }
 }
 
@@ -375,9 +417,19 @@ impl ConnectionConfig {
             db_pool_size: db_pool_size.unwrap_or(default.db_pool_size),
             prom_url: prom_url.unwrap_or(default.prom_url),
             prom_port: prom_port.unwrap_or(default.prom_port),
+            tls: default.tls,
         }
     }
 
+    /// Serve over HTTPS, with the certificate chain and private key at the given paths.
+    pub fn with_tls(mut self, cert_path: PathBuf, key_path: PathBuf) -> Self {
+        self.tls = Some(TlsConfig {
+            cert_path,
+            key_path,
+        });
+        self
+    }
+
     pub fn ci_integration_test_cfg() -> Self {
         Self {
             db_url: DEFAULT_SERVER_DB_URL.to_string(),
@@ -488,6 +540,7 @@ impl Default for ConnectionConfig {
             db_pool_size: DEFAULT_SERVER_DB_POOL_SIZE,
             prom_url: DEFAULT_SERVER_PROM_HOST.to_string(),
             prom_port: DEFAULT_SERVER_PROM_PORT,
+            tls: None,
         }
     }
 }
@@ -502,6 +555,8 @@ impl Default for Limits {
             max_db_query_cost: MAX_DB_QUERY_COST,
             default_page_size: DEFAULT_PAGE_SIZE,
             max_page_size: MAX_PAGE_SIZE,
+            object_max_page_size: None,
+            event_max_page_size: None,
             mutation_timeout_ms: DEFAULT_MUTATION_TIMEOUT_MS,
             request_timeout_ms: DEFAULT_REQUEST_TIMEOUT_MS,
             max_type_argument_depth: MAX_TYPE_ARGUMENT_DEPTH,
@@ -523,6 +578,8 @@ impl Default for InternalFeatureConfig {
             tracing: false,
             apollo_tracing: false,
             open_telemetry: false,
+            object_export: false,
+            object_export_token: None,
         }
     }
 }
@@ -557,6 +614,7 @@ mod tests {
                 max-db-query-cost = 50
                 default-page-size = 20
                 max-page-size = 50
+                object-max-page-size = 10
                 mutation-timeout-ms = 74000
                 request-timeout-ms = 27000
                 max-type-argument-depth = 32
@@ -576,6 +634,8 @@ mod tests {
                 max_db_query_cost: 50,
                 default_page_size: 20,
                 max_page_size: 50,
+                object_max_page_size: Some(10),
+                event_max_page_size: None,
                 mutation_timeout_ms: 74_000,
                 request_timeout_ms: 27_000,
                 max_type_argument_depth: 32,
@@ -626,6 +686,28 @@ mod tests {
         assert_eq!(actual, expect)
     }
 
+    #[test]
+    fn test_tx_exec_full_node_client_settings() {
+        let default: TxExecFullNodeConfig =
+            toml::from_str(r#"node_rpc_url = "http://fn""#).unwrap();
+        assert_eq!(default.max_concurrent_requests(), MAX_CONCURRENT_REQUESTS);
+        assert_eq!(
+            default.request_timeout(),
+            RPC_TIMEOUT_ERR_SLEEP_RETRY_PERIOD
+        );
+
+        let tuned: TxExecFullNodeConfig = toml::from_str(
+            r#" node_rpc_url = "http://fn"
+                max_concurrent_requests = 50
+                request_timeout_ms = 2500
+            "#,
+        )
+        .unwrap();
+        assert_eq!(tuned.node_rpc_url.as_deref(), Some("http://fn"));
+        assert_eq!(tuned.max_concurrent_requests(), 50);
+        assert_eq!(tuned.request_timeout(), Duration::from_millis(2500));
+    }
+
     #[test]
     fn test_read_everything_in_service_config() {
         let actual = ServiceConfig::read(
@@ -661,6 +743,8 @@ mod tests {
                 max_db_query_cost: 20,
                 default_page_size: 10,
                 max_page_size: 20,
+                object_max_page_size: None,
+                event_max_page_size: None,
                 mutation_timeout_ms: 74_000,
                 request_timeout_ms: 30_000,
                 max_type_argument_depth: 32,
@@ -77,13 +77,18 @@ async fn main() {
             db_pool_size,
             port,
             host,
+            tls_cert,
+            tls_key,
             config,
             node_rpc_url,
             prom_host,
             prom_port,
         } => {
-            let connection =
+            let mut connection =
                 ConnectionConfig::new(port, host, db_url, db_pool_size, prom_host, prom_port);
+            if let (Some(cert_path), Some(key_path)) = (tls_cert, tls_key) {
+                connection = connection.with_tls(cert_path, key_path);
+            }
             let service_config = service_config(config);
             let _guard = telemetry_subscribers::TelemetryConfig::new()
                 .with_env()
@@ -1,12 +1,10 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use super::export::{export_objects, ExportState, EXPORT_OBJECTS_PATH};
 use super::system_package_task::SystemPackageTask;
 use super::watermark_task::{Watermark, WatermarkLock, WatermarkTask};
-use crate::config::{
-    ConnectionConfig, ServiceConfig, Version, MAX_CONCURRENT_REQUESTS,
-    RPC_TIMEOUT_ERR_SLEEP_RETRY_PERIOD,
-};
+use crate::config::{ConnectionConfig, ServiceConfig, TlsConfig, Version};
 use crate::data::package_resolver::{DbPackageStore, PackageResolver};
 use crate::data::{DataLoader, Db};
 use crate::metrics::Metrics;
@@ -33,16 +31,15 @@ use async_graphql::EmptySubscription;
 use async_graphql::{extensions::ExtensionFactory, Schema, SchemaBuilder};
 use async_graphql_axum::{GraphQLRequest, GraphQLResponse};
 use axum::extract::FromRef;
-use axum::extract::{connect_info::IntoMakeServiceWithConnectInfo, ConnectInfo, State};
+use axum::extract::{ConnectInfo, State};
 use axum::http::{HeaderMap, StatusCode};
 use axum::middleware::{self};
 use axum::response::IntoResponse;
-use axum::routing::{post, MethodRouter, Route};
+use axum::routing::{get, post, MethodRouter, Route};
 use axum::{headers::Header, Router};
+use axum_server::tls_rustls::RustlsConfig;
 use http::{HeaderValue, Method, Request};
-use hyper::server::conn::AddrIncoming as HyperAddrIncoming;
 use hyper::Body;
-use hyper::Server as HyperServer;
 use mysten_metrics::spawn_monitored_task;
 use mysten_network::callback::{CallbackLayer, MakeCallbackHandler, ResponseHandler};
 use std::convert::Infallible;
@@ -61,7 +58,9 @@ use tracing::{info, warn};
 use uuid::Uuid;
 
 pub(crate) struct Server {
-    pub server: HyperServer<HyperAddrIncoming, IntoMakeServiceWithConnectInfo<Router, SocketAddr>>,
+    address: SocketAddr,
+    app: Router,
+    tls: Option<TlsConfig>,
     watermark_task: WatermarkTask,
     system_package_task: SystemPackageTask,
     state: AppState,
@@ -92,16 +91,17 @@ impl Server {
 
         let server_task = {
             info!("Starting graphql service");
+            let handle = axum_server::Handle::new();
             let cancellation_token = self.state.cancellation_token.clone();
-            spawn_monitored_task!(async move {
-                self.server
-                    .with_graceful_shutdown(async {
-                        cancellation_token.cancelled().await;
-                        info!("Shutdown signal received, terminating graphql service");
-                    })
-                    .await
-                    .map_err(|e| Error::Internal(format!("Server run failed: {}", e)))
-            })
+            spawn_monitored_task!({
+                let handle = handle.clone();
+                async move {
+                    cancellation_token.cancelled().await;
+                    info!("Shutdown signal received, terminating graphql service");
+                    handle.graceful_shutdown(None);
+                }
+            });
+            spawn_monitored_task!(serve(self.address, self.app, self.tls, handle))
         };
 
         // Wait for all tasks to complete. This ensures that the service doesn't fully shut down
@@ -177,6 +177,16 @@ impl ServerBuilder {
         )
     }
 
+    /// The URL the server can be reached at, over HTTPS if TLS is configured.
+    pub fn url(&self) -> String {
+        let scheme = if self.state.connection.tls.is_some() {
+            "https"
+        } else {
+            "http"
+        };
+        format!("{scheme}://{}", self.address())
+    }
+
     pub fn context_data(mut self, context_data: impl Any + Send + Sync) -> Self {
         self.schema = self.schema.data(context_data);
         self
@@ -318,12 +328,11 @@ impl ServerBuilder {
             .layer(Self::cors()?);
 
         Ok(Server {
-            server: axum::Server::bind(
-                &address
-                    .parse()
-                    .map_err(|_| Error::Internal(format!("Failed to parse address {}", address)))?,
-            )
-            .serve(app.into_make_service_with_connect_info::<SocketAddr>()),
+            address: address
+                .parse()
+                .map_err(|_| Error::Internal(format!("Failed to parse address {}", address)))?,
+            app,
+            tls: state.connection.tls.clone(),
             watermark_task,
             system_package_task,
             state,
@@ -402,8 +411,8 @@ impl ServerBuilder {
         let sui_sdk_client = if let Some(url) = &config.tx_exec_full_node.node_rpc_url {
             Some(
                 SuiClientBuilder::default()
-                    .request_timeout(RPC_TIMEOUT_ERR_SLEEP_RETRY_PERIOD)
-                    .max_concurrent_requests(MAX_CONCURRENT_REQUESTS)
+                    .request_timeout(config.tx_exec_full_node.request_timeout())
+                    .max_concurrent_requests(config.tx_exec_full_node.max_concurrent_requests())
                     .build(url)
                     .await
                     .map_err(|e| Error::Internal(format!("Failed to create SuiClient: {}", e)))?,
@@ -416,7 +425,7 @@ impl ServerBuilder {
         builder = builder
             .context_data(config.service.clone())
             .context_data(loader)
-            .context_data(db)
+            .context_data(db.clone())
             .context_data(pg_conn_pool)
             .context_data(resolver)
             .context_data(sui_sdk_client)
@@ -447,6 +456,15 @@ impl ServerBuilder {
         // TODO: uncomment once impl
         // if config.internal_features.open_telemetry { }
 
+        if config.internal_features.object_export {
+            if let Some(token) = &config.internal_features.object_export_token {
+                let state = ExportState::new(db, config.service.clone(), token.clone());
+                builder = builder.route(EXPORT_OBJECTS_PATH, get(export_objects).with_state(state));
+            } else {
+                warn!("Object export is enabled but no token is configured, not serving it");
+            }
+        }
+
         Ok(builder)
     }
 }
@@ -566,6 +584,37 @@ async fn health_checks(State(connection): State<ConnectionConfig>) -> StatusCode
     }
 }
 
+/// Serves `app` on `address` until `handle` is shut down, over HTTPS if `tls` is set, and plain
+/// HTTP otherwise.
+async fn serve(
+    address: SocketAddr,
+    app: Router,
+    tls: Option<TlsConfig>,
+    handle: axum_server::Handle,
+) -> Result<(), Error> {
+    let service = app.into_make_service_with_connect_info::<SocketAddr>();
+    match tls {
+        Some(tls) => {
+            let config = RustlsConfig::from_pem_file(&tls.cert_path, &tls.key_path)
+                .await
+                .map_err(|e| {
+                    Error::Internal(format!("Failed to load TLS certificate and key: {}", e))
+                })?;
+            axum_server::bind_rustls(address, config)
+                .handle(handle)
+                .serve(service)
+                .await
+        }
+        None => {
+            axum_server::bind(address)
+                .handle(handle)
+                .serve(service)
+                .await
+        }
+    }
+    .map_err(|e| Error::Internal(format!("Server run failed: {}", e)))
+}
+
 // One server per proc, so this is okay
 async fn get_or_init_server_start_time() -> &'static Instant {
     static ONCE: OnceCell<Instant> = OnceCell::const_new();
@@ -942,6 +991,51 @@ pub mod tests {
         );
     }
 
+    pub async fn test_query_connection_max_page_limit_impl() {
+        let service_config = ServiceConfig {
+            limits: Limits {
+                object_max_page_size: Some(5),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let schema = prep_schema(None, Some(service_config)).build_schema();
+
+        // Objects are limited by their own max page size.
+        let err: Vec<_> = schema
+            .execute("{ objects(first: 6) { nodes { version } } }")
+            .await
+            .into_result()
+            .unwrap_err()
+            .into_iter()
+            .map(|e| e.message)
+            .collect();
+        assert_eq!(
+            err,
+            vec!["Connection's page size of 6 exceeds max of 5".to_string()]
+        );
+
+        // Checkpoints are still limited by the global max page size.
+        schema
+            .execute("{ checkpoints(first: 6) { nodes { sequenceNumber } } }")
+            .await
+            .into_result()
+            .expect("Should complete successfully");
+
+        let err: Vec<_> = schema
+            .execute("{ checkpoints(first: 51) { nodes { sequenceNumber } } }")
+            .await
+            .into_result()
+            .unwrap_err()
+            .into_iter()
+            .map(|e| e.message)
+            .collect();
+        assert_eq!(
+            err,
+            vec!["Connection's page size of 51 exceeds max of 50".to_string()]
+        );
+    }
+
     pub async fn test_query_complexity_metrics_impl() {
         let server_builder = prep_schema(None, None);
         let metrics = server_builder.state.metrics.clone();
@@ -976,4 +1070,108 @@ pub mod tests {
         assert_eq!(req_metrics.output_nodes.get_sample_sum(), 2. + 4.);
         assert_eq!(req_metrics.query_depth.get_sample_sum(), 1. + 3.);
     }
+
+    pub async fn test_object_export_impl() {
+        use tower::ServiceExt;
+
+        let connection_config = ConnectionConfig::ci_integration_test_cfg();
+        let service_config = ServiceConfig::default();
+        let reader = PgManager::reader(connection_config.db_url.clone())
+            .expect("Failed to create pg connection pool");
+        let db = Db::new(reader, service_config.limits, metrics());
+        let watermark: WatermarkLock = Arc::new(tokio::sync::RwLock::new(Watermark {
+            checkpoint: 1,
+            epoch: 0,
+        }));
+
+        let state = ExportState::new(db, service_config, "secret".to_string());
+        let app = Router::new()
+            .route(EXPORT_OBJECTS_PATH, get(export_objects).with_state(state))
+            .layer(axum::Extension(watermark));
+
+        let export = |token: Option<&str>| {
+            let mut request = Request::builder()
+                .uri(format!(
+                    "{EXPORT_OBJECTS_PATH}?type=0x2::coin::Coin%3C0x2::sui::SUI%3E"
+                ))
+                .method(Method::GET);
+            if let Some(token) = token {
+                request = request.header(http::header::AUTHORIZATION, format!("Bearer {token}"));
+            }
+            app.clone().oneshot(request.body(Body::empty()).unwrap())
+        };
+
+        // Requests without the right token are rejected.
+        let response = export(None).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        let response = export(Some("wrong")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = export(Some("secret")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(http::header::CONTENT_TYPE).unwrap(),
+            "application/x-ndjson",
+        );
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        let expected_type = sui_types::gas_coin::GasCoin::type_().to_canonical_string(true);
+
+        // Genesis creates more gas coins than fit in a single page, so this also covers the export
+        // continuing across pages.
+        let mut addresses = std::collections::BTreeSet::new();
+        for line in body.lines() {
+            let object: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert_eq!(object["type"], expected_type.as_str());
+            assert!(addresses.insert(object["address"].as_str().unwrap().to_string()));
+        }
+        assert!(addresses.len() as u64 > ServiceConfig::default().limits.max_page_size);
+    }
+
+    #[tokio::test]
+    async fn test_serve_over_tls() {
+        let certificate =
+            rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let tls = TlsConfig {
+            cert_path: dir.path().join("cert.pem"),
+            key_path: dir.path().join("key.pem"),
+        };
+        std::fs::write(&tls.cert_path, certificate.serialize_pem().unwrap()).unwrap();
+        std::fs::write(&tls.key_path, certificate.serialize_private_key_pem()).unwrap();
+
+        let app = Router::new().route("/", get(|| async { "ok" }));
+        let handle = axum_server::Handle::new();
+        let server = tokio::spawn(serve(
+            "127.0.0.1:0".parse().unwrap(),
+            app,
+            Some(tls),
+            handle.clone(),
+        ));
+        let address = handle.listening().await.unwrap();
+        let url = format!("https://localhost:{}/", address.port());
+
+        // A client that trusts the self-signed certificate can connect over HTTPS.
+        let client = reqwest::Client::builder()
+            .add_root_certificate(
+                reqwest::Certificate::from_der(&certificate.serialize_der().unwrap()).unwrap(),
+            )
+            .resolve("localhost", address)
+            .https_only(true)
+            .build()
+            .unwrap();
+        let response = client.get(&url).send().await.unwrap();
+        assert_eq!(response.text().await.unwrap(), "ok");
+
+        // A client that does not trust it cannot.
+        let client = reqwest::Client::builder()
+            .resolve("localhost", address)
+            .build()
+            .unwrap();
+        assert!(client.get(&url).send().await.is_err());
+
+        handle.graceful_shutdown(None);
+        server.await.unwrap().unwrap();
+    }
 }
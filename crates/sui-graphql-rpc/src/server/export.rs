@@ -0,0 +1,160 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use async_graphql::connection::CursorType;
+use axum::body::StreamBody;
+use axum::extract::{Query, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use fastcrypto::encoding::{Base64, Encoding};
+use futures::stream;
+use move_core_types::language_storage::StructTag;
+use serde::Deserialize;
+use serde_json::json;
+use subtle::ConstantTimeEq;
+
+use super::watermark_task::{Watermark, WatermarkLock};
+use crate::config::ServiceConfig;
+use crate::data::Db;
+use crate::error::Error;
+use crate::types::cursor::{ConnectionKind, Page};
+use crate::types::object::{self, Object, ObjectFilter};
+use crate::types::type_filter::TypeFilter;
+
+/// Path of the bulk object export endpoint.
+pub(crate) const EXPORT_OBJECTS_PATH: &str = "/export/objects";
+
+/// Content type of the export response: one JSON document per line.
+const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
+/// State shared by requests to the export endpoint.
+#[derive(Clone)]
+pub(crate) struct ExportState {
+    db: Db,
+    service: ServiceConfig,
+    /// Bearer token that requests must present in their `Authorization` header.
+    auth_token: String,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ExportParams {
+    /// Type filter, in the same format accepted by `ObjectFilter.type`.
+    #[serde(rename = "type")]
+    type_: String,
+}
+
+impl ExportState {
+    pub(crate) fn new(db: Db, service: ServiceConfig, auth_token: String) -> Self {
+        Self {
+            db,
+            service,
+            auth_token,
+        }
+    }
+
+    fn is_authorized(&self, headers: &HeaderMap) -> bool {
+        headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .is_some_and(|token| bool::from(token.as_bytes().ct_eq(self.auth_token.as_bytes())))
+    }
+}
+
+/// Streams all live objects matching the `type` query parameter as newline-delimited JSON, as of
+/// the current watermark. Objects are read a page at a time (bounded by the service's maximum page
+/// size for objects), so the response is produced incrementally rather than buffered in full.
+pub(crate) async fn export_objects(
+    State(state): State<ExportState>,
+    axum::Extension(watermark_lock): axum::Extension<WatermarkLock>,
+    headers: HeaderMap,
+    Query(params): Query<ExportParams>,
+) -> Response {
+    if !state.is_authorized(&headers) {
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+
+    let type_ = match TypeFilter::from_str(&params.type_) {
+        Ok(type_) => type_,
+        Err(e) => return (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    };
+
+    let filter = ObjectFilter {
+        type_: Some(type_),
+        ..Default::default()
+    };
+
+    let checkpoint_viewed_at = Watermark::new(watermark_lock).await.checkpoint;
+
+    // The state of the stream is the cursor to resume from, or `None` once the last page has been
+    // produced.
+    let pages = stream::try_unfold(Some(None), move |after: Option<Option<object::Cursor>>| {
+        let state = state.clone();
+        let filter = filter.clone();
+        async move {
+            let Some(after) = after else {
+                return Ok(None);
+            };
+
+            let kind = ConnectionKind::Object;
+            let limit = kind.max_page_size(&state.service.limits);
+            let page = Page::from_params_for(kind, &state.service, Some(limit), after, None, None)
+                .map_err(|e| Error::Internal(e.message))?;
+
+            let conn = Object::paginate(&state.db, page, filter, checkpoint_viewed_at).await?;
+
+            let mut lines = String::new();
+            for edge in &conn.edges {
+                if let Some(line) = export_line(&edge.node)? {
+                    lines.push_str(&line);
+                    lines.push('\n');
+                }
+            }
+
+            let next = match conn.edges.last() {
+                Some(edge) if conn.has_next_page => Some(Some(
+                    object::Cursor::decode_cursor(&edge.cursor)
+                        .map_err(|e| Error::Internal(format!("Invalid export cursor: {e}")))?,
+                )),
+                _ => None,
+            };
+
+            Ok::<_, Error>(Some((lines, next)))
+        }
+    });
+
+    (
+        [(header::CONTENT_TYPE, NDJSON_CONTENT_TYPE)],
+        StreamBody::new(pages),
+    )
+        .into_response()
+}
+
+/// Serialize a single object as a line of the export. Objects without contents (wrapped or
+/// deleted) are skipped.
+fn export_line(object: &Object) -> Result<Option<String>, Error> {
+    let Some(native) = object.native_impl() else {
+        return Ok(None);
+    };
+
+    let bcs = bcs::to_bytes(native).map_err(|e| {
+        Error::Internal(format!(
+            "Failed to serialize object at {}: {e}",
+            object.address
+        ))
+    })?;
+
+    let line = json!({
+        "address": native.id().to_string(),
+        "version": native.version().value(),
+        "digest": native.digest().to_string(),
+        "type": native
+            .type_()
+            .map(|type_| StructTag::from(type_.clone()).to_canonical_string(/* with_prefix */ true)),
+        "bcs": Base64::encode(bcs),
+    });
+
+    Ok(Some(line.to_string()))
+}
@@ -44,7 +44,7 @@ async fn start_graphiql_server_impl(
     server_builder: ServerBuilder,
     ide_title: String,
 ) -> Result<(), Error> {
-    let address = server_builder.address();
+    let url = server_builder.url();
 
     // Add GraphiQL IDE handler on GET request to `/`` endpoint
     let server = server_builder
@@ -55,7 +55,7 @@ async fn start_graphiql_server_impl(
         .layer(axum::extract::Extension(Some(ide_title)))
         .build()?;
 
-    info!("Launch GraphiQL IDE at: http://{}", address);
+    info!("Launch GraphiQL IDE at: {}", url);
 
     server.run().await
 }
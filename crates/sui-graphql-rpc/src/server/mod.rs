@@ -4,6 +4,7 @@
 pub mod graphiql_server;
 
 pub mod builder;
+pub(crate) mod export;
 pub(crate) mod system_package_task;
 pub mod version;
 pub(crate) mod watermark_task;
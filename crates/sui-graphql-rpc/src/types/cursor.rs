@@ -15,7 +15,7 @@ use fastcrypto::encoding::{Base64, Encoding};
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
-    config::ServiceConfig,
+    config::{Limits, ServiceConfig},
     consistency::{Checkpointed, ConsistentIndexCursor},
     data::{Conn, DbConnection, DieselBackend, DieselConn, Query},
     error::Error,
@@ -48,6 +48,14 @@ pub(crate) struct Page<C> {
     end: End,
 }
 
+/// Kinds of connection whose max page size can be configured separately from the global one in
+/// `Limits`.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub(crate) enum ConnectionKind {
+    Object,
+    Event,
+}
+
 /// Whether the page is extracted from the beginning or the end of the range bounded by the cursors.
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
 enum End {
@@ -134,13 +142,39 @@ impl<C> Page<C> {
         before: Option<C>,
     ) -> Result<Self> {
         let limits = &config.limits;
+        Self::from_params_with_max(limits, limits.max_page_size, first, after, last, before)
+    }
+
+    /// Like `from_params`, but for a connection of `kind`, whose max page size may be overridden
+    /// in the `config`'s limits. The default page size is capped at that max.
+    pub(crate) fn from_params_for(
+        kind: ConnectionKind,
+        config: &ServiceConfig,
+        first: Option<u64>,
+        after: Option<C>,
+        last: Option<u64>,
+        before: Option<C>,
+    ) -> Result<Self> {
+        let limits = &config.limits;
+        let max_page_size = kind.max_page_size(limits);
+        Self::from_params_with_max(limits, max_page_size, first, after, last, before)
+    }
+
+    fn from_params_with_max(
+        limits: &Limits,
+        max_page_size: u64,
+        first: Option<u64>,
+        after: Option<C>,
+        last: Option<u64>,
+        before: Option<C>,
+    ) -> Result<Self> {
         let page = match (first, after, last, before) {
             (Some(_), _, Some(_), _) => return Err(Error::CursorNoFirstLast.extend()),
 
             (limit, after, None, before) => Page {
                 after,
                 before,
-                limit: limit.unwrap_or(limits.default_page_size),
+                limit: limit.unwrap_or(limits.default_page_size.min(max_page_size)),
                 end: End::Front,
             },
 
@@ -152,8 +186,8 @@ impl<C> Page<C> {
             },
         };
 
-        if page.limit > limits.max_page_size {
-            return Err(Error::PageTooLarge(page.limit, limits.max_page_size).extend());
+        if page.limit > max_page_size {
+            return Err(Error::PageTooLarge(page.limit, max_page_size).extend());
         }
 
         Ok(page)
@@ -186,6 +220,18 @@ impl<C> Page<C> {
     }
 }
 
+impl ConnectionKind {
+    /// The max page size for this kind of connection, falling back to the global max page size
+    /// if it has not been overridden.
+    pub(crate) fn max_page_size(self, limits: &Limits) -> u64 {
+        match self {
+            ConnectionKind::Object => limits.object_max_page_size,
+            ConnectionKind::Event => limits.event_max_page_size,
+        }
+        .unwrap_or(limits.max_page_size)
+    }
+}
+
 impl<C> Page<C>
 where
     C: Checkpointed,
@@ -816,4 +862,31 @@ mod tests {
             }"#]];
         expect.assert_eq(&format!("{err:#?}"));
     }
+
+    #[test]
+    fn test_connection_kind_max_page_size() {
+        let mut config = ServiceConfig::default();
+        config.limits.object_max_page_size = Some(10);
+
+        // Objects are limited by their own max, which also caps the default page size.
+        let page: Page<JsonCursor<u64>> =
+            Page::from_params_for(ConnectionKind::Object, &config, None, None, None, None).unwrap();
+        assert_eq!(page.limit(), 10);
+        assert!(Page::<JsonCursor<u64>>::from_params_for(
+            ConnectionKind::Object,
+            &config,
+            Some(11),
+            None,
+            None,
+            None
+        )
+        .is_err());
+
+        // Events fall back to the global max.
+        let max = config.limits.max_page_size;
+        let page: Page<JsonCursor<u64>> =
+            Page::from_params_for(ConnectionKind::Event, &config, Some(max), None, None, None)
+                .unwrap();
+        assert_eq!(page.limit(), max as usize);
+    }
 }
@@ -3,7 +3,7 @@
 
 use super::address::Address;
 use super::coin_metadata::CoinMetadata;
-use super::cursor::Page;
+use super::cursor::{ConnectionKind, Page};
 use super::dynamic_field::DynamicField;
 use super::dynamic_field::DynamicFieldName;
 use super::move_package::MovePackage;
@@ -306,7 +306,14 @@ impl OwnerImpl {
         before: Option<object::Cursor>,
         filter: Option<ObjectFilter>,
     ) -> Result<Connection<String, MoveObject>> {
-        let page = Page::from_params(ctx.data_unchecked(), first, after, last, before)?;
+        let page = Page::from_params_for(
+            ConnectionKind::Object,
+            ctx.data_unchecked(),
+            first,
+            after,
+            last,
+            before,
+        )?;
 
         let Some(filter) = filter.unwrap_or_default().intersect(ObjectFilter {
             owner: Some(self.address),
@@ -369,7 +376,14 @@ impl OwnerImpl {
         before: Option<object::Cursor>,
         type_: Option<ExactTypeFilter>,
     ) -> Result<Connection<String, Coin>> {
-        let page = Page::from_params(ctx.data_unchecked(), first, after, last, before)?;
+        let page = Page::from_params_for(
+            ConnectionKind::Object,
+            ctx.data_unchecked(),
+            first,
+            after,
+            last,
+            before,
+        )?;
         let coin = type_.map_or_else(GAS::type_tag, |t| t.0);
         Coin::paginate(
             ctx.data_unchecked(),
@@ -390,7 +404,14 @@ impl OwnerImpl {
         last: Option<u64>,
         before: Option<object::Cursor>,
     ) -> Result<Connection<String, StakedSui>> {
-        let page = Page::from_params(ctx.data_unchecked(), first, after, last, before)?;
+        let page = Page::from_params_for(
+            ConnectionKind::Object,
+            ctx.data_unchecked(),
+            first,
+            after,
+            last,
+            before,
+        )?;
         StakedSui::paginate(
             ctx.data_unchecked(),
             page,
@@ -422,7 +443,14 @@ impl OwnerImpl {
         last: Option<u64>,
         before: Option<object::Cursor>,
     ) -> Result<Connection<String, SuinsRegistration>> {
-        let page = Page::from_params(ctx.data_unchecked(), first, after, last, before)?;
+        let page = Page::from_params_for(
+            ConnectionKind::Object,
+            ctx.data_unchecked(),
+            first,
+            after,
+            last,
+            before,
+        )?;
         SuinsRegistration::paginate(
             ctx.data_unchecked::<Db>(),
             ctx.data_unchecked::<NameServiceConfig>(),
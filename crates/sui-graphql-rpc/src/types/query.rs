@@ -20,7 +20,7 @@ use super::{
     checkpoint::{self, Checkpoint, CheckpointId},
     coin::Coin,
     coin_metadata::CoinMetadata,
-    cursor::Page,
+    cursor::{ConnectionKind, Page},
     digest::Digest,
     dry_run_result::DryRunResult,
     epoch::Epoch,
@@ -269,7 +269,14 @@ impl Query {
     ) -> Result<Connection<String, Coin>> {
         let Watermark { checkpoint, .. } = *ctx.data()?;
 
-        let page = Page::from_params(ctx.data_unchecked(), first, after, last, before)?;
+        let page = Page::from_params_for(
+            ConnectionKind::Object,
+            ctx.data_unchecked(),
+            first,
+            after,
+            last,
+            before,
+        )?;
         let coin = type_.map_or_else(GAS::type_tag, |t| t.0);
         Coin::paginate(
             ctx.data_unchecked(),
@@ -339,7 +346,14 @@ impl Query {
     ) -> Result<Connection<String, Event>> {
         let Watermark { checkpoint, .. } = *ctx.data()?;
 
-        let page = Page::from_params(ctx.data_unchecked(), first, after, last, before)?;
+        let page = Page::from_params_for(
+            ConnectionKind::Event,
+            ctx.data_unchecked(),
+            first,
+            after,
+            last,
+            before,
+        )?;
         Event::paginate(
             ctx.data_unchecked(),
             page,
@@ -362,7 +376,14 @@ impl Query {
     ) -> Result<Connection<String, Object>> {
         let Watermark { checkpoint, .. } = *ctx.data()?;
 
-        let page = Page::from_params(ctx.data_unchecked(), first, after, last, before)?;
+        let page = Page::from_params_for(
+            ConnectionKind::Object,
+            ctx.data_unchecked(),
+            first,
+            after,
+            last,
+            before,
+        )?;
         Object::paginate(
             ctx.data_unchecked(),
             page,
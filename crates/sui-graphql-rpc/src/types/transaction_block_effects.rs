@@ -29,7 +29,7 @@ use super::{
     balance_change::BalanceChange,
     base64::Base64,
     checkpoint::{Checkpoint, CheckpointId},
-    cursor::{JsonCursor, Page},
+    cursor::{ConnectionKind, JsonCursor, Page},
     date_time::DateTime,
     digest::Digest,
     epoch::Epoch,
@@ -389,7 +389,14 @@ impl TransactionBlockEffects {
         last: Option<u64>,
         before: Option<CEvent>,
     ) -> Result<Connection<String, Event>> {
-        let page = Page::from_params(ctx.data_unchecked(), first, after, last, before)?;
+        let page = Page::from_params_for(
+            ConnectionKind::Event,
+            ctx.data_unchecked(),
+            first,
+            after,
+            last,
+            before,
+        )?;
         let mut connection = Connection::new(false, false);
         let len = match &self.kind {
             TransactionBlockEffectsKind::Stored { stored_tx, .. } => stored_tx.get_event_len(),
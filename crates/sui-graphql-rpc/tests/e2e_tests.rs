@@ -891,9 +891,27 @@ mod tests {
         test_query_max_page_limit_impl().await;
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn test_query_connection_max_page_limit() {
+        test_query_connection_max_page_limit_impl().await;
+    }
+
     #[tokio::test]
     #[serial]
     async fn test_query_complexity_metrics() {
         test_query_complexity_metrics_impl().await;
     }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_object_export() {
+        let connection_config = ConnectionConfig::ci_integration_test_cfg();
+        let cluster =
+            sui_graphql_rpc::test_infra::cluster::start_cluster(connection_config, None).await;
+        cluster
+            .wait_for_checkpoint_catchup(1, Duration::from_secs(10))
+            .await;
+        test_object_export_impl().await;
+    }
 }
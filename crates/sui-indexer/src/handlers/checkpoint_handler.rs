@@ -13,7 +13,7 @@ use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, Mutex};
 use sui_package_resolver::{PackageStore, PackageStoreWithLruCache, Resolver};
 use sui_rest_api::{CheckpointData, CheckpointTransaction, Client};
-use sui_types::base_types::ObjectRef;
+use sui_types::base_types::{ObjectRef, SuiAddress};
 use sui_types::dynamic_field::DynamicFieldInfo;
 use sui_types::dynamic_field::DynamicFieldName;
 use sui_types::dynamic_field::DynamicFieldType;
@@ -440,11 +440,7 @@ where
                 .collect::<Vec<_>>();
 
             // Changed Objects
-            let changed_objects = fx
-                .all_changed_objects()
-                .into_iter()
-                .map(|(object_ref, _owner, _write_kind)| object_ref.0)
-                .collect::<Vec<_>>();
+            let changed_objects = get_changed_object_ids(&fx);
 
             // Payers
             let payers = vec![tx.gas_owner()];
@@ -453,15 +449,7 @@ where
             let senders = vec![tx.sender()];
 
             // Recipients
-            let recipients = fx
-                .all_changed_objects()
-                .into_iter()
-                .filter_map(|(_object_ref, owner, _write_kind)| match owner {
-                    Owner::AddressOwner(address) => Some(address),
-                    _ => None,
-                })
-                .unique()
-                .collect::<Vec<_>>();
+            let recipients = get_recipient_addresses(&fx);
 
             // Move Calls
             let move_calls = tx
@@ -759,6 +747,37 @@ pub fn get_deleted_objects(effects: &TransactionEffects) -> Vec<ObjectRef> {
         .collect::<Vec<_>>()
 }
 
+/// IDs of the objects created, mutated or unwrapped by a transaction.
+pub fn get_changed_object_ids(effects: &TransactionEffects) -> Vec<ObjectID> {
+    effects
+        .all_changed_objects()
+        .into_iter()
+        .map(|(object_ref, _owner, _write_kind)| object_ref.0)
+        .collect()
+}
+
+/// IDs of the objects created by a transaction.
+pub fn get_created_object_ids(effects: &TransactionEffects) -> Vec<ObjectID> {
+    effects
+        .created()
+        .into_iter()
+        .map(|(object_ref, _owner)| object_ref.0)
+        .collect()
+}
+
+/// Distinct addresses that own an object created, mutated or unwrapped by a transaction.
+pub fn get_recipient_addresses(effects: &TransactionEffects) -> Vec<SuiAddress> {
+    effects
+        .all_changed_objects()
+        .into_iter()
+        .filter_map(|(_object_ref, owner, _write_kind)| match owner {
+            Owner::AddressOwner(address) => Some(address),
+            _ => None,
+        })
+        .unique()
+        .collect()
+}
+
 pub fn get_latest_objects(
     objects: Vec<&Object>,
 ) -> (
@@ -854,3 +873,93 @@ fn try_create_dynamic_field_info(
         },
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use sui_types::base_types::random_object_ref;
+    use sui_types::digests::TransactionDigest;
+    use sui_types::execution_status::ExecutionStatus;
+    use sui_types::gas::GasCostSummary;
+
+    use super::*;
+
+    #[test]
+    fn derive_indices_from_effects() {
+        let alice = SuiAddress::random_for_testing_only();
+        let bob = SuiAddress::random_for_testing_only();
+
+        let gas = random_object_ref();
+        let created_by_alice = random_object_ref();
+        let created_shared = random_object_ref();
+        let mutated_by_bob = random_object_ref();
+        let unwrapped_by_bob = random_object_ref();
+        let wrapped = random_object_ref();
+        let deleted = random_object_ref();
+        let unwrapped_then_deleted = random_object_ref();
+
+        let effects = TransactionEffects::new_from_execution_v1(
+            ExecutionStatus::Success,
+            0,
+            GasCostSummary::default(),
+            vec![],
+            vec![],
+            TransactionDigest::random(),
+            vec![
+                (created_by_alice, Owner::AddressOwner(alice)),
+                (
+                    created_shared,
+                    Owner::Shared {
+                        initial_shared_version: created_shared.1,
+                    },
+                ),
+            ],
+            vec![
+                (gas, Owner::AddressOwner(alice)),
+                (mutated_by_bob, Owner::AddressOwner(bob)),
+            ],
+            vec![(unwrapped_by_bob, Owner::AddressOwner(bob))],
+            vec![deleted],
+            vec![unwrapped_then_deleted],
+            vec![wrapped],
+            (gas, Owner::AddressOwner(alice)),
+            None,
+            vec![],
+        );
+
+        assert_eq!(
+            get_created_object_ids(&effects),
+            vec![created_by_alice.0, created_shared.0],
+        );
+
+        // Wrapped and deleted objects are not changed objects, but unwrapped ones are.
+        assert_eq!(
+            get_changed_object_ids(&effects)
+                .into_iter()
+                .collect::<BTreeSet<_>>(),
+            BTreeSet::from([
+                gas.0,
+                created_by_alice.0,
+                created_shared.0,
+                mutated_by_bob.0,
+                unwrapped_by_bob.0,
+            ]),
+        );
+
+        let recipients = get_recipient_addresses(&effects);
+        assert_eq!(recipients.len(), 2);
+        assert_eq!(
+            recipients.into_iter().collect::<BTreeSet<_>>(),
+            BTreeSet::from([alice, bob]),
+        );
+
+        assert_eq!(
+            get_deleted_objects(&effects)
+                .into_iter()
+                .map(|object_ref| object_ref.0)
+                .collect::<BTreeSet<_>>(),
+            BTreeSet::from([deleted.0, wrapped.0, unwrapped_then_deleted.0]),
+        );
+    }
+}
@@ -1185,6 +1185,46 @@ impl<U: R2D2Connection> IndexerReader<U> {
         Ok(sui_events)
     }
 
+    pub async fn events_by_type_prefix_in_blocking_task(
+        &self,
+        prefix: String,
+        start_time: u64,
+        end_time: u64,
+        limit: usize,
+    ) -> IndexerResult<Vec<StoredEvent>> {
+        self.spawn_blocking(move |this| {
+            this.events_by_type_prefix(&prefix, start_time, end_time, limit)
+        })
+        .await
+    }
+
+    /// Events whose type starts with `prefix`, such as `0x2::coin::` for every event declared in
+    /// the `coin` module, emitted at timestamps in `[start_time, end_time)`, in the order they
+    /// were emitted. The prefix is matched with `LIKE`, served by the `event_type` index, and is
+    /// not escaped: `%` and `_` in it are wildcards. Event types never contain `%`, but `_` in
+    /// the prefix also matches any other single character.
+    pub fn events_by_type_prefix(
+        &self,
+        prefix: &str,
+        start_time: u64,
+        end_time: u64,
+        limit: usize,
+    ) -> IndexerResult<Vec<StoredEvent>> {
+        let pattern = format!("{prefix}%");
+        run_query!(&self.pool, |conn| {
+            events::table
+                .filter(events::event_type.like(pattern))
+                .filter(events::timestamp_ms.ge(start_time as i64))
+                .filter(events::timestamp_ms.lt(end_time as i64))
+                .order((
+                    events::tx_sequence_number.asc(),
+                    events::event_sequence_number.asc(),
+                ))
+                .limit(limit as i64)
+                .load::<StoredEvent>(conn)
+        })
+    }
+
     pub async fn get_dynamic_fields_in_blocking_task(
         &self,
         parent_object_id: ObjectID,
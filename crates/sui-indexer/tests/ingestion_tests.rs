@@ -14,13 +14,14 @@ mod ingestion_tests {
     use sui_indexer::db::get_pool_connection;
     use sui_indexer::errors::Context;
     use sui_indexer::errors::IndexerError;
+    use sui_indexer::indexer_reader::IndexerReader;
     use sui_indexer::models::{
         events::StoredEvent, objects::StoredObject, transactions::StoredTransaction,
     };
     use sui_indexer::schema::{events, objects, transactions};
     use sui_indexer::store::{indexer_store::IndexerStore, PgIndexerStore};
     use sui_indexer::test_utils::{start_test_indexer, ReaderWriterConfig};
-    use sui_types::base_types::SuiAddress;
+    use sui_types::base_types::{ObjectID, SuiAddress};
     use sui_types::effects::TransactionEffectsAPI;
     use sui_types::gas_coin::GasCoin;
     use sui_types::storage::ReadStore;
@@ -249,4 +250,69 @@ mod ingestion_tests {
         assert_eq!(db_object.object_type_name, Some("Coin".to_string()));
         Ok(())
     }
+
+    #[tokio::test]
+    pub async fn test_events_by_type_prefix() -> Result<(), IndexerError> {
+        let mut sim = Simulacrum::new();
+        let data_ingestion_path = tempdir().unwrap().into_path();
+        sim.set_data_ingestion_path(data_ingestion_path.clone());
+        let _ = sim.create_checkpoint();
+
+        let (_, pg_store, _) = set_up(Arc::new(sim), data_ingestion_path).await;
+        wait_for_checkpoint(&pg_store, 1).await?;
+
+        // Events from a fresh package, so that they cannot be confused with indexed ones.
+        let package = ObjectID::random();
+        let prefix = format!("{}::pool::", package.to_hex_literal());
+        let event =
+            |tx_sequence_number: i64, module: &str, name: &str, timestamp_ms: i64| StoredEvent {
+                tx_sequence_number,
+                event_sequence_number: 0,
+                transaction_digest: vec![0; 32],
+                checkpoint_sequence_number: 0,
+                senders: vec![],
+                package: package.to_vec(),
+                module: module.to_owned(),
+                event_type: format!("{}::{module}::{name}", package.to_hex_literal()),
+                event_type_package: package.to_vec(),
+                event_type_module: module.to_owned(),
+                event_type_name: name.to_owned(),
+                timestamp_ms,
+                bcs: vec![],
+            };
+        let stored_events = vec![
+            event(1_000_000, "pool", "Deposit", 100),
+            event(1_000_001, "pool", "Withdraw", 200),
+            // Shares the module name as a prefix, but is declared in a different module.
+            event(1_000_002, "pool_v2", "Deposit", 300),
+            event(1_000_003, "vault", "Deposit", 400),
+            // Outside of the queried time range.
+            event(1_000_004, "pool", "Deposit", 1_000),
+        ];
+
+        let mut conn = get_pool_connection::<diesel::PgConnection>(&pg_store.blocking_cp())?;
+        diesel::insert_into(events::table)
+            .values(&stored_events)
+            .execute(&mut conn)
+            .map_err(|e| IndexerError::PostgresWriteError(e.to_string()))?;
+
+        let reader = IndexerReader::<diesel::PgConnection>::new(DEFAULT_DB_URL)
+            .map_err(|e| IndexerError::PgConnectionPoolInitError(e.to_string()))?;
+        let matching = reader
+            .events_by_type_prefix_in_blocking_task(prefix, 0, 1_000, 10)
+            .await?;
+
+        let event_types = matching
+            .iter()
+            .map(|event| event.event_type.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            event_types,
+            vec![
+                stored_events[0].event_type.clone(),
+                stored_events[1].event_type.clone(),
+            ]
+        );
+        Ok(())
+    }
 }
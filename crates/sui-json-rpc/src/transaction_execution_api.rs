@@ -142,13 +142,8 @@ impl TransactionExecutionApi {
 
         let transaction_orchestrator = self.transaction_orchestrator.clone();
         let orch_timer = self.metrics.orchestrator_latency_ms.start_timer();
-        let response = spawn_monitored_task!(transaction_orchestrator.execute_transaction_block(
-            ExecuteTransactionRequest {
-                transaction: txn,
-                request_type,
-            },
-            None,
-        ))
+        let response = spawn_monitored_task!(transaction_orchestrator
+            .execute_transaction_block(ExecuteTransactionRequest::new(txn, request_type), None,))
         .await?
         .map_err(Error::from)?;
         drop(orch_timer);
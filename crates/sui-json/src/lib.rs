@@ -1,12 +1,13 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::{BTreeMap, VecDeque};
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::fmt::{self, Debug, Formatter};
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
 
 use anyhow::{anyhow, bail};
-use fastcrypto::encoding::{Encoding, Hex};
+use fastcrypto::encoding::{Base64, Encoding, Hex};
 use move_binary_format::CompiledModule;
 use move_binary_format::{binary_config::BinaryConfig, file_format::SignatureToken};
 use move_bytecode_utils::resolve_struct;
@@ -27,9 +28,10 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, Number, Value as JsonValue};
 
 use sui_types::base_types::{
-    is_primitive_type_tag, ObjectID, SuiAddress, TxContext, TxContextKind, RESOLVED_ASCII_STR,
-    RESOLVED_STD_OPTION, RESOLVED_UTF8_STR, STD_ASCII_MODULE_NAME, STD_ASCII_STRUCT_NAME,
-    STD_OPTION_MODULE_NAME, STD_OPTION_STRUCT_NAME, STD_UTF8_MODULE_NAME, STD_UTF8_STRUCT_NAME,
+    is_primitive_type_tag, ObjectID, SequenceNumber, SuiAddress, TxContext, TxContextKind,
+    RESOLVED_ASCII_STR, RESOLVED_STD_OPTION, RESOLVED_UTF8_STR, STD_ASCII_MODULE_NAME,
+    STD_ASCII_STRUCT_NAME, STD_OPTION_MODULE_NAME, STD_OPTION_STRUCT_NAME, STD_UTF8_MODULE_NAME,
+    STD_UTF8_STRUCT_NAME,
 };
 use sui_types::id::{ID, RESOLVED_SUI_ID};
 use sui_types::move_package::MovePackage;
@@ -38,6 +40,7 @@ use sui_types::transfer::RESOLVED_RECEIVING_STRUCT;
 use sui_types::MOVE_STDLIB_ADDRESS;
 
 const HEX_PREFIX: &str = "0x";
+const BASE64_PREFIX: &str = "base64:";
 
 #[cfg(test)]
 mod tests;
@@ -314,22 +317,20 @@ impl SuiJsonValue {
             (JsonValue::String(s), MoveTypeLayout::Vector(t)) => {
                 match &**t {
                     MoveTypeLayout::U8 => {
-                        // We can encode U8 Vector as string in 2 ways
+                        // We can encode U8 Vector as string in 3 ways
                         // 1. If it starts with 0x, we treat it as hex strings, where each pair is a
                         //    byte
-                        // 2. If it does not start with 0x, we treat each character as an ASCII
-                        //    encoded byte
-                        // We have to support both for the convenience of the user. This is because
+                        // 2. If it starts with base64:, we treat the rest as Base64
+                        // 3. Otherwise, we treat the string as its UTF-8 encoded bytes
+                        // We have to support these for the convenience of the user. This is because
                         // sometime we need Strings as arg Other times we need vec of hex bytes for
                         // address. Issue is both Address and Strings are represented as Vec<u8> in
                         // Move call
-                        let vec = if s.starts_with(HEX_PREFIX) {
-                            // If starts with 0x, treat as hex vector
-                            Hex::decode(s).map_err(|e| anyhow!(e))?
-                        } else {
-                            // Else raw bytes
-                            s.as_bytes().to_vec()
-                        };
+                        // Note that `std::string::String` and `std::ascii::String` args are always
+                        // taken literally, so `"base64:q80="` is 11 bytes for those but 2 bytes for
+                        // a `vector<u8>`.
+                        let (encoding, s) = ByteStringEncoding::infer(s);
+                        let vec = encoding.decode(s)?;
                         R::MoveValue::Vector(vec.iter().copied().map(R::MoveValue::U8).collect())
                     }
                     MoveTypeLayout::Struct(MoveStructLayout { fields: inner, .. }) => {
@@ -359,6 +360,41 @@ impl SuiJsonValue {
     }
 }
 
+/// Encodings accepted for strings that stand for a sequence of bytes, such as `vector<u8>`
+/// arguments and addresses.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum ByteStringEncoding {
+    /// Pairs of hex digits, optionally prefixed with `0x`.
+    Hex,
+    /// The UTF-8 bytes of the string.
+    Utf8,
+    /// Standard (padded) Base64.
+    Base64,
+}
+
+impl ByteStringEncoding {
+    /// Infer the encoding of a byte string from its prefix: strings starting with `0x` are hex,
+    /// strings starting with `base64:` are Base64 (and the prefix is stripped), and everything else
+    /// is taken as its UTF-8 bytes. Returns the encoding, and the part of `s` to decode with it.
+    pub fn infer(s: &str) -> (Self, &str) {
+        if s.starts_with(HEX_PREFIX) {
+            (Self::Hex, s)
+        } else if let Some(rest) = s.strip_prefix(BASE64_PREFIX) {
+            (Self::Base64, rest)
+        } else {
+            (Self::Utf8, s)
+        }
+    }
+
+    pub fn decode(&self, s: &str) -> Result<Vec<u8>, anyhow::Error> {
+        match self {
+            Self::Hex => Hex::decode(s).map_err(|e| anyhow!(e)),
+            Self::Utf8 => Ok(s.as_bytes().to_vec()),
+            Self::Base64 => Base64::decode(s).map_err(|e| anyhow!(e)),
+        }
+    }
+}
+
 impl Debug for SuiJsonValue {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
@@ -372,7 +408,8 @@ fn json_value_to_sui_address(value: &JsonValue) -> anyhow::Result<SuiAddress> {
             if !s.starts_with(HEX_PREFIX) {
                 bail!("Address hex string must start with 0x.",);
             }
-            Ok(SuiAddress::from_str(&s)?)
+            let bytes = ByteStringEncoding::Hex.decode(&s)?;
+            Ok(SuiAddress::try_from(bytes)?)
         }
         JsonValue::Array(bytes) => {
             fn value_to_byte_array(v: &Vec<JsonValue>) -> Option<Vec<u8>> {
@@ -815,47 +852,132 @@ pub fn resolve_move_function_args(
     type_args: &[TypeTag],
     combined_args_json: Vec<SuiJsonValue>,
 ) -> Result<Vec<(ResolvedCallArg, SignatureToken)>, anyhow::Error> {
-    // Extract the expected function signature
-    let module = package.deserialize_module(&module_ident, &BinaryConfig::standard())?;
-    let function_str = function.as_ident_str();
-    let fdef = module
-        .function_defs
-        .iter()
-        .find(|fdef| {
-            module.identifier_at(module.function_handle_at(fdef.function).name) == function_str
-        })
-        .ok_or_else(|| {
-            anyhow!(
-                "Could not resolve function {} in module {}",
-                function,
-                module_ident
-            )
-        })?;
-    let function_signature = module.function_handle_at(fdef.function);
-    let parameters = &module.signature_at(function_signature.parameters).0;
-
-    // Lengths have to match, less one, due to TxContext
-    let expected_len = match parameters.last() {
-        Some(param) if TxContext::kind(&module, param) != TxContextKind::None => {
-            parameters.len() - 1
+    let signature = FunctionSignature::resolve(package, &module_ident, &function)?;
+    signature.resolve_args(type_args, combined_args_json)
+}
+
+/// The module defining a function, along with the function's parameters.
+#[derive(Debug)]
+struct FunctionSignature {
+    module: CompiledModule,
+    parameters: Vec<SignatureToken>,
+}
+
+impl FunctionSignature {
+    /// Extract the expected signature of `function` in `module_ident` from `package`.
+    fn resolve(
+        package: &MovePackage,
+        module_ident: &Identifier,
+        function: &Identifier,
+    ) -> Result<Self, anyhow::Error> {
+        let module = package.deserialize_module(module_ident, &BinaryConfig::standard())?;
+        let function_str = function.as_ident_str();
+        let fdef = module
+            .function_defs
+            .iter()
+            .find(|fdef| {
+                module.identifier_at(module.function_handle_at(fdef.function).name) == function_str
+            })
+            .ok_or_else(|| {
+                anyhow!(
+                    "Could not resolve function {} in module {}",
+                    function,
+                    module_ident
+                )
+            })?;
+        let function_signature = module.function_handle_at(fdef.function);
+        let parameters = module.signature_at(function_signature.parameters).0.clone();
+        Ok(Self { module, parameters })
+    }
+
+    fn resolve_args(
+        &self,
+        type_args: &[TypeTag],
+        combined_args_json: Vec<SuiJsonValue>,
+    ) -> Result<Vec<(ResolvedCallArg, SignatureToken)>, anyhow::Error> {
+        let module = &self.module;
+        let parameters = &self.parameters;
+
+        // Lengths have to match, less one, due to TxContext
+        let expected_len = match parameters.last() {
+            Some(param) if TxContext::kind(module, param) != TxContextKind::None => {
+                parameters.len() - 1
+            }
+            _ => parameters.len(),
+        };
+        if combined_args_json.len() != expected_len {
+            bail!(
+                "Expected {} args, found {}",
+                expected_len,
+                combined_args_json.len()
+            );
         }
-        _ => parameters.len(),
-    };
-    if combined_args_json.len() != expected_len {
-        bail!(
-            "Expected {} args, found {}",
-            expected_len,
-            combined_args_json.len()
+        // Check that the args are valid and convert to the correct format
+        let call_args = resolve_call_args(module, type_args, &combined_args_json, parameters)?;
+        let tupled_call_args = call_args
+            .into_iter()
+            .zip(parameters.iter())
+            .map(|(arg, expected_type)| (arg, expected_type.clone()))
+            .collect::<Vec<_>>();
+        Ok(tupled_call_args)
+    }
+}
+
+/// Memoizes function signatures extracted from packages, so that repeatedly resolving the
+/// arguments of the same function does not deserialize its module every time. Entries are
+/// keyed by package ID, module and function, and are replaced when the package version changes.
+#[derive(Default)]
+pub struct FunctionSignatureCache {
+    signatures: Mutex<HashMap<(ObjectID, Identifier, Identifier), CachedFunctionSignature>>,
+}
+
+struct CachedFunctionSignature {
+    version: SequenceNumber,
+    signature: Arc<FunctionSignature>,
+}
+
+impl FunctionSignatureCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Same as `resolve_move_function_args`, but reuses the cached signature of `function` when
+    /// it was previously resolved from the same version of `package`.
+    pub fn resolve_move_function_args(
+        &self,
+        package: &MovePackage,
+        module_ident: Identifier,
+        function: Identifier,
+        type_args: &[TypeTag],
+        combined_args_json: Vec<SuiJsonValue>,
+    ) -> Result<Vec<(ResolvedCallArg, SignatureToken)>, anyhow::Error> {
+        self.function_signature(package, module_ident, function)?
+            .resolve_args(type_args, combined_args_json)
+    }
+
+    fn function_signature(
+        &self,
+        package: &MovePackage,
+        module_ident: Identifier,
+        function: Identifier,
+    ) -> Result<Arc<FunctionSignature>, anyhow::Error> {
+        let key = (package.id(), module_ident, function);
+        if let Some(cached) = self.signatures.lock().unwrap().get(&key) {
+            if cached.version == package.version() {
+                return Ok(cached.signature.clone());
+            }
+        }
+
+        let signature = Arc::new(FunctionSignature::resolve(package, &key.1, &key.2)?);
+        self.signatures.lock().unwrap().insert(
+            key,
+            CachedFunctionSignature {
+                version: package.version(),
+                signature: signature.clone(),
+            },
         );
+        Ok(signature)
     }
-    // Check that the args are valid and convert to the correct format
-    let call_args = resolve_call_args(&module, type_args, &combined_args_json, parameters)?;
-    let tupled_call_args = call_args
-        .into_iter()
-        .zip(parameters.iter())
-        .map(|(arg, expected_type)| (arg, expected_type.clone()))
-        .collect::<Vec<_>>();
-    Ok(tupled_call_args)
 }
 
 fn convert_string_to_u256(s: &str) -> Result<U256, anyhow::Error> {
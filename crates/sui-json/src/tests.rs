@@ -3,6 +3,7 @@
 
 use std::path::Path;
 use std::str::FromStr;
+use std::sync::Arc;
 
 use fastcrypto::encoding::{Encoding, Hex};
 use move_core_types::annotated_value::{MoveFieldLayout, MoveStructLayout, MoveTypeLayout};
@@ -21,12 +22,13 @@ use sui_types::base_types::{
 use sui_types::dynamic_field::derive_dynamic_field_id;
 use sui_types::gas_coin::GasCoin;
 use sui_types::object::Object;
+use sui_types::SUI_FRAMEWORK_PACKAGE_ID;
 use sui_types::{parse_sui_type_tag, MOVE_STDLIB_ADDRESS};
 
 use crate::ResolvedCallArg;
 
-use super::{check_valid_homogeneous, HEX_PREFIX};
-use super::{resolve_move_function_args, SuiJsonValue};
+use super::{check_valid_homogeneous, ByteStringEncoding, HEX_PREFIX};
+use super::{resolve_move_function_args, FunctionSignatureCache, SuiJsonValue};
 
 // Negative test cases
 #[test]
@@ -661,6 +663,48 @@ fn test_basic_args_linter_top_level() {
     }
 }
 
+#[test]
+fn test_function_signature_cache() {
+    let mut package =
+        BuiltInFramework::get_package_by_id(&SUI_FRAMEWORK_PACKAGE_ID).genesis_move_package();
+
+    let module = Identifier::new("coin").unwrap();
+    let function = Identifier::new("value").unwrap();
+    let cache = FunctionSignatureCache::new();
+
+    let first = cache
+        .function_signature(&package, module.clone(), function.clone())
+        .unwrap();
+    let second = cache
+        .function_signature(&package, module.clone(), function.clone())
+        .unwrap();
+    assert!(Arc::ptr_eq(&first, &second));
+
+    // A new version of the package invalidates the cached signature.
+    package.increment_version();
+    let third = cache
+        .function_signature(&package, module.clone(), function.clone())
+        .unwrap();
+    assert!(!Arc::ptr_eq(&first, &third));
+
+    // Cached and uncached resolution agree.
+    let coin = json!(format!("{}", ObjectID::random()));
+    let args = vec![SuiJsonValue::new(coin).unwrap()];
+    let type_args = [parse_sui_type_tag("0x2::sui::SUI").unwrap()];
+    assert_eq!(
+        cache
+            .resolve_move_function_args(
+                &package,
+                module.clone(),
+                function.clone(),
+                &type_args,
+                args.clone()
+            )
+            .unwrap(),
+        resolve_move_function_args(&package, module, function, &type_args, args).unwrap(),
+    );
+}
+
 #[test]
 fn test_convert_address_from_bcs() {
     let bcs_bytes = [
@@ -936,3 +980,93 @@ fn test_string_vec_df_name_child_id_eq() {
         child_id.to_string()
     );
 }
+
+#[test]
+fn test_byte_string_encoding_infer() {
+    assert_eq!(
+        ByteStringEncoding::infer("0xabcd"),
+        (ByteStringEncoding::Hex, "0xabcd")
+    );
+    assert_eq!(
+        ByteStringEncoding::infer("base64:q80="),
+        (ByteStringEncoding::Base64, "q80=")
+    );
+    assert_eq!(
+        ByteStringEncoding::infer("abcd"),
+        (ByteStringEncoding::Utf8, "abcd")
+    );
+}
+
+#[test]
+fn test_byte_string_encoding_decode() {
+    use ByteStringEncoding as E;
+
+    assert_eq!(E::Hex.decode("0xabcd").unwrap(), vec![0xab, 0xcd]);
+    assert_eq!(E::Hex.decode("abcd").unwrap(), vec![0xab, 0xcd]);
+    assert!(E::Hex.decode("0xabc").is_err());
+    assert!(E::Hex.decode("0xzz").is_err());
+
+    assert_eq!(E::Utf8.decode("abcd").unwrap(), b"abcd".to_vec());
+    assert_eq!(E::Utf8.decode("").unwrap(), Vec::<u8>::new());
+    assert_eq!(
+        E::Utf8.decode("caf\u{e9}").unwrap(),
+        vec![b'c', b'a', b'f', 0xc3, 0xa9]
+    );
+
+    assert_eq!(E::Base64.decode("q80=").unwrap(), vec![0xab, 0xcd]);
+    assert!(E::Base64.decode("q80").is_err());
+    assert!(E::Base64.decode("not base64!").is_err());
+}
+
+#[test]
+fn test_u8_vector_from_byte_strings() {
+    let layout = MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8));
+    let expected = bcs::to_bytes(&vec![0xabu8, 0xcd]).unwrap();
+
+    for s in ["0xabcd", "base64:q80="] {
+        let sui_json = SuiJsonValue::new(json!(s)).unwrap();
+        assert_eq!(sui_json.to_bcs_bytes(&layout).unwrap(), expected);
+    }
+
+    let sui_json = SuiJsonValue::new(json!("test_str")).unwrap();
+    assert_eq!(
+        sui_json.to_bcs_bytes(&layout).unwrap(),
+        bcs::to_bytes(&b"test_str".to_vec()).unwrap()
+    );
+
+    let sui_json = SuiJsonValue::new(json!("caf\u{e9}")).unwrap();
+    assert_eq!(
+        sui_json.to_bcs_bytes(&layout).unwrap(),
+        bcs::to_bytes(&"caf\u{e9}".as_bytes().to_vec()).unwrap()
+    );
+
+    let sui_json = SuiJsonValue::new(json!("base64:q80")).unwrap();
+    assert!(sui_json.to_bcs_bytes(&layout).is_err());
+}
+
+#[test]
+fn test_base64_prefix_only_decoded_for_u8_vectors() {
+    let string_layout = MoveTypeLayout::Struct(MoveStructLayout {
+        type_: StructTag {
+            address: MOVE_STDLIB_ADDRESS,
+            module: STD_ASCII_MODULE_NAME.into(),
+            name: STD_ASCII_STRUCT_NAME.into(),
+            type_params: vec![],
+        },
+        fields: vec![MoveFieldLayout {
+            name: ident_str!("bytes").into(),
+            layout: MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8)),
+        }],
+    });
+    let u8_vector_layout = MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8));
+
+    let sui_json = SuiJsonValue::new(json!("base64:q80=")).unwrap();
+    assert_eq!(
+        sui_json.to_bcs_bytes(&string_layout).unwrap(),
+        bcs::to_bytes(&b"base64:q80=".to_vec()).unwrap()
+    );
+    assert_eq!(
+        sui_json.to_bcs_bytes(&u8_vector_layout).unwrap(),
+        bcs::to_bytes(&vec![0xabu8, 0xcd]).unwrap()
+    );
+}
@@ -1895,8 +1895,12 @@ pub async fn build_http_server(
     router = router.merge(json_rpc_router);
 
     if config.enable_experimental_rest_api {
+        let store = match &state.indexes {
+            Some(indexes) => store.with_indexes(indexes.clone()),
+            None => store,
+        };
         let rest_router =
-            sui_rest_api::RestService::new(Arc::new(store.clone()), chain_id, software_version)
+            sui_rest_api::RestService::new(Arc::new(store), chain_id, software_version)
                 .into_router();
         router = router.nest("/rest", rest_router);
     }
@@ -38,6 +38,9 @@ use syn::{parse_macro_input, Data, DeriveInput, Fields, Type};
 ///
 ///     /// Returns a map of all features to values
 ///     pub fn feature_map(&self) -> std::collections::BTreeMap<String, bool>;
+///
+///     /// Returns the configs whose values differ between `self` and `other`
+///     pub fn diff(&self, other: &Self) -> Vec<ProtocolConfigDiff>;
 /// ```
 #[proc_macro_derive(ProtocolConfigAccessors)]
 pub fn accessors_macro(input: TokenStream) -> TokenStream {
@@ -131,6 +134,16 @@ pub fn accessors_macro(input: TokenStream) -> TokenStream {
                             stringify!(#field_name)
                         };
 
+                        let value_diff = quote! {
+                            if self.#field_name != other.#field_name {
+                                diffs.push(ProtocolConfigDiff {
+                                    name: stringify!(#field_name).to_owned(),
+                                    old_value: self.#field_name.map(|v| v.to_string()),
+                                    new_value: other.#field_name.map(|v| v.to_string()),
+                                });
+                            }
+                        };
+
                         // Track all the types seen
                         if inner_types.contains(&inner_type) {
                             None
@@ -141,7 +154,10 @@ pub fn accessors_macro(input: TokenStream) -> TokenStream {
                             })
                         };
 
-                        Some(((getter, (test_setter, value_setter)), (value_lookup, field_name_str)))
+                        Some((
+                            (getter, (test_setter, value_setter)),
+                            (value_lookup, (field_name_str, value_diff)),
+                        ))
                     }
                     _ => None,
                 }
@@ -152,9 +168,9 @@ pub fn accessors_macro(input: TokenStream) -> TokenStream {
     };
 
     #[allow(clippy::type_complexity)]
-    let ((getters, (test_setters, value_setters)), (value_lookup, field_names_str)): (
+    let ((getters, (test_setters, value_setters)), (value_lookup, (field_names_str, value_diffs))): (
+        (Vec<_>, (Vec<_>, Vec<_>)),
         (Vec<_>, (Vec<_>, Vec<_>)),
-        (Vec<_>, Vec<_>),
     ) = tokens.unzip();
     let output = quote! {
         // For each getter, expand it out into a function in the impl block
@@ -185,6 +201,14 @@ pub fn accessors_macro(input: TokenStream) -> TokenStream {
             pub fn feature_map(&self) -> std::collections::BTreeMap<String, bool> {
                 self.feature_flags.attr_map()
             }
+
+            /// List the config attributes whose values differ between `self` and `other`, in
+            /// declaration order, including attributes that are only set in one of them
+            pub fn diff(&self, other: &Self) -> Vec<ProtocolConfigDiff> {
+                let mut diffs = vec![];
+                #(#value_diffs)*
+                diffs
+            }
         }
 
         // For each attr, derive a setter from the raw value and from string repr
@@ -205,6 +229,15 @@ pub fn accessors_macro(input: TokenStream) -> TokenStream {
             #(#inner_types(#inner_types),)*
         }
 
+        /// A config attribute whose value differs between two protocol configs, with each value
+        /// rendered as a string, or `None` if the attribute is not set in that config
+        #[derive(Clone, Serialize, Debug, PartialEq, Eq)]
+        pub struct ProtocolConfigDiff {
+            pub name: String,
+            pub old_value: Option<String>,
+            pub new_value: Option<String>,
+        }
+
         impl std::fmt::Display for ProtocolConfigValue {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 use std::fmt::Write;
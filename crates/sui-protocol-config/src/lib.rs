@@ -164,6 +164,25 @@ impl ProtocolVersion {
     pub fn max() -> Self {
         Self::MAX
     }
+
+    /// For `#[serde(deserialize_with)]` on config fields: rejects versions that are not supported
+    /// by this binary, so that a bad config fails to load with a readable error instead of
+    /// panicking later in `ProtocolConfig::get_for_version`.
+    pub fn deserialize_supported<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let version = Self(u64::deserialize(deserializer)?);
+        if version < Self::MIN || version > Self::MAX_ALLOWED {
+            return Err(serde::de::Error::custom(format!(
+                "protocol version {} is not supported by this binary, it must be between MIN ({}) and MAX ({})",
+                version.0,
+                Self::MIN.0,
+                Self::MAX_ALLOWED.0,
+            )));
+        }
+        Ok(version)
+    }
 }
 
 impl From<u64> for ProtocolVersion {
@@ -442,6 +461,10 @@ struct FeatureFlags {
     // Resolve Move abort locations to the package id instead of the runtime module ID.
     #[serde(skip_serializing_if = "is_false")]
     resolve_abort_locations_to_package_id: bool,
+
+    // If true, reject transactions that also pass a gas payment object as an input.
+    #[serde(skip_serializing_if = "is_false")]
+    reject_gas_object_as_argument: bool,
 }
 
 fn is_false(b: &bool) -> bool {
@@ -788,7 +811,6 @@ pub struct ProtocolConfig {
 
     /// === Tokenomics ===
 
-    // TODO: Option<this should be changed to u64.
     /// Sender of a txn that touches an object will get this percent of the storage rebate back.
     /// In basis point.
     storage_rebate_rate: Option<u64>,
@@ -1331,6 +1353,10 @@ impl ProtocolConfig {
     pub fn resolve_abort_locations_to_package_id(&self) -> bool {
         self.feature_flags.resolve_abort_locations_to_package_id
     }
+
+    pub fn reject_gas_object_as_argument(&self) -> bool {
+        self.feature_flags.reject_gas_object_as_argument
+    }
 }
 
 #[cfg(not(msim))]
@@ -2388,6 +2414,10 @@ impl ProtocolConfig {
     pub fn set_mysticeti_leader_scoring_and_schedule(&mut self, val: bool) {
         self.feature_flags.mysticeti_leader_scoring_and_schedule = val;
     }
+
+    pub fn set_reject_gas_object_as_argument_for_testing(&mut self, val: bool) {
+        self.feature_flags.reject_gas_object_as_argument = val
+    }
 }
 
 type OverrideFn = dyn Fn(ProtocolVersion, ProtocolConfig) -> ProtocolConfig + Send;
@@ -2483,6 +2513,36 @@ mod test {
     use super::*;
     use insta::assert_yaml_snapshot;
 
+    #[test]
+    fn deserialize_supported_protocol_version() {
+        use serde::de::value::{Error, U64Deserializer};
+
+        let deserialize =
+            |v: u64| ProtocolVersion::deserialize_supported(U64Deserializer::<Error>::new(v));
+
+        assert_eq!(
+            deserialize(MAX_PROTOCOL_VERSION).unwrap(),
+            ProtocolVersion::MAX
+        );
+        assert_eq!(
+            deserialize(MIN_PROTOCOL_VERSION).unwrap(),
+            ProtocolVersion::MIN
+        );
+
+        let err = deserialize(MAX_PROTOCOL_VERSION + 1)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains(&format!("MIN ({MIN_PROTOCOL_VERSION})")),
+            "{err}"
+        );
+        assert!(
+            err.contains(&format!("MAX ({MAX_PROTOCOL_VERSION})")),
+            "{err}"
+        );
+        assert!(deserialize(0).is_err());
+    }
+
     #[test]
     fn snapshot_tests() {
         println!("\n============================================================================");
@@ -2508,6 +2568,54 @@ mod test {
         }
     }
 
+    /// Asserts that `config` matches the checked-in snapshot of its version, the one
+    /// `snapshot_tests` records for `Chain::Unknown`, naming every setting that differs.
+    /// Unlike `assert_yaml_snapshot!`, a mismatch never writes a new snapshot to review.
+    fn assert_protocol_version_immutable(config: &ProtocolConfig) {
+        let version = config.version.as_u64();
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(format!(
+            "src/snapshots/sui_protocol_config__test__version_{version}.snap"
+        ));
+        let snapshot = std::fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("Cannot read {}: {e}", path.display()));
+        // Skip the metadata header of the snapshot.
+        let (_, contents) = snapshot.split_once("\n---\n").unwrap();
+        let expected: serde_yaml::Mapping = serde_yaml::from_str(contents).unwrap();
+        let actual = serde_yaml::to_value(config).unwrap();
+        let actual = actual.as_mapping().unwrap();
+
+        let changed: Vec<_> = expected
+            .iter()
+            .filter(|(name, value)| actual.get(name) != Some(value))
+            .chain(
+                actual
+                    .iter()
+                    .filter(|(name, _)| !expected.contains_key(name)),
+            )
+            .filter_map(|(name, _)| name.as_str())
+            .collect();
+        assert!(
+            changed.is_empty(),
+            "Protocol version {version} must not change, but these settings did: {changed:?}"
+        );
+    }
+
+    #[test]
+    fn protocol_versions_are_immutable() {
+        assert_protocol_version_immutable(&ProtocolConfig::get_for_version(
+            ProtocolVersion::new(1),
+            Chain::Unknown,
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "these settings did: [\"max_arguments\"]")]
+    fn protocol_version_mutation_is_detected() {
+        let mut config = ProtocolConfig::get_for_version(ProtocolVersion::new(1), Chain::Unknown);
+        config.set_max_arguments_for_testing(config.max_arguments() + 1);
+        assert_protocol_version_immutable(&config);
+    }
+
     #[test]
     fn test_getters() {
         let prot: ProtocolConfig =
@@ -2518,6 +2626,147 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_getter_types() {
+        // Getters are derived from the field's inner type, so these coercions only compile while
+        // each getter returns exactly the type its field stores.
+        let _: fn(&ProtocolConfig) -> u16 = ProtocolConfig::binary_module_handles;
+        let _: fn(&ProtocolConfig) -> u32 = ProtocolConfig::max_arguments;
+        let _: fn(&ProtocolConfig) -> u64 = ProtocolConfig::storage_rebate_rate;
+        let _: fn(&ProtocolConfig) -> u64 = ProtocolConfig::storage_fund_reinvest_rate;
+        let _: fn(&ProtocolConfig) -> u64 = ProtocolConfig::reward_slashing_rate;
+        let _: fn(&ProtocolConfig) -> f64 = ProtocolConfig::scoring_decision_mad_divisor;
+        let _: fn(&ProtocolConfig) -> Option<u64> = ProtocolConfig::storage_rebate_rate_as_option;
+        let _: fn(&ProtocolConfig) -> Option<f64> =
+            ProtocolConfig::scoring_decision_mad_divisor_as_option;
+
+        let prot: ProtocolConfig =
+            ProtocolConfig::get_for_version(ProtocolVersion::MAX, Chain::Unknown);
+        assert!(
+            prot.lookup_attr("storage_rebate_rate".to_string())
+                == Some(ProtocolConfigValue::u64(prot.storage_rebate_rate()))
+        );
+        assert!(
+            prot.lookup_attr("scoring_decision_mad_divisor".to_string())
+                == Some(ProtocolConfigValue::f64(
+                    prot.scoring_decision_mad_divisor()
+                ))
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        let base = ProtocolConfig::get_for_version(ProtocolVersion::MAX, Chain::Unknown);
+        assert!(base.diff(&base).is_empty());
+
+        let mut old = base.clone();
+        old.disable_storage_rebate_rate_for_testing();
+        let mut new = base.clone();
+        new.set_max_arguments_for_testing(base.max_arguments() + 1);
+        new.disable_max_move_identifier_len_for_testing();
+
+        let mut diffs = old.diff(&new);
+        diffs.sort_by(|a, b| a.name.cmp(&b.name));
+        assert_eq!(
+            diffs,
+            vec![
+                ProtocolConfigDiff {
+                    name: "max_arguments".to_owned(),
+                    old_value: Some(base.max_arguments().to_string()),
+                    new_value: Some((base.max_arguments() + 1).to_string()),
+                },
+                ProtocolConfigDiff {
+                    name: "max_move_identifier_len".to_owned(),
+                    old_value: Some(base.max_move_identifier_len().to_string()),
+                    new_value: None,
+                },
+                ProtocolConfigDiff {
+                    name: "storage_rebate_rate".to_owned(),
+                    old_value: None,
+                    new_value: Some(base.storage_rebate_rate().to_string()),
+                },
+            ]
+        );
+
+        // Diffs between consecutive versions agree with the attribute maps.
+        for i in MIN_PROTOCOL_VERSION..MAX_PROTOCOL_VERSION {
+            let old = ProtocolConfig::get_for_version(ProtocolVersion::new(i), Chain::Unknown);
+            let new = ProtocolConfig::get_for_version(ProtocolVersion::new(i + 1), Chain::Unknown);
+            let (old_attrs, new_attrs) = (old.attr_map(), new.attr_map());
+            for diff in old.diff(&new) {
+                let render = |v: &Option<ProtocolConfigValue>| v.as_ref().map(|v| v.to_string());
+                assert_eq!(diff.old_value, render(&old_attrs[&diff.name]));
+                assert_eq!(diff.new_value, render(&new_attrs[&diff.name]));
+                assert_ne!(diff.old_value, diff.new_value);
+            }
+        }
+    }
+
+    #[test]
+    fn test_verifier_config() {
+        let prot: ProtocolConfig =
+            ProtocolConfig::get_for_version(ProtocolVersion::MIN, Chain::Unknown);
+
+        let config = prot.verifier_config(/* for_signing */ true);
+        assert_eq!(config.max_loop_depth, Some(prot.max_loop_depth() as usize));
+        assert_eq!(
+            config.max_generic_instantiation_length,
+            Some(prot.max_generic_instantiation_length() as usize)
+        );
+        assert_eq!(
+            config.max_function_parameters,
+            Some(prot.max_function_parameters() as usize)
+        );
+        assert_eq!(
+            config.max_basic_blocks,
+            Some(prot.max_basic_blocks() as usize)
+        );
+        assert_eq!(
+            config.max_value_stack_size,
+            prot.max_value_stack_size() as usize
+        );
+        assert_eq!(config.max_type_nodes, Some(prot.max_type_nodes() as usize));
+        assert_eq!(config.max_push_size, Some(prot.max_push_size() as usize));
+        assert_eq!(
+            config.max_dependency_depth,
+            Some(prot.max_dependency_depth() as usize)
+        );
+        assert_eq!(
+            config.max_fields_in_struct,
+            Some(prot.max_fields_in_struct() as usize)
+        );
+        assert_eq!(
+            config.max_function_definitions,
+            Some(prot.max_function_definitions() as usize)
+        );
+        assert_eq!(
+            config.max_struct_definitions,
+            Some(prot.max_struct_definitions() as usize)
+        );
+        assert_eq!(
+            config.max_constant_vector_len,
+            Some(prot.max_move_vector_len())
+        );
+        assert_eq!(
+            config.max_back_edges_per_function,
+            Some(prot.max_back_edges_per_function() as usize)
+        );
+        assert_eq!(
+            config.max_back_edges_per_module,
+            Some(prot.max_back_edges_per_module() as usize)
+        );
+        assert_eq!(
+            config.max_idenfitier_len,
+            prot.max_move_identifier_len_as_option()
+        );
+        assert_eq!(config.bytecode_version, prot.move_binary_format_version());
+
+        // Back edge limits only apply when signing.
+        let config = prot.verifier_config(/* for_signing */ false);
+        assert_eq!(config.max_back_edges_per_function, None);
+        assert_eq!(config.max_back_edges_per_module, None);
+    }
+
     #[test]
     fn test_setters() {
         let mut prot: ProtocolConfig =
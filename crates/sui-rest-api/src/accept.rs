@@ -4,6 +4,8 @@
 use axum::http::{self, header, HeaderMap};
 use mime::Mime;
 
+use crate::APPLICATION_CBOR;
+
 pub const APPLICATION_BCS: &str = "application/bcs";
 
 /// `Accept` header, defined in [RFC7231](http://tools.ietf.org/html/rfc7231#section-5.3.2)
@@ -48,6 +50,7 @@ where
 pub enum AcceptFormat {
     Json,
     Bcs,
+    Cbor,
 }
 
 #[axum::async_trait]
@@ -67,6 +70,9 @@ where
             if mime.as_ref() == APPLICATION_BCS {
                 return Ok(Self::Bcs);
             }
+            if mime.as_ref() == APPLICATION_CBOR {
+                return Ok(Self::Cbor);
+            }
         }
 
         Ok(Self::Json)
@@ -113,6 +119,13 @@ mod tests {
         let accept = AcceptFormat::from_request(req, &()).await.unwrap();
         assert_eq!(accept, AcceptFormat::Bcs);
 
+        let req = Request::builder()
+            .header(header::ACCEPT, "application/cbor, application/bcs;q=0.5")
+            .body(())
+            .unwrap();
+        let accept = AcceptFormat::from_request(req, &()).await.unwrap();
+        assert_eq!(accept, AcceptFormat::Cbor);
+
         let req = Request::builder()
             .header(header::ACCEPT, "*/*")
             .body(())
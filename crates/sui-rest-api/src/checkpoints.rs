@@ -1,7 +1,9 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
 use sui_types::{full_checkpoint_content::CheckpointData, messages_checkpoint::CheckpointDigest};
 use sui_types::{
     messages_checkpoint::{CertifiedCheckpointSummary, CheckpointSequenceNumber},
@@ -9,7 +11,9 @@ use sui_types::{
 };
 use tap::Pipe;
 
-use crate::{accept::AcceptFormat, response::Bcs, response::ResponseContent, Result};
+use crate::{
+    accept::AcceptFormat, response::ResponseContent, types::X_SUI_CURSOR, RestError, Result,
+};
 
 pub const GET_LATEST_CHECKPOINT_PATH: &str = "/checkpoints";
 pub const GET_CHECKPOINT_PATH: &str = "/checkpoints/:checkpoint";
@@ -19,10 +23,10 @@ pub async fn get_full_checkpoint<S: ReadStore>(
     Path(checkpoint_id): Path<CheckpointId>,
     accept: AcceptFormat,
     State(state): State<S>,
-) -> Result<Bcs<CheckpointData>> {
+) -> Result<ResponseContent<CheckpointData>> {
     match accept {
-        AcceptFormat::Bcs => {}
-        _ => return Err(anyhow::anyhow!("invalid accept type").into()),
+        AcceptFormat::Bcs | AcceptFormat::Cbor => {}
+        AcceptFormat::Json => return Err(anyhow::anyhow!("invalid accept type").into()),
     }
 
     let verified_summary = match checkpoint_id {
@@ -37,7 +41,11 @@ pub async fn get_full_checkpoint<S: ReadStore>(
 
     let checkpoint_data = state.get_checkpoint_data(verified_summary, checkpoint_contents)?;
 
-    Ok(Bcs(checkpoint_data))
+    match accept {
+        AcceptFormat::Cbor => ResponseContent::Cbor(checkpoint_data),
+        _ => ResponseContent::Bcs(checkpoint_data),
+    }
+    .pipe(Ok)
 }
 
 pub async fn get_latest_checkpoint<S: ReadStore>(
@@ -49,10 +57,100 @@ pub async fn get_latest_checkpoint<S: ReadStore>(
     match accept {
         AcceptFormat::Json => ResponseContent::Json(summary),
         AcceptFormat::Bcs => ResponseContent::Bcs(summary),
+        AcceptFormat::Cbor => ResponseContent::Cbor(summary),
     }
     .pipe(Ok)
 }
 
+/// Number of checkpoints in a page when the request does not set a `limit`.
+const DEFAULT_CHECKPOINT_PAGE_SIZE: u64 = 50;
+/// Requests for larger pages are clamped to this many checkpoints.
+const MAX_CHECKPOINT_PAGE_SIZE: u64 = 100;
+
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct ListCheckpointsQuery {
+    /// Sequence number of the first checkpoint of the page. Defaults to the lowest available
+    /// checkpoint.
+    pub start: Option<CheckpointSequenceNumber>,
+    pub limit: Option<u64>,
+}
+
+/// A page of consecutive checkpoint summaries.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct CheckpointPage {
+    pub checkpoints: Vec<CertifiedCheckpointSummary>,
+    /// `start` of the next page, or `None` if this page ends at the latest checkpoint.
+    pub next_cursor: Option<CheckpointSequenceNumber>,
+}
+
+/// Serves the latest checkpoint, or a page of checkpoint summaries when `start` or `limit` is
+/// set. The cursor of the next page is also returned in the `x-sui-cursor` header.
+pub async fn list_checkpoints<S: ReadStore>(
+    Query(query): Query<ListCheckpointsQuery>,
+    accept: AcceptFormat,
+    State(state): State<S>,
+) -> Result<Response> {
+    if query.start.is_none() && query.limit.is_none() {
+        return get_latest_checkpoint(accept, State(state))
+            .await
+            .map(IntoResponse::into_response);
+    }
+
+    let page = checkpoint_page(&state, &query)?;
+
+    let mut headers = HeaderMap::new();
+    if let Some(cursor) = page.next_cursor {
+        headers.insert(X_SUI_CURSOR, cursor.to_string().try_into().unwrap());
+    }
+
+    let body = match accept {
+        AcceptFormat::Json => ResponseContent::Json(page),
+        AcceptFormat::Bcs => ResponseContent::Bcs(page),
+        AcceptFormat::Cbor => ResponseContent::Cbor(page),
+    };
+    Ok((headers, body).into_response())
+}
+
+fn checkpoint_page<S: ReadStore>(
+    state: &S,
+    query: &ListCheckpointsQuery,
+) -> Result<CheckpointPage> {
+    let latest = state.get_latest_checkpoint_sequence_number()?;
+    let start = match query.start {
+        Some(start) => start,
+        None => state.get_lowest_available_checkpoint()?,
+    };
+    if start > latest {
+        return Err(RestError::new(
+            StatusCode::BAD_REQUEST,
+            format!("Start checkpoint {start} is beyond the latest checkpoint {latest}"),
+        ));
+    }
+
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_CHECKPOINT_PAGE_SIZE)
+        .clamp(1, MAX_CHECKPOINT_PAGE_SIZE);
+    let end = latest.min(start.saturating_add(limit - 1));
+
+    let checkpoints = (start..=end)
+        .map(|sequence_number| {
+            state
+                .get_checkpoint_by_sequence_number(sequence_number)?
+                .ok_or(CheckpointNotFoundError(CheckpointId::SequenceNumber(
+                    sequence_number,
+                )))
+                .map_err(RestError::from)
+                .map(Into::into)
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(CheckpointPage {
+        checkpoints,
+        next_cursor: (end < latest).then(|| end + 1),
+    })
+}
+
 pub async fn get_checkpoint<S: ReadStore>(
     Path(checkpoint_id): Path<CheckpointId>,
     accept: AcceptFormat,
@@ -68,6 +166,7 @@ pub async fn get_checkpoint<S: ReadStore>(
     match accept {
         AcceptFormat::Json => ResponseContent::Json(summary),
         AcceptFormat::Bcs => ResponseContent::Bcs(summary),
+        AcceptFormat::Cbor => ResponseContent::Cbor(summary),
     }
     .pipe(Ok)
 }
@@ -132,3 +231,59 @@ impl From<CheckpointNotFoundError> for crate::RestError {
         Self::new(axum::http::StatusCode::NOT_FOUND, value.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use simulacrum::Simulacrum;
+
+    use super::*;
+
+    fn page(state: &Simulacrum, start: Option<u64>, limit: Option<u64>) -> Result<CheckpointPage> {
+        checkpoint_page(state, &ListCheckpointsQuery { start, limit })
+    }
+
+    fn sequence_numbers(page: &CheckpointPage) -> Vec<u64> {
+        page.checkpoints
+            .iter()
+            .map(|checkpoint| checkpoint.sequence_number)
+            .collect()
+    }
+
+    #[test]
+    fn checkpoint_pages() {
+        let mut sim = Simulacrum::new();
+        for _ in 0..5 {
+            sim.create_checkpoint();
+        }
+
+        let Ok(first) = page(&sim, None, Some(2)) else {
+            panic!("expected a page");
+        };
+        assert_eq!(sequence_numbers(&first), vec![0, 1]);
+        assert_eq!(first.next_cursor, Some(2));
+
+        let Ok(second) = page(&sim, first.next_cursor, Some(2)) else {
+            panic!("expected a page");
+        };
+        assert_eq!(sequence_numbers(&second), vec![2, 3]);
+        assert_eq!(second.next_cursor, Some(4));
+
+        // The last page ends at the latest checkpoint, without a cursor.
+        let Ok(last) = page(&sim, second.next_cursor, Some(2)) else {
+            panic!("expected a page");
+        };
+        assert_eq!(sequence_numbers(&last), vec![4, 5]);
+        assert_eq!(last.next_cursor, None);
+
+        // Large limits are clamped.
+        let Ok(all) = page(&sim, Some(0), Some(u64::MAX)) else {
+            panic!("expected a page");
+        };
+        assert_eq!(sequence_numbers(&all), vec![0, 1, 2, 3, 4, 5]);
+
+        let Err(err) = page(&sim, Some(6), None) else {
+            panic!("expected an error");
+        };
+        assert_eq!(err.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+}
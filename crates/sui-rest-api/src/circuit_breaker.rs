@@ -0,0 +1,473 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use sui_types::base_types::{EpochId, ObjectID, ObjectInfo, SuiAddress, VersionNumber};
+use sui_types::committee::Committee;
+use sui_types::digests::{
+    CheckpointContentsDigest, CheckpointDigest, TransactionDigest, TransactionEventsDigest,
+};
+use sui_types::effects::{TransactionEffects, TransactionEvents};
+use sui_types::messages_checkpoint::{
+    CheckpointContents, CheckpointSequenceNumber, FullCheckpointContents, VerifiedCheckpoint,
+};
+use sui_types::object::Object;
+use sui_types::storage::error::{Error as StorageError, Kind, Result};
+use sui_types::storage::{ObjectKey, ObjectStore, ReadStore};
+use sui_types::transaction::VerifiedTransaction;
+
+/// Returned, wrapped in a storage error, by a `CircuitBreakingReadStore` while its breaker is
+/// open, instead of calling through to the inner store.
+#[derive(Debug)]
+pub struct StoreUnavailable;
+
+impl std::fmt::Display for StoreUnavailable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "store is unavailable after repeated read failures")
+    }
+}
+
+impl std::error::Error for StoreUnavailable {}
+
+#[derive(Clone, Copy, Debug)]
+pub struct CircuitBreakerConfig {
+    /// Number of most recent reads the failure rate is computed over. The breaker does not trip
+    /// until this many reads have been observed.
+    pub window_size: usize,
+    /// Fraction of failed reads in the window, in (0, 1], at which the breaker trips.
+    pub failure_threshold: f64,
+    /// How long reads fast-fail once the breaker has tripped, before a probe read is let through.
+    pub cooldown: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            window_size: 20,
+            failure_threshold: 0.5,
+            cooldown: Duration::from_secs(10),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum State {
+    /// Reads go through to the inner store, and their outcomes (`true` for a failure) are
+    /// recorded.
+    Closed { outcomes: VecDeque<bool> },
+    /// Reads fast-fail until `until`.
+    Open { until: Instant },
+    /// A single probe read is in flight. Its outcome decides whether the breaker closes again.
+    Probing,
+}
+
+/// A `ReadStore` which stops calling through to a failing inner store. Once the rate of failed
+/// reads exceeds `CircuitBreakerConfig::failure_threshold`, reads fail immediately with
+/// `StoreUnavailable` for `CircuitBreakerConfig::cooldown`, after which one read is let through
+/// to probe whether the inner store has recovered.
+pub struct CircuitBreakingReadStore<S> {
+    inner: S,
+    config: CircuitBreakerConfig,
+    state: Arc<Mutex<State>>,
+}
+
+impl<S: Clone> Clone for CircuitBreakingReadStore<S> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            config: self.config,
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<S> CircuitBreakingReadStore<S> {
+    pub fn new(inner: S, config: CircuitBreakerConfig) -> Self {
+        Self {
+            inner,
+            config,
+            state: Arc::new(Mutex::new(State::Closed {
+                outcomes: VecDeque::with_capacity(config.window_size),
+            })),
+        }
+    }
+
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Whether reads are currently being fast-failed.
+    pub fn is_open(&self) -> bool {
+        !matches!(*self.state.lock().unwrap(), State::Closed { .. })
+    }
+
+    fn call<'a, T>(&'a self, f: impl FnOnce(&'a S) -> Result<T>) -> Result<T> {
+        self.acquire()?;
+        let result = f(&self.inner);
+        // Data which is missing from the store does not indicate that the store is unhealthy.
+        self.record(matches!(&result, Err(e) if e.kind() != Kind::Missing));
+        result
+    }
+
+    /// Checks whether a read may call through to the inner store, moving an open breaker whose
+    /// cooldown has elapsed to probing.
+    fn acquire(&self) -> Result<()> {
+        let mut state = self.state.lock().unwrap();
+        match *state {
+            State::Closed { .. } => Ok(()),
+            State::Open { until } if Instant::now() >= until => {
+                *state = State::Probing;
+                Ok(())
+            }
+            State::Open { .. } | State::Probing => Err(StorageError::custom(StoreUnavailable)),
+        }
+    }
+
+    fn record(&self, failed: bool) {
+        let mut state = self.state.lock().unwrap();
+        match &mut *state {
+            State::Closed { outcomes } => {
+                if outcomes.len() == self.config.window_size {
+                    outcomes.pop_front();
+                }
+                outcomes.push_back(failed);
+
+                let failures = outcomes.iter().filter(|failed| **failed).count();
+                if outcomes.len() == self.config.window_size
+                    && failures as f64 >= self.config.failure_threshold * outcomes.len() as f64
+                {
+                    *state = self.open();
+                }
+            }
+            State::Probing if failed => *state = self.open(),
+            State::Probing => {
+                *state = State::Closed {
+                    outcomes: VecDeque::with_capacity(self.config.window_size),
+                }
+            }
+            // Reads which started before the breaker tripped may still complete while it is open.
+            State::Open { .. } => {}
+        }
+    }
+
+    fn open(&self) -> State {
+        State::Open {
+            until: Instant::now() + self.config.cooldown,
+        }
+    }
+}
+
+impl<S: ObjectStore> ObjectStore for CircuitBreakingReadStore<S> {
+    fn get_object(&self, object_id: &ObjectID) -> Result<Option<Object>> {
+        self.call(|inner| inner.get_object(object_id))
+    }
+
+    fn get_object_by_key(
+        &self,
+        object_id: &ObjectID,
+        version: VersionNumber,
+    ) -> Result<Option<Object>> {
+        self.call(|inner| inner.get_object_by_key(object_id, version))
+    }
+
+    fn multi_get_objects(&self, object_ids: &[ObjectID]) -> Result<Vec<Option<Object>>> {
+        self.call(|inner| inner.multi_get_objects(object_ids))
+    }
+
+    fn multi_get_objects_by_key(&self, object_keys: &[ObjectKey]) -> Result<Vec<Option<Object>>> {
+        self.call(|inner| inner.multi_get_objects_by_key(object_keys))
+    }
+}
+
+impl<S: ReadStore> ReadStore for CircuitBreakingReadStore<S> {
+    fn get_committee(&self, epoch: EpochId) -> Result<Option<Arc<Committee>>> {
+        self.call(|inner| inner.get_committee(epoch))
+    }
+
+    fn get_latest_checkpoint(&self) -> Result<VerifiedCheckpoint> {
+        self.call(|inner| inner.get_latest_checkpoint())
+    }
+
+    fn get_latest_checkpoint_sequence_number(&self) -> Result<CheckpointSequenceNumber> {
+        self.call(|inner| inner.get_latest_checkpoint_sequence_number())
+    }
+
+    fn get_latest_epoch_id(&self) -> Result<EpochId> {
+        self.call(|inner| inner.get_latest_epoch_id())
+    }
+
+    fn get_highest_verified_checkpoint(&self) -> Result<VerifiedCheckpoint> {
+        self.call(|inner| inner.get_highest_verified_checkpoint())
+    }
+
+    fn get_highest_synced_checkpoint(&self) -> Result<VerifiedCheckpoint> {
+        self.call(|inner| inner.get_highest_synced_checkpoint())
+    }
+
+    fn get_lowest_available_checkpoint(&self) -> Result<CheckpointSequenceNumber> {
+        self.call(|inner| inner.get_lowest_available_checkpoint())
+    }
+
+    fn get_checkpoint_by_digest(
+        &self,
+        digest: &CheckpointDigest,
+    ) -> Result<Option<VerifiedCheckpoint>> {
+        self.call(|inner| inner.get_checkpoint_by_digest(digest))
+    }
+
+    fn get_checkpoint_by_sequence_number(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> Result<Option<VerifiedCheckpoint>> {
+        self.call(|inner| inner.get_checkpoint_by_sequence_number(sequence_number))
+    }
+
+    fn get_checkpoint_contents_by_digest(
+        &self,
+        digest: &CheckpointContentsDigest,
+    ) -> Result<Option<CheckpointContents>> {
+        self.call(|inner| inner.get_checkpoint_contents_by_digest(digest))
+    }
+
+    fn get_checkpoint_contents_by_sequence_number(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> Result<Option<CheckpointContents>> {
+        self.call(|inner| inner.get_checkpoint_contents_by_sequence_number(sequence_number))
+    }
+
+    fn get_transaction(
+        &self,
+        tx_digest: &TransactionDigest,
+    ) -> Result<Option<Arc<VerifiedTransaction>>> {
+        self.call(|inner| inner.get_transaction(tx_digest))
+    }
+
+    fn multi_get_transactions(
+        &self,
+        tx_digests: &[TransactionDigest],
+    ) -> Result<Vec<Option<Arc<VerifiedTransaction>>>> {
+        self.call(|inner| inner.multi_get_transactions(tx_digests))
+    }
+
+    fn get_transaction_effects(
+        &self,
+        tx_digest: &TransactionDigest,
+    ) -> Result<Option<TransactionEffects>> {
+        self.call(|inner| inner.get_transaction_effects(tx_digest))
+    }
+
+    fn multi_get_transaction_effects(
+        &self,
+        tx_digests: &[TransactionDigest],
+    ) -> Result<Vec<Option<TransactionEffects>>> {
+        self.call(|inner| inner.multi_get_transaction_effects(tx_digests))
+    }
+
+    fn get_events(
+        &self,
+        event_digest: &TransactionEventsDigest,
+    ) -> Result<Option<TransactionEvents>> {
+        self.call(|inner| inner.get_events(event_digest))
+    }
+
+    fn multi_get_events(
+        &self,
+        event_digests: &[TransactionEventsDigest],
+    ) -> Result<Vec<Option<TransactionEvents>>> {
+        self.call(|inner| inner.multi_get_events(event_digests))
+    }
+
+    fn get_full_checkpoint_contents_by_sequence_number(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> Result<Option<FullCheckpointContents>> {
+        self.call(|inner| inner.get_full_checkpoint_contents_by_sequence_number(sequence_number))
+    }
+
+    fn get_full_checkpoint_contents(
+        &self,
+        digest: &CheckpointContentsDigest,
+    ) -> Result<Option<FullCheckpointContents>> {
+        self.call(|inner| inner.get_full_checkpoint_contents(digest))
+    }
+
+    fn get_owned_objects(
+        &self,
+        owner: SuiAddress,
+        cursor: Option<ObjectID>,
+    ) -> Result<Box<dyn Iterator<Item = ObjectInfo> + '_>> {
+        self.call(|inner| inner.get_owned_objects(owner, cursor))
+    }
+
+    // `get_checkpoint_data` is left to its default implementation, so that each of the reads it is
+    // made up of goes through the breaker.
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    use super::*;
+
+    /// Store whose `get_latest_checkpoint` fails while `failing` is set, and otherwise reports
+    /// that there is no checkpoint, counting every call.
+    #[derive(Default)]
+    struct FlakyStore {
+        failing: AtomicBool,
+        calls: AtomicUsize,
+    }
+
+    impl ObjectStore for FlakyStore {
+        fn get_object(&self, _object_id: &ObjectID) -> Result<Option<Object>> {
+            unimplemented!()
+        }
+
+        fn get_object_by_key(
+            &self,
+            _object_id: &ObjectID,
+            _version: VersionNumber,
+        ) -> Result<Option<Object>> {
+            unimplemented!()
+        }
+    }
+
+    impl ReadStore for FlakyStore {
+        fn get_committee(&self, _epoch: EpochId) -> Result<Option<Arc<Committee>>> {
+            unimplemented!()
+        }
+
+        fn get_latest_checkpoint(&self) -> Result<VerifiedCheckpoint> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.failing.load(Ordering::SeqCst) {
+                Err(StorageError::custom("read failed"))
+            } else {
+                Err(StorageError::missing("no checkpoints"))
+            }
+        }
+
+        fn get_highest_verified_checkpoint(&self) -> Result<VerifiedCheckpoint> {
+            unimplemented!()
+        }
+
+        fn get_highest_synced_checkpoint(&self) -> Result<VerifiedCheckpoint> {
+            unimplemented!()
+        }
+
+        fn get_lowest_available_checkpoint(&self) -> Result<CheckpointSequenceNumber> {
+            unimplemented!()
+        }
+
+        fn get_checkpoint_by_digest(
+            &self,
+            _digest: &CheckpointDigest,
+        ) -> Result<Option<VerifiedCheckpoint>> {
+            unimplemented!()
+        }
+
+        fn get_checkpoint_by_sequence_number(
+            &self,
+            _sequence_number: CheckpointSequenceNumber,
+        ) -> Result<Option<VerifiedCheckpoint>> {
+            unimplemented!()
+        }
+
+        fn get_checkpoint_contents_by_digest(
+            &self,
+            _digest: &CheckpointContentsDigest,
+        ) -> Result<Option<CheckpointContents>> {
+            unimplemented!()
+        }
+
+        fn get_checkpoint_contents_by_sequence_number(
+            &self,
+            _sequence_number: CheckpointSequenceNumber,
+        ) -> Result<Option<CheckpointContents>> {
+            unimplemented!()
+        }
+
+        fn get_transaction(
+            &self,
+            _tx_digest: &TransactionDigest,
+        ) -> Result<Option<Arc<VerifiedTransaction>>> {
+            unimplemented!()
+        }
+
+        fn get_transaction_effects(
+            &self,
+            _tx_digest: &TransactionDigest,
+        ) -> Result<Option<TransactionEffects>> {
+            unimplemented!()
+        }
+
+        fn get_events(
+            &self,
+            _event_digest: &TransactionEventsDigest,
+        ) -> Result<Option<TransactionEvents>> {
+            unimplemented!()
+        }
+
+        fn get_full_checkpoint_contents_by_sequence_number(
+            &self,
+            _sequence_number: CheckpointSequenceNumber,
+        ) -> Result<Option<FullCheckpointContents>> {
+            unimplemented!()
+        }
+
+        fn get_full_checkpoint_contents(
+            &self,
+            _digest: &CheckpointContentsDigest,
+        ) -> Result<Option<FullCheckpointContents>> {
+            unimplemented!()
+        }
+    }
+
+    fn is_unavailable(error: &StorageError) -> bool {
+        std::error::Error::source(error).is_some_and(|source| source.is::<StoreUnavailable>())
+    }
+
+    #[test]
+    fn repeated_failures_trip_the_breaker() {
+        let inner = Arc::new(FlakyStore::default());
+        inner.failing.store(true, Ordering::SeqCst);
+        let store = CircuitBreakingReadStore::new(
+            inner.clone(),
+            CircuitBreakerConfig {
+                window_size: 4,
+                failure_threshold: 0.5,
+                cooldown: Duration::from_millis(100),
+            },
+        );
+
+        for _ in 0..4 {
+            let error = store.get_latest_checkpoint().unwrap_err();
+            assert!(!is_unavailable(&error));
+        }
+        assert!(store.is_open());
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 4);
+
+        // Reads now fast-fail without reaching the inner store.
+        for _ in 0..10 {
+            let error = store.get_latest_checkpoint().unwrap_err();
+            assert!(is_unavailable(&error));
+        }
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 4);
+
+        // After the cooldown, a failed probe opens the breaker again.
+        std::thread::sleep(Duration::from_millis(100));
+        assert!(!is_unavailable(&store.get_latest_checkpoint().unwrap_err()));
+        assert!(is_unavailable(&store.get_latest_checkpoint().unwrap_err()));
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 5);
+
+        // Once the inner store recovers, a successful probe closes the breaker.
+        inner.failing.store(false, Ordering::SeqCst);
+        std::thread::sleep(Duration::from_millis(100));
+        assert!(!is_unavailable(&store.get_latest_checkpoint().unwrap_err()));
+        assert!(!store.is_open());
+        assert!(!is_unavailable(&store.get_latest_checkpoint().unwrap_err()));
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 7);
+    }
+}
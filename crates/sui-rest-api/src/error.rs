@@ -31,8 +31,14 @@ impl axum::response::IntoResponse for RestError {
 
 impl From<sui_types::storage::error::Error> for RestError {
     fn from(value: sui_types::storage::error::Error) -> Self {
+        let unavailable = std::error::Error::source(&value)
+            .is_some_and(|source| source.is::<crate::StoreUnavailable>());
         Self {
-            status: StatusCode::INTERNAL_SERVER_ERROR,
+            status: if unavailable {
+                StatusCode::SERVICE_UNAVAILABLE
+            } else {
+                StatusCode::INTERNAL_SERVER_ERROR
+            },
             message: Some(value.to_string()),
         }
     }
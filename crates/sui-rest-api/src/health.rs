@@ -1,30 +1,60 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use std::borrow::Cow;
 use std::time::{Duration, SystemTime};
 
 use axum::{
     extract::{Query, State},
     http::StatusCode,
-    response::IntoResponse,
+    Json,
 };
-use sui_types::storage::ReadStore;
-use tap::Pipe;
+use sui_types::digests::ChainIdentifier;
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
 
-use crate::Result;
+use crate::{RestService, Result};
 
 pub const HEALTH_PATH: &str = "/health";
 
-#[derive(serde::Deserialize)]
+#[derive(Debug, Default, serde::Deserialize)]
 pub struct Threshold {
     threshold_seconds: Option<u32>,
+    /// Largest number of checkpoints the store may lag behind the latest verified checkpoint
+    /// before the node is reported as unhealthy.
+    threshold: Option<u64>,
 }
 
-pub async fn health<S: ReadStore>(
-    Query(Threshold { threshold_seconds }): Query<Threshold>,
-    State(state): State<S>,
-) -> Result<impl IntoResponse> {
-    let summary = state.get_latest_checkpoint()?;
+/// Health of the node, served as JSON by the health endpoint.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct Health {
+    pub chain_id: ChainIdentifier,
+    pub software_version: Cow<'static, str>,
+    /// Sequence number of the highest executed checkpoint.
+    pub checkpoint_height: CheckpointSequenceNumber,
+    /// Sequence number of the highest checkpoint known to the node, generally verified by
+    /// state-sync.
+    pub latest_checkpoint_height: CheckpointSequenceNumber,
+    /// Whether every known checkpoint has been executed.
+    pub synced: bool,
+}
+
+impl Health {
+    fn lag(&self) -> u64 {
+        self.latest_checkpoint_height
+            .saturating_sub(self.checkpoint_height)
+    }
+}
+
+/// Reports the health of the node. Responds with `503 Service Unavailable` when the store lags
+/// more than `threshold` checkpoints behind the latest verified checkpoint.
+pub async fn health(
+    Query(Threshold {
+        threshold_seconds,
+        threshold,
+    }): Query<Threshold>,
+    State(state): State<RestService>,
+) -> Result<(StatusCode, Json<Health>)> {
+    let summary = state.store.get_latest_checkpoint()?;
 
     // If we have a provided threshold, check that it's close to the current time
     if let Some(threshold_seconds) = threshold_seconds {
@@ -40,5 +70,85 @@ pub async fn health<S: ReadStore>(
         }
     }
 
-    StatusCode::OK.pipe(Ok)
+    let latest_checkpoint_height = state
+        .store
+        .get_highest_verified_checkpoint()?
+        .sequence_number
+        .max(summary.sequence_number);
+
+    let health = Health {
+        chain_id: state.chain_id(),
+        software_version: state.software_version().into(),
+        checkpoint_height: summary.sequence_number,
+        latest_checkpoint_height,
+        synced: summary.sequence_number == latest_checkpoint_height,
+    };
+
+    let status = match threshold {
+        Some(threshold) if health.lag() > threshold => StatusCode::SERVICE_UNAVAILABLE,
+        _ => StatusCode::OK,
+    };
+
+    Ok((status, Json(health)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use simulacrum::Simulacrum;
+    use sui_types::storage::ReadStore;
+
+    use super::*;
+
+    fn service() -> RestService {
+        let mut sim = Simulacrum::new();
+        sim.create_checkpoint();
+        sim.create_checkpoint();
+
+        let chain_id = (*sim
+            .get_checkpoint_by_sequence_number(0)
+            .unwrap()
+            .unwrap()
+            .digest())
+        .into();
+        RestService::new(Arc::new(sim), chain_id, "1.2.3")
+    }
+
+    #[tokio::test]
+    async fn health_reports_executed_checkpoint() {
+        let state = service();
+        let chain_id = state.chain_id();
+
+        let Ok((status, Json(health))) = health(
+            Query(Threshold {
+                threshold: Some(0),
+                ..Default::default()
+            }),
+            State(state),
+        )
+        .await
+        else {
+            panic!("expected a health report");
+        };
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(health.chain_id, chain_id);
+        assert_eq!(health.software_version, "1.2.3");
+        assert_eq!(health.checkpoint_height, 2);
+        assert_eq!(health.latest_checkpoint_height, 2);
+        assert!(health.synced);
+    }
+
+    #[test]
+    fn lag_is_measured_against_latest_checkpoint() {
+        let health = Health {
+            chain_id: ChainIdentifier::default(),
+            software_version: "1.2.3".into(),
+            checkpoint_height: 7,
+            latest_checkpoint_height: 10,
+            synced: false,
+        };
+        assert_eq!(health.lag(), 3);
+    }
 }
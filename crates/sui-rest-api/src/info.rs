@@ -7,6 +7,7 @@ use crate::{accept::AcceptFormat, response::ResponseContent};
 use crate::{RestService, Result};
 use axum::extract::State;
 use sui_types::digests::ChainIdentifier;
+use sui_types::sui_system_state::{get_sui_system_state, SuiSystemStateTrait};
 use tap::Pipe;
 
 pub async fn node_info(
@@ -15,12 +16,14 @@ pub async fn node_info(
 ) -> Result<ResponseContent<NodeInfo>> {
     let latest_checkpoint = state.store.get_latest_checkpoint()?;
     let oldest_checkpoint = state.store.get_lowest_available_checkpoint()?;
+    let system_state = get_sui_system_state(&state.store).map_err(anyhow::Error::from)?;
 
     let response = NodeInfo {
         checkpoint_height: latest_checkpoint.sequence_number,
         oldest_checkpoint_height: oldest_checkpoint,
         timestamp_ms: latest_checkpoint.timestamp_ms,
         epoch: latest_checkpoint.epoch(),
+        protocol_version: system_state.protocol_version(),
         chain_id: state.chain_id(),
         software_version: state.software_version().into(),
     };
@@ -28,17 +31,63 @@ pub async fn node_info(
     match accept {
         AcceptFormat::Json => ResponseContent::Json(response),
         AcceptFormat::Bcs => ResponseContent::Bcs(response),
+        AcceptFormat::Cbor => ResponseContent::Cbor(response),
     }
     .pipe(Ok)
 }
 
+/// Response of the node info endpoint, served as JSON or BCS depending on the request's `Accept`
+/// header.
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct NodeInfo {
     pub chain_id: ChainIdentifier,
     pub epoch: u64,
+    /// Sequence number of the latest executed checkpoint.
     pub checkpoint_height: u64,
+    /// Timestamp of the latest executed checkpoint.
     pub timestamp_ms: u64,
+    /// Sequence number of the lowest checkpoint that has not been pruned.
     pub oldest_checkpoint_height: u64,
     pub software_version: Cow<'static, str>,
-    //TODO include current protocol version
+    /// Protocol version of the current epoch, as recorded in the system state.
+    pub protocol_version: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use simulacrum::Simulacrum;
+    use sui_types::storage::ReadStore;
+    use sui_types::sui_system_state::epoch_start_sui_system_state::EpochStartSystemStateTrait;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn node_info_reports_chain_and_checkpoints() {
+        let mut sim = Simulacrum::new();
+        sim.create_checkpoint();
+        sim.create_checkpoint();
+
+        let chain_id = (*sim
+            .get_checkpoint_by_sequence_number(0)
+            .unwrap()
+            .unwrap()
+            .digest())
+        .into();
+        let protocol_version = sim.epoch_start_state().protocol_version().as_u64();
+        let state = RestService::new(Arc::new(sim), chain_id, "1.2.3");
+
+        let Ok(ResponseContent::Json(info)) = node_info(AcceptFormat::Json, State(state)).await
+        else {
+            panic!("expected a JSON response");
+        };
+
+        assert_eq!(info.chain_id, chain_id);
+        assert_eq!(info.software_version, "1.2.3");
+        assert_eq!(info.protocol_version, protocol_version);
+        assert_eq!(info.epoch, 0);
+        assert_eq!(info.checkpoint_height, 2);
+        assert_eq!(info.oldest_checkpoint_height, 0);
+    }
 }
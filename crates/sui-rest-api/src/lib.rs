@@ -1,26 +1,36 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use axum::{routing::get, Router};
+use axum::{
+    routing::{get, MethodRouter},
+    Router,
+};
 
 pub mod accept;
 mod checkpoints;
+mod circuit_breaker;
 mod client;
 mod error;
 mod health;
 mod info;
 mod objects;
 mod response;
+mod routes;
 pub mod types;
+mod version;
 
+pub use circuit_breaker::{CircuitBreakerConfig, CircuitBreakingReadStore, StoreUnavailable};
 pub use client::Client;
 pub use error::{RestError, Result};
+pub use info::NodeInfo;
+pub use routes::RouteInfo;
 pub use sui_types::full_checkpoint_content::{CheckpointData, CheckpointTransaction};
 use sui_types::storage::ReadStore;
 
 pub const TEXT_PLAIN_UTF_8: &str = "text/plain; charset=utf-8";
 pub const APPLICATION_BCS: &str = "application/bcs";
 pub const APPLICATION_JSON: &str = "application/json";
+pub const APPLICATION_CBOR: &str = "application/cbor";
 
 #[derive(Clone)]
 pub struct RestService {
@@ -57,13 +67,25 @@ impl RestService {
         self.software_version
     }
 
+    /// Lists every route served by the router returned from `into_router`.
+    pub fn routes(&self) -> Vec<RouteInfo> {
+        route_listing()
+    }
+
     pub fn into_router(self) -> Router {
         rest_router(self.store.clone())
             .merge(
-                Router::new()
-                    .route("/", get(info::node_info))
+                service_routes()
+                    .into_iter()
+                    .fold(Router::new(), |router, (info, method_router)| {
+                        router.route(&info.path, method_router)
+                    })
                     .with_state(self.clone()),
             )
+            .layer(axum::middleware::from_fn_with_state(
+                self.clone(),
+                version::check_min_server_version,
+            ))
             .layer(axum::middleware::map_response_with_state(
                 self,
                 response::append_info_headers,
@@ -84,28 +106,167 @@ impl RestService {
     }
 }
 
-fn rest_router<S>(state: S) -> Router
+fn route_listing() -> Vec<RouteInfo> {
+    service_routes()
+        .into_iter()
+        .map(|(info, _)| info)
+        .chain(
+            rest_routes::<std::sync::Arc<dyn ReadStore + Send + Sync>>()
+                .into_iter()
+                .map(|(info, _)| info),
+        )
+        .collect()
+}
+
+/// Routes served with the `RestService` itself as state.
+fn service_routes() -> Vec<(RouteInfo, MethodRouter<RestService>)> {
+    vec![
+        (
+            RouteInfo::get("/", "Information about the node and the chain it follows"),
+            get(info::node_info),
+        ),
+        (
+            RouteInfo::get(
+                health::HEALTH_PATH,
+                "Health of the node and how far its store lags behind the latest checkpoint",
+            ),
+            get(health::health),
+        ),
+        (
+            RouteInfo::get(routes::LIST_ROUTES_PATH, "List of the routes served"),
+            get(routes::list_routes),
+        ),
+    ]
+}
+
+/// Routes served from a `ReadStore`. Axum does not expose the routes registered on a `Router`,
+/// so each route is kept together with its description in order to list them.
+fn rest_routes<S>() -> Vec<(RouteInfo, MethodRouter<S>)>
 where
     S: ReadStore + Clone + Send + Sync + 'static,
 {
-    Router::new()
-        .route(health::HEALTH_PATH, get(health::health::<S>))
-        .route(
-            checkpoints::GET_FULL_CHECKPOINT_PATH,
+    vec![
+        (
+            RouteInfo::get(
+                checkpoints::GET_FULL_CHECKPOINT_PATH,
+                "Checkpoint with all of its transactions, effects, events and objects",
+            ),
             get(checkpoints::get_full_checkpoint::<S>),
-        )
-        .route(
-            checkpoints::GET_CHECKPOINT_PATH,
+        ),
+        (
+            RouteInfo::get(
+                checkpoints::GET_CHECKPOINT_PATH,
+                "Checkpoint by sequence number",
+            ),
             get(checkpoints::get_checkpoint::<S>),
-        )
-        .route(
-            checkpoints::GET_LATEST_CHECKPOINT_PATH,
-            get(checkpoints::get_latest_checkpoint::<S>),
-        )
-        .route(objects::GET_OBJECT_PATH, get(objects::get_object::<S>))
-        .route(
-            objects::GET_OBJECT_WITH_VERSION_PATH,
+        ),
+        (
+            RouteInfo::get(
+                checkpoints::GET_LATEST_CHECKPOINT_PATH,
+                "Latest executed checkpoint, or a page of checkpoints given `start` or `limit`",
+            ),
+            get(checkpoints::list_checkpoints::<S>),
+        ),
+        (
+            RouteInfo::get(objects::GET_OBJECT_PATH, "Latest version of an object"),
+            get(objects::get_object::<S>),
+        ),
+        (
+            RouteInfo::get(
+                objects::GET_OBJECT_WITH_VERSION_PATH,
+                "Object at a specific version",
+            ),
             get(objects::get_object_with_version::<S>),
-        )
+        ),
+        (
+            RouteInfo::get(
+                objects::GET_OBJECT_VERSION_PATH,
+                "Latest version and digest of an object, without its contents",
+            ),
+            get(objects::get_object_version::<S>),
+        ),
+        (
+            RouteInfo::get(
+                objects::LIST_OWNED_OBJECTS_PATH,
+                "Page of the references of the objects owned by an address",
+            ),
+            get(objects::get_objects_by_owner::<S>),
+        ),
+    ]
+}
+
+fn rest_router<S>(state: S) -> Router
+where
+    S: ReadStore + Clone + Send + Sync + 'static,
+{
+    rest_routes::<S>()
+        .into_iter()
+        .fold(Router::new(), |router, (info, method_router)| {
+            router.route(&info.path, method_router)
+        })
         .with_state(state)
 }
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{header, Request, StatusCode},
+    };
+    use simulacrum::Simulacrum;
+    use tower::ServiceExt;
+
+    use super::*;
+
+    #[test]
+    fn routes_are_listed() {
+        let routes = route_listing();
+        let paths = routes
+            .iter()
+            .map(|route| route.path.as_str())
+            .collect::<Vec<_>>();
+
+        for path in [
+            objects::GET_OBJECT_PATH,
+            objects::GET_OBJECT_WITH_VERSION_PATH,
+            objects::GET_OBJECT_VERSION_PATH,
+            objects::LIST_OWNED_OBJECTS_PATH,
+            checkpoints::GET_CHECKPOINT_PATH,
+            checkpoints::GET_LATEST_CHECKPOINT_PATH,
+            health::HEALTH_PATH,
+            routes::LIST_ROUTES_PATH,
+        ] {
+            assert!(paths.contains(&path), "{path} is not listed");
+        }
+        assert!(routes.iter().all(|route| route.method == "GET"));
+
+        // This service does not execute transactions.
+        assert!(!paths.iter().any(|path| path.contains("transactions")));
+    }
+
+    #[tokio::test]
+    async fn full_checkpoint_as_cbor() {
+        let mut sim = Simulacrum::new();
+        sim.create_checkpoint();
+        let chain_id = (*sim
+            .get_checkpoint_by_sequence_number(0)
+            .unwrap()
+            .unwrap()
+            .digest())
+        .into();
+        let router =
+            RestService::new_without_version(std::sync::Arc::new(sim), chain_id).into_router();
+
+        let request = Request::get("/checkpoints/1/full")
+            .header(header::ACCEPT, APPLICATION_CBOR)
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], APPLICATION_CBOR);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let checkpoint: CheckpointData = ciborium::from_reader(body.as_ref()).unwrap();
+        assert_eq!(checkpoint.checkpoint_summary.sequence_number, 1);
+    }
+}
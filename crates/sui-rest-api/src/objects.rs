@@ -1,11 +1,19 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{accept::AcceptFormat, response::ResponseContent, types::JsonObject, Result};
-use axum::extract::{Path, State};
+use crate::{
+    accept::AcceptFormat,
+    response::ResponseContent,
+    types::{JsonObject, X_SUI_CURSOR},
+    RestError, Result,
+};
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
 use sui_types::{
-    base_types::{ObjectID, SequenceNumber},
+    base_types::{ObjectDigest, ObjectID, ObjectRef, ObjectType, SequenceNumber, SuiAddress},
     object::Object,
+    parse_sui_struct_tag,
     storage::ReadStore,
 };
 use tap::Pipe;
@@ -24,6 +32,7 @@ pub async fn get_object<S: ReadStore>(
     match accept {
         AcceptFormat::Json => ResponseContent::Json(JsonObject::from_object(&object)),
         AcceptFormat::Bcs => ResponseContent::Bcs(object),
+        AcceptFormat::Cbor => ResponseContent::Cbor(object),
     }
     .pipe(Ok)
 }
@@ -42,10 +51,165 @@ pub async fn get_object_with_version<S: ReadStore>(
     match accept {
         AcceptFormat::Json => ResponseContent::Json(JsonObject::from_object(&object)),
         AcceptFormat::Bcs => ResponseContent::Bcs(object),
+        AcceptFormat::Cbor => ResponseContent::Cbor(object),
+    }
+    .pipe(Ok)
+}
+
+pub const GET_OBJECT_VERSION_PATH: &str = "/objects/:object_id/version";
+
+/// Latest version and digest of an object, without its contents.
+#[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ObjectVersion {
+    pub object_id: ObjectID,
+    pub version: SequenceNumber,
+    pub digest: ObjectDigest,
+}
+
+pub async fn get_object_version<S: ReadStore>(
+    Path(object_id): Path<ObjectID>,
+    accept: AcceptFormat,
+    State(state): State<S>,
+) -> Result<ResponseContent<ObjectRef, ObjectVersion>> {
+    let object_version = latest_object_version(&state, object_id)?;
+
+    let object_ref = (
+        object_version.object_id,
+        object_version.version,
+        object_version.digest,
+    );
+    match accept {
+        AcceptFormat::Json => ResponseContent::Json(object_version),
+        AcceptFormat::Bcs => ResponseContent::Bcs(object_ref),
+        AcceptFormat::Cbor => ResponseContent::Cbor(object_ref),
     }
     .pipe(Ok)
 }
 
+fn latest_object_version<S: ReadStore>(state: &S, object_id: ObjectID) -> Result<ObjectVersion> {
+    let (object_id, version, digest) = state
+        .get_object(&object_id)?
+        .ok_or_else(|| ObjectNotFoundError::new(object_id))?
+        .compute_object_reference();
+    Ok(ObjectVersion {
+        object_id,
+        version,
+        digest,
+    })
+}
+
+pub const LIST_OWNED_OBJECTS_PATH: &str = "/accounts/:owner/objects";
+
+/// Number of objects in a page when the request does not set a `limit`.
+const DEFAULT_OBJECT_PAGE_SIZE: u64 = 50;
+/// Requests for larger pages are clamped to this many objects.
+const MAX_OBJECT_PAGE_SIZE: u64 = 200;
+/// Most owned objects read to serve one page. With a `type` filter, a page can end early, once
+/// this many objects were read, so that filtering a large owned set stays cheap.
+const MAX_OBJECTS_SCANNED_PER_PAGE: usize = 1000;
+
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct ListOwnedObjectsQuery {
+    /// Id of the last object of the previous page. Defaults to the start of the owner's objects.
+    pub cursor: Option<ObjectID>,
+    pub limit: Option<u64>,
+    /// Only list objects of this Move type, e.g. `0x2::coin::Coin<0x2::sui::SUI>`. Type
+    /// parameters must match exactly.
+    #[serde(rename = "type")]
+    pub object_type: Option<String>,
+}
+
+/// A page of the objects owned by an address, in increasing object id order.
+///
+/// With a `type` filter, a page that is not the last one may hold fewer than `limit` objects, or
+/// none at all, if the objects read for it did not match.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct OwnedObjectPage {
+    pub objects: Vec<ObjectVersion>,
+    /// `cursor` of the next page, or `None` if this is the last page. It may be the id of an
+    /// object that was read but did not match the `type` filter.
+    pub next_cursor: Option<ObjectID>,
+}
+
+/// Serves a page of the references of the objects owned by `owner`, optionally only those of the
+/// Move type in the `type` query. The cursor of the next page is also returned in the
+/// `x-sui-cursor` header.
+pub async fn get_objects_by_owner<S: ReadStore>(
+    Path(owner): Path<SuiAddress>,
+    Query(query): Query<ListOwnedObjectsQuery>,
+    accept: AcceptFormat,
+    State(state): State<S>,
+) -> Result<Response> {
+    let page = owned_object_page(&state, owner, &query, MAX_OBJECTS_SCANNED_PER_PAGE)?;
+
+    let mut headers = HeaderMap::new();
+    if let Some(cursor) = page.next_cursor {
+        headers.insert(X_SUI_CURSOR, cursor.to_string().try_into().unwrap());
+    }
+
+    let body = match accept {
+        AcceptFormat::Json => ResponseContent::Json(page),
+        AcceptFormat::Bcs => ResponseContent::Bcs(page),
+        AcceptFormat::Cbor => ResponseContent::Cbor(page),
+    };
+    Ok((headers, body).into_response())
+}
+
+/// Reads at most `max_scanned` of the owner's objects. If the page is not full by then, it ends at
+/// the last object read.
+fn owned_object_page<S: ReadStore>(
+    state: &S,
+    owner: SuiAddress,
+    query: &ListOwnedObjectsQuery,
+    max_scanned: usize,
+) -> Result<OwnedObjectPage> {
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_OBJECT_PAGE_SIZE)
+        .clamp(1, MAX_OBJECT_PAGE_SIZE) as usize;
+    let object_type = query
+        .object_type
+        .as_deref()
+        .map(parse_sui_struct_tag)
+        .transpose()
+        .map_err(|e| RestError::new(StatusCode::BAD_REQUEST, format!("Invalid type: {e}")))?;
+
+    // Filter before paging, and read one matching object past the end of the page, to tell
+    // whether there is a next page.
+    let mut objects = Vec::with_capacity(limit);
+    let mut next_cursor = None;
+    let mut last_scanned = None;
+    for (scanned, object) in state.get_owned_objects(owner, query.cursor)?.enumerate() {
+        if scanned == max_scanned {
+            next_cursor = last_scanned;
+            break;
+        }
+
+        let matches = match (&object_type, &object.type_) {
+            (None, _) => true,
+            (Some(object_type), ObjectType::Struct(move_type)) => move_type.is(object_type),
+            (Some(_), ObjectType::Package) => false,
+        };
+        if matches {
+            if objects.len() == limit {
+                next_cursor = last_scanned;
+                break;
+            }
+            objects.push(ObjectVersion {
+                object_id: object.object_id,
+                version: object.version,
+                digest: object.digest,
+            });
+        }
+        last_scanned = Some(object.object_id);
+    }
+
+    Ok(OwnedObjectPage {
+        objects,
+        next_cursor,
+    })
+}
+
 #[derive(Debug)]
 pub struct ObjectNotFoundError {
     object_id: ObjectID,
@@ -87,3 +251,219 @@ impl From<ObjectNotFoundError> for crate::RestError {
         Self::new(axum::http::StatusCode::NOT_FOUND, value.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use axum::response::IntoResponse;
+    use simulacrum::Simulacrum;
+    use sui_types::{
+        gas_coin::MIST_PER_SUI,
+        programmable_transaction_builder::ProgrammableTransactionBuilder,
+        storage::ObjectStore,
+        transaction::{Transaction, TransactionData},
+        Identifier, SUI_FRAMEWORK_PACKAGE_ID, SUI_SYSTEM_STATE_OBJECT_ID,
+    };
+
+    use super::*;
+
+    #[test]
+    fn object_version() {
+        let sim = Simulacrum::new();
+
+        let object = sim
+            .get_object(&SUI_SYSTEM_STATE_OBJECT_ID)
+            .unwrap()
+            .unwrap();
+        let Ok(object_version) = latest_object_version(&sim, SUI_SYSTEM_STATE_OBJECT_ID) else {
+            panic!("expected the object's version");
+        };
+        assert_eq!(
+            object_version,
+            ObjectVersion {
+                object_id: SUI_SYSTEM_STATE_OBJECT_ID,
+                version: object.version(),
+                digest: object.digest(),
+            }
+        );
+
+        let Err(err) = latest_object_version(&sim, ObjectID::random()) else {
+            panic!("expected an error");
+        };
+        assert_eq!(err.into_response().status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn owned_object_pages() {
+        let mut sim = Simulacrum::new();
+        let owner = SuiAddress::random_for_testing_only();
+        for _ in 0..3 {
+            sim.request_gas(owner, MIST_PER_SUI).unwrap();
+        }
+
+        let page = |cursor, limit| {
+            owned_object_page(
+                &sim,
+                owner,
+                &ListOwnedObjectsQuery {
+                    cursor,
+                    limit,
+                    ..Default::default()
+                },
+                MAX_OBJECTS_SCANNED_PER_PAGE,
+            )
+        };
+
+        let Ok(all) = page(None, None) else {
+            panic!("expected a page");
+        };
+        assert_eq!(all.objects.len(), 3);
+        assert_eq!(all.next_cursor, None);
+        let ids = all
+            .objects
+            .iter()
+            .map(|object| object.object_id)
+            .collect::<Vec<_>>();
+
+        let Ok(first) = page(None, Some(2)) else {
+            panic!("expected a page");
+        };
+        assert_eq!(first.objects, all.objects[..2]);
+        assert_eq!(first.next_cursor, Some(ids[1]));
+
+        let Ok(last) = page(first.next_cursor, Some(2)) else {
+            panic!("expected a page");
+        };
+        assert_eq!(last.objects, all.objects[2..]);
+        assert_eq!(last.next_cursor, None);
+
+        // An address without objects has an empty page.
+        let Ok(empty) = owned_object_page(
+            &sim,
+            SuiAddress::random_for_testing_only(),
+            &ListOwnedObjectsQuery::default(),
+            MAX_OBJECTS_SCANNED_PER_PAGE,
+        ) else {
+            panic!("expected a page");
+        };
+        assert!(empty.objects.is_empty());
+        assert_eq!(empty.next_cursor, None);
+    }
+
+    #[test]
+    fn owned_object_pages_filtered_by_type() {
+        let mut sim = Simulacrum::new();
+        let (owner, _) = sim.keystore().accounts().next().unwrap();
+        let owner = *owner;
+        for _ in 0..2 {
+            sim.request_gas(owner, MIST_PER_SUI).unwrap();
+        }
+
+        // Creating a kiosk gives its owner a `KioskOwnerCap`, next to their gas coins.
+        let transaction = {
+            let (_, key) = sim.keystore().accounts().next().unwrap();
+            let gas = sim
+                .store()
+                .owned_objects(owner)
+                .find(|object| object.is_gas_coin())
+                .unwrap()
+                .compute_object_reference();
+            let mut builder = ProgrammableTransactionBuilder::new();
+            builder
+                .move_call(
+                    SUI_FRAMEWORK_PACKAGE_ID,
+                    Identifier::new("kiosk").unwrap(),
+                    Identifier::new("default").unwrap(),
+                    vec![],
+                    vec![],
+                )
+                .unwrap();
+            let data = TransactionData::new_programmable(
+                owner,
+                vec![gas],
+                builder.finish(),
+                1_000_000_000,
+                sim.reference_gas_price(),
+            );
+            Transaction::from_data_and_signer(data, vec![key])
+        };
+        let (_, error) = sim.execute_transaction(transaction).unwrap();
+        assert!(error.is_none(), "{error:?}");
+
+        let scan = |object_type: &str, cursor, limit, max_scanned| {
+            owned_object_page(
+                &sim,
+                owner,
+                &ListOwnedObjectsQuery {
+                    cursor,
+                    limit,
+                    object_type: Some(object_type.to_owned()),
+                },
+                max_scanned,
+            )
+        };
+        let page = |object_type: &str, cursor, limit| {
+            scan(object_type, cursor, limit, MAX_OBJECTS_SCANNED_PER_PAGE)
+        };
+        let is_gas_coin = |object: &ObjectVersion| {
+            sim.get_object(&object.object_id)
+                .unwrap()
+                .unwrap()
+                .is_gas_coin()
+        };
+
+        let Ok(caps) = page("0x2::kiosk::KioskOwnerCap", None, None) else {
+            panic!("expected a page");
+        };
+        assert_eq!(caps.objects.len(), 1);
+        assert!(!is_gas_coin(&caps.objects[0]));
+
+        let coin_type = "0x2::coin::Coin<0x2::sui::SUI>";
+        let Ok(coins) = page(coin_type, None, None) else {
+            panic!("expected a page");
+        };
+        assert!(coins.objects.len() >= 2);
+        assert!(coins.objects.iter().all(is_gas_coin));
+
+        // Pages are cut from the filtered objects.
+        let mut paged = vec![];
+        let mut cursor = None;
+        loop {
+            let Ok(next) = page(coin_type, cursor, Some(1)) else {
+                panic!("expected a page");
+            };
+            assert_eq!(next.objects.len(), 1);
+            paged.extend(next.objects);
+            cursor = next.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        assert_eq!(paged, coins.objects);
+
+        // Reading one object per page, the cap's page is preceded or followed by pages that
+        // matched nothing, but still carry a cursor past the objects they read.
+        let owned = sim.store().owned_objects(owner).count();
+        let mut paged = vec![];
+        let mut pages = 0;
+        let mut cursor = None;
+        loop {
+            let Ok(next) = scan("0x2::kiosk::KioskOwnerCap", cursor, None, 1) else {
+                panic!("expected a page");
+            };
+            assert!(next.objects.len() <= 1);
+            paged.extend(next.objects);
+            pages += 1;
+            cursor = next.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        assert_eq!(paged, caps.objects);
+        assert_eq!(pages, owned);
+
+        let Err(err) = page("not a type", None, None) else {
+            panic!("expected an error");
+        };
+        assert_eq!(err.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+}
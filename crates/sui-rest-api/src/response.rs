@@ -11,16 +11,19 @@ use reqwest::StatusCode;
 use crate::{
     types::{
         X_SUI_CHAIN_ID, X_SUI_CHECKPOINT_HEIGHT, X_SUI_EPOCH, X_SUI_OLDEST_CHECKPOINT_HEIGHT,
-        X_SUI_TIMESTAMP_MS,
+        X_SUI_SOFTWARE_VERSION, X_SUI_TIMESTAMP_MS,
     },
-    RestService, APPLICATION_BCS, TEXT_PLAIN_UTF_8,
+    RestService, APPLICATION_BCS, APPLICATION_CBOR, TEXT_PLAIN_UTF_8,
 };
 
 pub struct Bcs<T>(pub T);
 
+pub struct Cbor<T>(pub T);
+
 pub enum ResponseContent<T, J = T> {
     Bcs(T),
     Json(J),
+    Cbor(T),
 }
 
 impl<T> axum::response::IntoResponse for Bcs<T>
@@ -50,6 +53,34 @@ where
     }
 }
 
+impl<T> axum::response::IntoResponse for Cbor<T>
+where
+    T: serde::Serialize,
+{
+    fn into_response(self) -> axum::response::Response {
+        let mut buf = Vec::new();
+        match ciborium::into_writer(&self.0, &mut buf) {
+            Ok(()) => (
+                [(
+                    axum::http::header::CONTENT_TYPE,
+                    axum::http::HeaderValue::from_static(APPLICATION_CBOR),
+                )],
+                buf,
+            )
+                .into_response(),
+            Err(err) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                [(
+                    axum::http::header::CONTENT_TYPE,
+                    axum::http::HeaderValue::from_static(TEXT_PLAIN_UTF_8),
+                )],
+                err.to_string(),
+            )
+                .into_response(),
+        }
+    }
+}
+
 impl<T, J> axum::response::IntoResponse for ResponseContent<T, J>
 where
     T: serde::Serialize,
@@ -59,6 +90,7 @@ where
         match self {
             ResponseContent::Bcs(inner) => Bcs(inner).into_response(),
             ResponseContent::Json(inner) => axum::Json(inner).into_response(),
+            ResponseContent::Cbor(inner) => Cbor(inner).into_response(),
         }
     }
 }
@@ -100,6 +132,9 @@ pub async fn append_info_headers(
         X_SUI_OLDEST_CHECKPOINT_HEIGHT,
         oldest_checkpoint.to_string().try_into().unwrap(),
     );
+    if let Ok(software_version) = state.software_version().try_into() {
+        headers.insert(X_SUI_SOFTWARE_VERSION, software_version);
+    }
 
     (headers, response)
 }
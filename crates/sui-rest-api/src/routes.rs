@@ -0,0 +1,30 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::{extract::State, Json};
+
+use crate::RestService;
+
+pub const LIST_ROUTES_PATH: &str = "/api/routes";
+
+/// Description of a route served by the `RestService`.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RouteInfo {
+    pub method: String,
+    pub path: String,
+    pub description: String,
+}
+
+impl RouteInfo {
+    pub(crate) fn get(path: &str, description: &str) -> Self {
+        Self {
+            method: "GET".to_owned(),
+            path: path.to_owned(),
+            description: description.to_owned(),
+        }
+    }
+}
+
+pub async fn list_routes(State(state): State<RestService>) -> Json<Vec<RouteInfo>> {
+    Json(state.routes())
+}
@@ -136,6 +136,13 @@ pub const X_SUI_CHECKPOINT_HEIGHT: &str = "x-sui-checkpoint-height";
 /// Oldest non-pruned checkpoint height
 pub const X_SUI_OLDEST_CHECKPOINT_HEIGHT: &str = "x-sui-oldest-checkpoint-height";
 
+/// Software version of the node serving the request
+pub const X_SUI_SOFTWARE_VERSION: &str = "x-sui-software-version";
+
+/// Request header with the minimum software version a client accepts from the node. Requests
+/// naming a newer version than the node's are rejected with `426 Upgrade Required`.
+pub const X_SUI_MIN_SERVER_VERSION: &str = "x-min-server-version";
+
 /// Current epoch of the chain
 pub const X_SUI_EPOCH: &str = "x-sui-epoch";
 
@@ -0,0 +1,142 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::{
+    extract::State,
+    http::{Request, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use semver::Version;
+
+use crate::{types::X_SUI_MIN_SERVER_VERSION, RestService};
+
+/// Rejects requests whose `X-Min-Server-Version` header names a version newer than the one this
+/// node reports, with `426 Upgrade Required`, so that clients can refuse to talk to stale nodes.
+/// Requests without the header are passed through unchanged.
+pub async fn check_min_server_version<B>(
+    State(state): State<RestService>,
+    request: Request<B>,
+    next: Next<B>,
+) -> Response {
+    let Some(min_version) = request.headers().get(X_SUI_MIN_SERVER_VERSION) else {
+        return next.run(request).await;
+    };
+
+    let min_version = match min_version
+        .to_str()
+        .ok()
+        .and_then(|value| Version::parse(value.trim()).ok())
+    {
+        Some(min_version) => min_version,
+        None => {
+            return (
+                StatusCode::BAD_REQUEST,
+                format!("invalid {X_SUI_MIN_SERVER_VERSION} header, expected a semver version"),
+            )
+                .into_response()
+        }
+    };
+
+    if !satisfies_min_version(state.software_version(), &min_version) {
+        return (
+            StatusCode::UPGRADE_REQUIRED,
+            format!(
+                "server version {} is below the required {min_version}",
+                state.software_version()
+            ),
+        )
+            .into_response();
+    }
+
+    next.run(request).await
+}
+
+/// Whether `software_version` is at least `min_version`. Node versions carry the git revision as a
+/// pre-release suffix (e.g. `1.22.0-abcdef`), so only the release part of the server's version is
+/// compared. A version that is not valid semver (e.g. `unknown`) never satisfies the requirement.
+fn satisfies_min_version(software_version: &str, min_version: &Version) -> bool {
+    let Ok(version) = Version::parse(software_version) else {
+        return false;
+    };
+
+    Version::new(version.major, version.minor, version.patch) >= *min_version
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::body::Body;
+    use simulacrum::Simulacrum;
+    use sui_types::storage::ReadStore;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::types::X_SUI_SOFTWARE_VERSION;
+
+    fn service(software_version: &'static str) -> RestService {
+        let mut sim = Simulacrum::new();
+        sim.create_checkpoint();
+
+        let chain_id = (*sim
+            .get_checkpoint_by_sequence_number(0)
+            .unwrap()
+            .unwrap()
+            .digest())
+        .into();
+        RestService::new(Arc::new(sim), chain_id, software_version)
+    }
+
+    fn request(min_version: Option<&str>) -> Request<Body> {
+        let mut request = Request::builder().uri("/");
+        if let Some(min_version) = min_version {
+            request = request.header(X_SUI_MIN_SERVER_VERSION, min_version);
+        }
+        request.body(Body::empty()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn rejects_requests_requiring_a_newer_server() {
+        let response = service("1.2.3-abcdef")
+            .into_router()
+            .oneshot(request(Some("1.3.0")))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UPGRADE_REQUIRED);
+        assert_eq!(
+            response.headers().get(X_SUI_SOFTWARE_VERSION).unwrap(),
+            "1.2.3-abcdef"
+        );
+    }
+
+    #[tokio::test]
+    async fn serves_requests_satisfied_by_the_server() {
+        for min_version in [None, Some("1.2.3"), Some("1.0.0")] {
+            let response = service("1.2.3-abcdef")
+                .into_router()
+                .oneshot(request(min_version))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK, "{min_version:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_malformed_min_version() {
+        let response = service("1.2.3")
+            .into_router()
+            .oneshot(request(Some("latest")))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn unknown_server_version_is_not_satisfying() {
+        let min_version = Version::new(0, 0, 1);
+        assert!(!satisfies_min_version("unknown", &min_version));
+        assert!(satisfies_min_version("0.0.1", &min_version));
+    }
+}
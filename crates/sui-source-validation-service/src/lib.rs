@@ -6,10 +6,13 @@ use std::collections::BTreeMap;
 use std::fmt;
 use std::net::TcpListener;
 use std::path::PathBuf;
+use std::str::FromStr;
 use std::sync::{Arc, RwLock};
 use std::time::Duration;
 use std::{ffi::OsString, fs, path::Path, process::Command};
 use tokio::sync::oneshot::Sender;
+use tokio::sync::Semaphore;
+use tokio::task::JoinHandle;
 
 use anyhow::{anyhow, bail};
 use axum::extract::{Query, State};
@@ -69,6 +72,16 @@ pub fn host_port() -> String {
 #[derive(Clone, Deserialize, Debug)]
 pub struct Config {
     pub packages: Vec<PackageSource>,
+    /// When set, upgrade watchers only log observed upgrades and leave served sources untouched.
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Maximum number of packages verified at the same time on startup. Unbounded when unset.
+    #[serde(default)]
+    pub concurrency: Option<usize>,
+    /// How long verifying a single package on startup may take, in seconds, before it is reported
+    /// as failed. Unbounded when unset.
+    #[serde(default)]
+    pub verification_timeout_secs: Option<u64>,
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -114,19 +127,53 @@ pub struct SourceInfo {
 }
 
 #[derive(Eq, PartialEq, Clone, Default, Serialize, Deserialize, Debug, Ord, PartialOrd)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "lowercase", try_from = "String")]
 pub enum Network {
     #[default]
-    #[serde(alias = "Mainnet")]
     Mainnet,
-    #[serde(alias = "Testnet")]
     Testnet,
-    #[serde(alias = "Devnet")]
     Devnet,
-    #[serde(alias = "Localnet")]
     Localnet,
 }
 
+impl Network {
+    /// Every known network, in the order they are reported.
+    pub fn all() -> [Network; 4] {
+        [
+            Network::Mainnet,
+            Network::Testnet,
+            Network::Devnet,
+            Network::Localnet,
+        ]
+    }
+}
+
+impl FromStr for Network {
+    type Err = anyhow::Error;
+
+    /// Parses a network by name, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Network::all()
+            .into_iter()
+            .find(|network| network.to_string().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                let known: Vec<_> = Network::all().iter().map(|n| n.to_string()).collect();
+                anyhow!(
+                    "Unknown network '{s}', expected one of: {}",
+                    known.join(", ")
+                )
+            })
+    }
+}
+
+impl TryFrom<String> for Network {
+    type Error = anyhow::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
 impl fmt::Display for Network {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -165,7 +212,11 @@ pub async fn verify_package(
         run_bytecode_verifier: false, /* no need to run verifier if code is on-chain */
         print_diags_to_stderr: false,
     };
-    let compiled_package = build_config.build(package_path.as_ref().to_path_buf())?;
+    // Building is synchronous and can be slow, so it runs on a blocking thread to keep it from
+    // stalling the runtime, and to let a verification timeout interrupt the wait for it.
+    let build_path = package_path.as_ref().to_path_buf();
+    let compiled_package =
+        tokio::task::spawn_blocking(move || build_config.build(build_path)).await??;
 
     let network_url = match network {
         Network::Mainnet => MAINNET_URL,
@@ -206,8 +257,9 @@ pub async fn verify_package(
 }
 
 pub fn parse_config(config_path: impl AsRef<Path>) -> anyhow::Result<Config> {
+    let config_path = config_path.as_ref();
     let contents = fs::read_to_string(config_path)?;
-    Ok(toml::from_str(&contents)?)
+    toml::from_str(&contents).map_err(|e| anyhow!("Invalid config {}: {e}", config_path.display()))
 }
 
 pub fn repo_name_from_url(url: &str) -> anyhow::Result<String> {
@@ -343,7 +395,10 @@ pub async fn initialize(
         }
     }
     clone_repositories(repos, dir).await?;
-    let sources = verify_packages(config, dir).await?;
+    let VerificationReport { sources, failures } = verify_packages_report(config, dir).await?;
+    for (package_path, error) in &failures {
+        error!("failed to verify {}: {error}", package_path.display());
+    }
     let sources_list = sources_list(&sources).await;
     Ok((sources, sources_list))
 }
@@ -370,7 +425,45 @@ pub async fn sources_list(sources: &NetworkLookup) -> NetworkLookup {
     sources_list
 }
 
+/// Outcome of verifying all packages in a `Config`.
+pub struct VerificationReport {
+    /// Sources of the packages that were verified successfully.
+    pub sources: NetworkLookup,
+    /// Packages that failed to verify, or did not finish verifying in time.
+    pub failures: Vec<(PathBuf, anyhow::Error)>,
+}
+
+/// Verifies all packages in `config`, failing if any of them could not be verified. See
+/// `verify_packages_report` to keep the packages that were verified.
 pub async fn verify_packages(config: &Config, dir: &Path) -> anyhow::Result<NetworkLookup> {
+    let VerificationReport {
+        sources,
+        mut failures,
+    } = verify_packages_report(config, dir).await?;
+    match failures.len() {
+        0 => Ok(sources),
+        1 => Err(failures.pop().unwrap().1),
+        n => {
+            let errors: Vec<_> = failures
+                .iter()
+                .map(|(package_path, error)| format!("- {}: {error}", package_path.display()))
+                .collect();
+            bail!("{n} packages failed to verify:\n{}", errors.join("\n"))
+        }
+    }
+}
+
+/// Verifies all packages in `config` concurrently, up to `config.concurrency` at a time and each
+/// within `config.verification_timeout_secs`. A package failing to verify does not stop the others
+/// from being verified; failures are collected in the report instead.
+pub async fn verify_packages_report(
+    config: &Config,
+    dir: &Path,
+) -> anyhow::Result<VerificationReport> {
+    let permits = Arc::new(Semaphore::new(
+        config.concurrency.unwrap_or(Semaphore::MAX_PERMITS).max(1),
+    ));
+    let timeout = config.verification_timeout_secs.map(Duration::from_secs);
     let mut tasks = vec![];
     for p in &config.packages {
         match p {
@@ -387,10 +480,8 @@ pub async fn verify_packages(config: &Config, dir: &Path) -> anyhow::Result<Netw
                             .clone();
                         let network = r.network.clone().unwrap_or_default();
                         let t =
-                            tokio::spawn(
-                                async move { verify_package(&network, package_path).await },
-                            );
-                        tasks.push(t)
+                            spawn_verification(network, package_path.clone(), &permits, timeout);
+                        tasks.push((package_path, t))
                     }
                 }
             }
@@ -398,9 +489,8 @@ pub async fn verify_packages(config: &Config, dir: &Path) -> anyhow::Result<Netw
                 for p in &packages_dir.paths {
                     let package_path = PathBuf::from(p.path.clone());
                     let network = packages_dir.network.clone().unwrap_or_default();
-                    let t =
-                        tokio::spawn(async move { verify_package(&network, package_path).await });
-                    tasks.push(t)
+                    let t = spawn_verification(network, package_path.clone(), &permits, timeout);
+                    tasks.push((package_path, t))
                 }
             }
         }
@@ -410,8 +500,15 @@ pub async fn verify_packages(config: &Config, dir: &Path) -> anyhow::Result<Netw
     let mut testnet_lookup = AddressLookup::new();
     let mut devnet_lookup = AddressLookup::new();
     let mut localnet_lookup = AddressLookup::new();
-    for t in tasks {
-        let (network, new_lookup) = t.await.unwrap()?;
+    let mut failures = vec![];
+    for (package_path, t) in tasks {
+        let (network, new_lookup) = match t.await.map_err(anyhow::Error::from) {
+            Ok(Ok(result)) => result,
+            Ok(Err(e)) | Err(e) => {
+                failures.push((package_path, e));
+                continue;
+            }
+        };
         match network {
             Network::Mainnet => mainnet_lookup.extend(new_lookup),
             Network::Testnet => testnet_lookup.extend(new_lookup),
@@ -424,18 +521,45 @@ pub async fn verify_packages(config: &Config, dir: &Path) -> anyhow::Result<Netw
     lookup.insert(Network::Testnet, testnet_lookup);
     lookup.insert(Network::Devnet, devnet_lookup);
     lookup.insert(Network::Localnet, localnet_lookup);
-    Ok(lookup)
+    Ok(VerificationReport {
+        sources: lookup,
+        failures,
+    })
+}
+
+/// Spawns verification of the package at `package_path`, once one of `permits` is available.
+/// When verification times out, the permit is released right away, although the package build
+/// may keep running on its blocking thread until it finishes.
+fn spawn_verification(
+    network: Network,
+    package_path: PathBuf,
+    permits: &Arc<Semaphore>,
+    timeout: Option<Duration>,
+) -> JoinHandle<anyhow::Result<(Network, AddressLookup)>> {
+    let permits = permits.clone();
+    tokio::spawn(async move {
+        let _permit = permits.acquire_owned().await?;
+        let verification = verify_package(&network, &package_path);
+        match timeout {
+            Some(timeout) => tokio::time::timeout(timeout, verification)
+                .await
+                .map_err(|_| anyhow!("verification timed out after {timeout:?}"))?,
+            None => verification.await,
+        }
+    })
 }
 
 // A thread that monitors on-chain transactions for package upgrades. `config` specifies which packages
 // to watch. `app_state` contains the map of sources returned by the server. In particular, `watch_for_upgrades`
 // invalidates (i.e., clears) the sources returned by the serve when we observe a package upgrade, so that we do not
 // falsely report outdated sources for a package. Pass an optional `channel` to observe the upgrade transaction(s).
-// The `channel` parameter exists for testing.
+// The `channel` parameter exists for testing. When `dry_run` is set, observed upgrades are logged but `app_state`
+// is left untouched and the server keeps running.
 pub async fn watch_for_upgrades(
     packages: Vec<PackageSource>,
     app_state: Arc<RwLock<AppState>>,
     network: Network,
+    dry_run: bool,
     channel: Option<Sender<SuiTransactionBlockEffects>>,
 ) -> anyhow::Result<()> {
     let mut watch_ids = ArrayParams::new();
@@ -489,6 +613,17 @@ pub async fn watch_for_upgrades(
                 // Restarting is a manual side-effect outside of this server because we need to ensure that sources in the
                 // repositories _actually contain_ the latest source corresponding to on-chain data (which is subject to
                 // manual syncing itself currently).
+                if dry_run {
+                    info!(
+                        "[dry-run] Saw upgrade txn, would clear sources and shut down: {:?}",
+                        result
+                    );
+                    if let Some(channel) = channel {
+                        channel.send(result).unwrap();
+                        break Ok(());
+                    }
+                    continue;
+                }
                 info!("Saw upgrade txn: {:?}", result);
                 let mut app_state = app_state.write().unwrap();
                 app_state.sources = NetworkLookup::new(); // Clear all sources.
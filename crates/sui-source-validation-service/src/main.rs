@@ -44,14 +44,8 @@ pub async fn main() -> anyhow::Result<()> {
         sources_list,
     }));
     let mut threads = vec![];
-    let networks_to_watch = vec![
-        Network::Mainnet,
-        Network::Testnet,
-        Network::Devnet,
-        Network::Localnet,
-    ];
     // spawn a watcher thread for upgrades for each network
-    for network in networks_to_watch {
+    for network in Network::all() {
         let app_state_copy = app_state.clone();
         let packages: Vec<_> = package_config
             .clone()
@@ -73,8 +67,9 @@ pub async fn main() -> anyhow::Result<()> {
         if packages.is_empty() {
             continue;
         }
+        let dry_run = package_config.dry_run;
         let watcher = tokio::spawn(async move {
-            watch_for_upgrades(packages, app_state_copy, network, None).await
+            watch_for_upgrades(packages, app_state_copy, network, dry_run, None).await
         });
         threads.push(watcher);
     }
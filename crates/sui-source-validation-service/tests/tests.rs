@@ -23,10 +23,11 @@ use tokio::sync::oneshot;
 use move_core_types::account_address::AccountAddress;
 use move_symbol_pool::Symbol;
 use sui_source_validation_service::{
-    host_port, initialize, serve, start_prometheus_server, verify_packages, watch_for_upgrades,
-    AddressLookup, AppState, Branch, CloneCommand, Config, DirectorySource, ErrorResponse, Network,
-    NetworkLookup, Package, PackageSource, RepositorySource, SourceInfo, SourceLookup,
-    SourceResponse, SourceServiceMetrics, METRICS_HOST_PORT, SUI_SOURCE_VALIDATION_VERSION_HEADER,
+    host_port, initialize, parse_config, serve, start_prometheus_server, verify_packages,
+    verify_packages_report, watch_for_upgrades, AddressLookup, AppState, Branch, CloneCommand,
+    Config, DirectorySource, ErrorResponse, Network, NetworkLookup, Package, PackageSource,
+    RepositorySource, SourceInfo, SourceLookup, SourceResponse, SourceServiceMetrics,
+    VerificationReport, METRICS_HOST_PORT, SUI_SOURCE_VALIDATION_VERSION_HEADER,
 };
 use test_cluster::TestClusterBuilder;
 
@@ -86,6 +87,9 @@ async fn test_end_to_end() -> anyhow::Result<()> {
             }],
             network: Some(Network::Localnet),
         })],
+        dry_run: false,
+        concurrency: None,
+        verification_timeout_secs: None,
     };
     // Start watching for upgrades.
     let mut sources = NetworkLookup::new();
@@ -100,8 +104,30 @@ async fn test_end_to_end() -> anyhow::Result<()> {
     }));
     let app_state_ref = app_state.clone();
     let (tx, rx) = oneshot::channel();
+    let packages = config.packages.clone();
     tokio::spawn(async move {
-        watch_for_upgrades(config.packages, app_state, Network::Localnet, Some(tx)).await
+        watch_for_upgrades(packages, app_state, Network::Localnet, false, Some(tx)).await
+    });
+
+    // Watch the same upgrade cap in dry-run mode, which should leave its state untouched.
+    let mut dry_run_sources = NetworkLookup::new();
+    dry_run_sources.insert(Network::Localnet, AddressLookup::new());
+    let dry_run_app_state = Arc::new(RwLock::new(AppState {
+        sources: dry_run_sources.clone(),
+        metrics: None,
+        sources_list: dry_run_sources,
+    }));
+    let dry_run_app_state_ref = dry_run_app_state.clone();
+    let (dry_run_tx, dry_run_rx) = oneshot::channel();
+    tokio::spawn(async move {
+        watch_for_upgrades(
+            config.packages,
+            dry_run_app_state,
+            Network::Localnet,
+            true,
+            Some(dry_run_tx),
+        )
+        .await
     });
 
     // Set up to upgrade package.
@@ -115,7 +141,7 @@ async fn test_end_to_end() -> anyhow::Result<()> {
     let upgrade_pkg_path =
         copy_with_published_at_manifest(&package_path, &tmp_dir.path().to_path_buf(), package_id);
     // Run the upgrade.
-    run_upgrade(upgrade_pkg_path, cap, context, gas_obj_id, rgp).await?;
+    run_upgrade(upgrade_pkg_path.clone(), cap, context, gas_obj_id, rgp).await?;
 
     // Test expects to observe an upgrade transaction.
     let Ok(SuiTransactionBlockEffects::V1(effects)) = rx.await else {
@@ -126,6 +152,22 @@ async fn test_end_to_end() -> anyhow::Result<()> {
     let app_state_ref = app_state_ref.read().unwrap();
     assert!(app_state_ref.sources.is_empty());
 
+    // Test expects the dry-run watcher to observe the same upgrade without clearing its sources.
+    let Ok(SuiTransactionBlockEffects::V1(dry_run_effects)) = dry_run_rx.await else {
+        panic!("No upgrade transaction observed in dry-run mode")
+    };
+    assert_eq!(
+        dry_run_effects.transaction_digest,
+        effects.transaction_digest
+    );
+    let dry_run_app_state_ref = dry_run_app_state_ref.read().unwrap();
+    assert!(dry_run_app_state_ref
+        .sources
+        .contains_key(&Network::Localnet));
+    assert!(dry_run_app_state_ref
+        .sources_list
+        .contains_key(&Network::Localnet));
+
     ///////////////////////////
     // Test verify_packages
     //////////////////////////
@@ -141,6 +183,9 @@ async fn test_end_to_end() -> anyhow::Result<()> {
             }],
             network: Some(Network::Localnet),
         })],
+        dry_run: false,
+        concurrency: None,
+        verification_timeout_secs: None,
     };
 
     let fixtures = tempfile::tempdir()?;
@@ -166,6 +211,62 @@ Network localnet: Multiple source verification errors found:
 - Local dependency did not match its on-chain version at 0000000000000000000000000000000000000000000000000000000000000001::MoveStdlib::address"#
     ];
     expected.assert_eq(truncated_error_message);
+
+    ///////////////////////////
+    // Test verify_packages_report
+    //////////////////////////
+    // Verify a published package concurrently with packages that fail to verify, because they
+    // are missing or do not match their on-chain version.
+    let missing_path = fixtures.path().join("missing");
+    let stdlib_path = fixtures
+        .path()
+        .join("localnet")
+        .join("sui__main")
+        .join("move-stdlib");
+    let directory_source = |path: &PathBuf| {
+        PackageSource::Directory(DirectorySource {
+            paths: vec![Package {
+                path: path.display().to_string(),
+                watch: None,
+            }],
+            network: Some(Network::Localnet),
+        })
+    };
+    let config = Config {
+        packages: vec![
+            directory_source(&missing_path),
+            directory_source(&upgrade_pkg_path),
+            directory_source(&stdlib_path),
+        ],
+        dry_run: false,
+        concurrency: Some(3),
+        verification_timeout_secs: Some(600),
+    };
+    let VerificationReport { sources, failures } =
+        verify_packages_report(&config, fixtures.path()).await?;
+    let verified_modules: Vec<_> = sources[&Network::Localnet][&AccountAddress::from(package_id)]
+        .keys()
+        .copied()
+        .collect();
+    assert_eq!(verified_modules, vec![Symbol::from("foo")]);
+    let failed_paths: Vec<_> = failures.iter().map(|(path, _)| path.clone()).collect();
+    assert_eq!(failed_paths, vec![missing_path, stdlib_path]);
+
+    // A package that does not finish building within the timeout fails to verify.
+    let config = Config {
+        packages: vec![directory_source(&upgrade_pkg_path)],
+        dry_run: false,
+        concurrency: Some(2),
+        verification_timeout_secs: Some(0),
+    };
+    let VerificationReport { sources, failures } =
+        verify_packages_report(&config, fixtures.path()).await?;
+    assert!(sources[&Network::Localnet].is_empty());
+    let [(failed_path, error)] = &failures[..] else {
+        panic!("Expected one failure, got {failures:?}");
+    };
+    assert_eq!(failed_path, &upgrade_pkg_path);
+    assert_eq!(error.to_string(), "verification timed out after 0ns");
     Ok(())
 }
 
@@ -258,9 +359,58 @@ fn copy_with_published_at_manifest(
     upgrade_pkg_path
 }
 
+#[tokio::test]
+async fn test_verify_packages_reports_all_failures() -> anyhow::Result<()> {
+    let missing = tempfile::tempdir()?;
+    let package_paths: Vec<_> = ["first", "second", "third"]
+        .into_iter()
+        .map(|name| missing.path().join(name))
+        .collect();
+    let config = Config {
+        packages: package_paths
+            .iter()
+            .map(|path| {
+                PackageSource::Directory(DirectorySource {
+                    paths: vec![Package {
+                        path: path.display().to_string(),
+                        watch: None,
+                    }],
+                    network: Some(Network::Localnet),
+                })
+            })
+            .collect(),
+        dry_run: false,
+        // Verify one package at a time, so every package only starts after the previous one failed.
+        concurrency: Some(1),
+        verification_timeout_secs: Some(60),
+    };
+    let tmp_dir = tempfile::tempdir()?;
+
+    let VerificationReport { sources, failures } =
+        verify_packages_report(&config, tmp_dir.path()).await?;
+    assert!(sources.values().all(|addresses| addresses.is_empty()));
+    let failed_paths: Vec<_> = failures.iter().map(|(path, _)| path.clone()).collect();
+    assert_eq!(failed_paths, package_paths);
+
+    let error = verify_packages(&config, tmp_dir.path())
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(error.starts_with("3 packages failed to verify:"));
+
+    // Failing packages do not fail initialization.
+    initialize(&config, tmp_dir.path()).await?;
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_api_route() -> anyhow::Result<()> {
-    let config = Config { packages: vec![] };
+    let config = Config {
+        packages: vec![],
+        dry_run: false,
+        concurrency: None,
+        verification_timeout_secs: None,
+    };
     let tmp_dir = tempfile::tempdir()?;
     initialize(&config, tmp_dir.path()).await?;
 
@@ -461,11 +611,48 @@ paths = [
                     },
                 ),
             ],
+            dry_run: false,
+            concurrency: None,
+            verification_timeout_secs: None,
         }"#]];
     expect.assert_eq(&format!("{:#?}", config));
     Ok(())
 }
 
+#[test]
+fn test_parse_config_rejects_unknown_network() -> anyhow::Result<()> {
+    let config = r#"
+[[packages]]
+source = "Directory"
+[packages.values]
+network = "mainnnet"
+paths = [{ path = "home/user/some/upgradeable-package", watch = "0x1234" }]
+"#;
+    let dir = tempfile::tempdir()?;
+    let config_path = dir.path().join("config.toml");
+    fs::write(&config_path, config)?;
+
+    let err = parse_config(&config_path).unwrap_err().to_string();
+    assert!(
+        err.contains(
+            "Unknown network 'mainnnet', expected one of: mainnet, testnet, devnet, localnet"
+        ),
+        "{err}"
+    );
+
+    // Network names are not case sensitive.
+    fs::write(&config_path, config.replace("mainnnet", "Mainnet"))?;
+    let config = parse_config(&config_path)?;
+    assert!(matches!(
+        &config.packages[..],
+        [PackageSource::Directory(DirectorySource {
+            network: Some(Network::Mainnet),
+            ..
+        })]
+    ));
+    Ok(())
+}
+
 #[test]
 fn test_clone_command() -> anyhow::Result<()> {
     let source = RepositorySource {
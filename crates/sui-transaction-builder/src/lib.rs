@@ -30,7 +30,8 @@ use sui_types::object::{Object, Owner};
 use sui_types::programmable_transaction_builder::ProgrammableTransactionBuilder;
 use sui_types::sui_system_state::SUI_SYSTEM_MODULE_NAME;
 use sui_types::transaction::{
-    Argument, CallArg, Command, InputObjectKind, ObjectArg, TransactionData, TransactionKind,
+    check_gas_budget, Argument, CallArg, Command, InputObjectKind, ObjectArg, TransactionData,
+    TransactionKind,
 };
 use sui_types::{coin, fp_ensure, SUI_FRAMEWORK_PACKAGE_ID, SUI_SYSTEM_PACKAGE_ID};
 
@@ -52,11 +53,30 @@ pub trait DataReader {
 }
 
 #[derive(Clone)]
-pub struct TransactionBuilder(Arc<dyn DataReader + Sync + Send>);
+pub struct TransactionBuilder(
+    Arc<dyn DataReader + Sync + Send>,
+    // When set, move call and publish transactions are checked against the gas budget limit of
+    // this config before they are returned.
+    Option<Arc<ProtocolConfig>>,
+);
 
 impl TransactionBuilder {
     pub fn new(data_reader: Arc<dyn DataReader + Sync + Send>) -> Self {
-        Self(data_reader)
+        Self(data_reader, None)
+    }
+
+    /// Reject move call and publish transactions whose gas budget exceeds the limit in `config`,
+    /// instead of discovering it only after submission.
+    pub fn with_strict_gas_budget_check(mut self, config: ProtocolConfig) -> Self {
+        self.1 = Some(Arc::new(config));
+        self
+    }
+
+    fn check_gas_budget(&self, gas_budget: u64) -> Result<(), anyhow::Error> {
+        if let Some(config) = &self.1 {
+            check_gas_budget(config, gas_budget)?;
+        }
+        Ok(())
     }
 
     async fn select_gas(
@@ -401,6 +421,7 @@ impl TransactionBuilder {
             call_args,
         )
         .await?;
+        self.check_gas_budget(gas_budget)?;
         let pt = builder.finish();
         let input_objects = pt
             .input_objects()?
@@ -593,6 +614,7 @@ impl TransactionBuilder {
         gas: Option<ObjectID>,
         gas_budget: u64,
     ) -> anyhow::Result<TransactionData> {
+        self.check_gas_budget(gas_budget)?;
         let gas_price = self.0.get_reference_gas_price().await?;
         let gas = self
             .select_gas(sender, gas, gas_budget, vec![], gas_price)
@@ -1,7 +1,12 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{collections::BTreeMap, path::PathBuf, sync::Arc, time::Duration};
+use std::{
+    collections::{BTreeMap, HashSet},
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
+};
 
 use move_binary_format::CompiledModule;
 use move_bytecode_utils::module_cache::GetModule;
@@ -12,14 +17,16 @@ use sui_config::genesis;
 use sui_protocol_config::ProtocolVersion;
 use sui_swarm_config::genesis_config::AccountConfig;
 use sui_swarm_config::network_config_builder::ConfigBuilder;
+use sui_types::message_envelope::Message;
 use sui_types::storage::ReadStore;
 use sui_types::{
     base_types::{ObjectID, SequenceNumber, SuiAddress, VersionNumber},
     committee::{Committee, EpochId},
     crypto::AccountKeyPair,
-    digests::{ObjectDigest, TransactionDigest, TransactionEventsDigest},
+    digests::{ObjectDigest, TransactionDigest, TransactionEffectsDigest, TransactionEventsDigest},
     effects::{TransactionEffects, TransactionEffectsAPI, TransactionEvents},
     error::{SuiError, UserInputError},
+    full_checkpoint_content::{CheckpointData, CheckpointTransaction},
     messages_checkpoint::{
         CheckpointContents, CheckpointContentsDigest, CheckpointDigest, CheckpointSequenceNumber,
         VerifiedCheckpoint,
@@ -35,9 +42,10 @@ use tempfile::tempdir;
 use typed_store::traits::TableSummary;
 use typed_store::traits::TypedStoreDebug;
 use typed_store::Map;
+use typed_store::TypedStoreError;
 use typed_store::{
     metrics::SamplingInterval,
-    rocks::{DBMap, MetricConf},
+    rocks::{DBMap, MetricConf, RocksDB},
 };
 use typed_store_derive::DBMapUtils;
 
@@ -53,6 +61,33 @@ pub struct PersistedStoreInnerReadOnlyWrapper {
     pub inner: PersistedStoreInnerReadOnly,
 }
 
+/// An inconsistency between the tables of a `PersistedStore`, found by
+/// `PersistedStore::check_invariants`.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum InvariantViolation {
+    #[error("Live version {version} of object {object_id} is not stored")]
+    MissingLiveObjectVersion {
+        object_id: ObjectID,
+        version: SequenceNumber,
+    },
+    #[error("Contents {content_digest} of checkpoint {sequence_number} are not stored")]
+    MissingCheckpointContents {
+        sequence_number: CheckpointSequenceNumber,
+        content_digest: CheckpointContentsDigest,
+    },
+    #[error("Committee for epoch {epoch} is stored as the committee for epoch {position}")]
+    NonContiguousCommittees { position: EpochId, epoch: EpochId },
+    #[error(
+        "Live version {live_version} of object {object_id} is older than version {written_version} written by transaction {tx_digest}"
+    )]
+    StaleLiveObject {
+        object_id: ObjectID,
+        live_version: SequenceNumber,
+        written_version: SequenceNumber,
+        tx_digest: TransactionDigest,
+    },
+}
+
 #[derive(Debug, DBMapUtils)]
 pub struct PersistedStoreInner {
     // Checkpoint data
@@ -63,6 +98,7 @@ pub struct PersistedStoreInner {
     // Transaction data
     transactions: DBMap<TransactionDigest, sui_types::transaction::TrustedTransaction>,
     effects: DBMap<TransactionDigest, TransactionEffects>,
+    effects_digest_to_tx: DBMap<TransactionEffectsDigest, TransactionDigest>,
     events: DBMap<TransactionEventsDigest, TransactionEvents>,
     events_tx_digest_index: DBMap<TransactionDigest, TransactionEventsDigest>,
 
@@ -76,6 +112,13 @@ pub struct PersistedStoreInner {
 
 impl PersistedStore {
     pub fn new(genesis: &genesis::Genesis, path: PathBuf) -> Self {
+        let mut res = Self::open(path);
+        res.init_with_genesis(genesis);
+
+        res
+    }
+
+    fn open(path: PathBuf) -> Self {
         let samp: SamplingInterval = SamplingInterval::new(Duration::from_secs(60), 0);
         let read_write = PersistedStoreInner::open_tables_read_write(
             path.clone(),
@@ -84,10 +127,287 @@ impl PersistedStore {
             None,
         );
 
-        let mut res = Self { path, read_write };
-        res.init_with_genesis(genesis);
+        Self { path, read_write }
+    }
 
-        res
+    /// Look up a transaction by the digest of its effects, e.g. when replaying from the effects
+    /// digests referenced by a checkpoint.
+    pub fn get_transaction_by_effects_digest(
+        &self,
+        digest: &TransactionEffectsDigest,
+    ) -> Option<VerifiedTransaction> {
+        self.read_write
+            .effects_digest_to_tx
+            .get(digest)
+            .expect("Fatal: DB read failed")
+            .and_then(|tx_digest| self.get_transaction(&tx_digest))
+    }
+
+    /// Stitch checkpoint `sequence_number` back together with its contents and the transactions,
+    /// effects, events, input and output objects of each of its transactions, in the shape served
+    /// by the REST API. Returns `None` if the checkpoint or any of its parts is not stored.
+    pub fn assemble_full_checkpoint(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> Option<CheckpointData> {
+        let checkpoint = self.get_checkpoint_by_sequence_number(sequence_number)?;
+        let checkpoint_contents = self.get_checkpoint_contents(&checkpoint.content_digest)?;
+
+        let transactions = checkpoint_contents
+            .iter()
+            .map(|execution_digests| {
+                let tx_digest = &execution_digests.transaction;
+                let transaction = self.get_transaction(tx_digest)?;
+                let effects = self.get_transaction_effects(tx_digest)?;
+                let events = match effects.events_digest() {
+                    Some(digest) => Some(self.get_transaction_events(digest)?),
+                    None => None,
+                };
+
+                // Unwrapped-then-deleted objects are not stored in state before the transaction.
+                let unwrapped_then_deleted = effects
+                    .unwrapped_then_deleted()
+                    .into_iter()
+                    .map(|(id, _, _)| id)
+                    .collect::<HashSet<_>>();
+                let input_objects = effects
+                    .input_shared_objects()
+                    .into_iter()
+                    .map(|kind| kind.id_and_version())
+                    .chain(effects.modified_at_versions())
+                    .collect::<HashSet<_>>()
+                    .into_iter()
+                    .filter(|(id, _)| !unwrapped_then_deleted.contains(id))
+                    .map(|(id, version)| self.get_object_at_version(&id, version))
+                    .collect::<Option<Vec<_>>>()?;
+                let output_objects = effects
+                    .all_changed_objects()
+                    .into_iter()
+                    .map(|((id, version, _), _, _)| self.get_object_at_version(&id, version))
+                    .collect::<Option<Vec<_>>>()?;
+
+                Some(CheckpointTransaction {
+                    transaction: transaction.into(),
+                    effects,
+                    events,
+                    input_objects,
+                    output_objects,
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(CheckpointData {
+            checkpoint_summary: checkpoint.into(),
+            checkpoint_contents,
+            transactions,
+        })
+    }
+
+    /// Create a new store, backed by a temporary directory, holding the state of this store as of
+    /// checkpoint `sequence_number`.
+    ///
+    /// Live objects are reconstructed by replaying the effects of every transaction up to and
+    /// including that checkpoint against the object version history, so that transactions applied
+    /// to the fork do not affect this store, and vice versa.
+    pub fn fork_at_checkpoint(&self, sequence_number: CheckpointSequenceNumber) -> PersistedStore {
+        let mut fork = Self::open(tempdir().unwrap().into_path());
+
+        let fork_checkpoint = self
+            .get_checkpoint_by_sequence_number(sequence_number)
+            .unwrap_or_else(|| panic!("checkpoint {sequence_number} does not exist"));
+        let last_epoch = if fork_checkpoint.end_of_epoch_data.is_some() {
+            fork_checkpoint.epoch + 1
+        } else {
+            fork_checkpoint.epoch
+        };
+        for committee in (0..=last_epoch).map_while(|epoch| self.get_committee_by_epoch(epoch)) {
+            fork.insert_committee(committee);
+        }
+
+        for seq in 0..=sequence_number {
+            let checkpoint = self
+                .get_checkpoint_by_sequence_number(seq)
+                .expect("checkpoints must be contiguous");
+            let contents = self
+                .get_checkpoint_contents(&checkpoint.content_digest)
+                .expect("checkpoint contents must exist");
+
+            for digests in contents.iter() {
+                let tx_digest = digests.transaction;
+                let transaction = self
+                    .get_transaction(&tx_digest)
+                    .expect("checkpointed transaction must exist");
+                let effects = self
+                    .get_transaction_effects(&tx_digest)
+                    .expect("checkpointed transaction effects must exist");
+                let events = self
+                    .get_transaction_events_by_tx_digest(&tx_digest)
+                    .unwrap_or_default();
+                let written_objects = effects
+                    .all_changed_objects()
+                    .into_iter()
+                    .map(|((id, version, _), _, _)| {
+                        let object = self
+                            .get_object_at_version(&id, version)
+                            .expect("written object version must exist");
+                        (id, object)
+                    })
+                    .collect();
+                fork.insert_executed_transaction(transaction, effects, events, written_objects);
+            }
+
+            fork.insert_checkpoint_contents(contents);
+            fork.insert_checkpoint(checkpoint);
+        }
+
+        fork
+    }
+
+    /// Epochs for which a committee is stored, in ascending order.
+    pub fn available_epochs(&self) -> Vec<EpochId> {
+        let num_committees = self
+            .read_write
+            .epoch_to_committee
+            .get(&())
+            .expect("Fatal: DB read failed")
+            .map_or(0, |committees| committees.len());
+        (0..num_committees as EpochId).collect()
+    }
+
+    /// Checks that the tables of this store are consistent with each other, returning the first
+    /// violation found. Meant to be called by tests after a sequence of operations on the store.
+    pub fn check_invariants(&self) -> Result<(), InvariantViolation> {
+        let live_objects = self
+            .read_write
+            .live_objects
+            .unbounded_iter()
+            .collect::<BTreeMap<_, _>>();
+
+        for (&object_id, &version) in &live_objects {
+            if self.get_object_at_version(&object_id, version).is_none() {
+                return Err(InvariantViolation::MissingLiveObjectVersion { object_id, version });
+            }
+        }
+
+        for (sequence_number, checkpoint) in self.read_write.checkpoints.unbounded_iter() {
+            let content_digest = VerifiedCheckpoint::from(checkpoint).content_digest;
+            if self.get_checkpoint_contents(&content_digest).is_none() {
+                return Err(InvariantViolation::MissingCheckpointContents {
+                    sequence_number,
+                    content_digest,
+                });
+            }
+        }
+
+        let committees = self
+            .read_write
+            .epoch_to_committee
+            .get(&())
+            .expect("Fatal: DB read failed")
+            .unwrap_or_default();
+        for (position, committee) in committees.iter().enumerate() {
+            if committee.epoch != position as EpochId {
+                return Err(InvariantViolation::NonContiguousCommittees {
+                    position: position as EpochId,
+                    epoch: committee.epoch,
+                });
+            }
+        }
+
+        for (tx_digest, effects) in self.read_write.effects.unbounded_iter() {
+            for ((object_id, written_version, _), _, _) in effects.all_changed_objects() {
+                match live_objects.get(&object_id) {
+                    Some(&live_version) if live_version < written_version => {
+                        return Err(InvariantViolation::StaleLiveObject {
+                            object_id,
+                            live_version,
+                            written_version,
+                            tx_digest,
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Inserts `checkpoint` together with its `contents`, failing without writing anything if
+    /// `contents` are not the ones `checkpoint` commits to.
+    pub fn insert_checkpoint_with_contents(
+        &mut self,
+        checkpoint: VerifiedCheckpoint,
+        contents: CheckpointContents,
+    ) -> Result<(), SuiError> {
+        if checkpoint.content_digest != *contents.digest() {
+            return Err(SuiError::Storage(format!(
+                "Contents digest {} does not match content digest {} of checkpoint {}",
+                contents.digest(),
+                checkpoint.content_digest,
+                checkpoint.sequence_number(),
+            )));
+        }
+        self.insert_checkpoint_contents(contents);
+        self.insert_checkpoint(checkpoint);
+        Ok(())
+    }
+
+    /// Inserts `checkpoints` in a single write batch, which is much faster than calling
+    /// `insert_checkpoint` for each of them when seeding a long chain.
+    pub fn insert_checkpoints(&mut self, checkpoints: Vec<VerifiedCheckpoint>) {
+        let mut batch = self.read_write.checkpoints.batch();
+        batch
+            .insert_batch(
+                &self.read_write.checkpoint_digest_to_sequence_number,
+                checkpoints
+                    .iter()
+                    .map(|checkpoint| (*checkpoint.digest(), checkpoint.sequence_number())),
+            )
+            .expect("Fatal: DB write failed");
+        batch
+            .insert_batch(
+                &self.read_write.checkpoints,
+                checkpoints.iter().map(|checkpoint| {
+                    (checkpoint.sequence_number(), checkpoint.serializable_ref())
+                }),
+            )
+            .expect("Fatal: DB write failed");
+        batch.write().expect("Fatal: DB write failed");
+    }
+
+    /// Flushes the in-memory writes of all tables to disk.
+    pub fn flush(&self) -> Result<(), TypedStoreError> {
+        flush_tables(&self.read_write.checkpoints.rocksdb, &self.table_names())
+    }
+
+    /// Compacts all tables, which keeps reads fast after many writes.
+    pub fn compact(&self) {
+        compact_tables(&self.read_write.checkpoints.rocksdb, &self.table_names())
+    }
+
+    /// Spawns a task compacting all tables every `interval`, until the returned handle is aborted.
+    pub fn spawn_compaction_task(&self, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let rocksdb = self.read_write.checkpoints.rocksdb.clone();
+        let tables = Arc::new(self.table_names());
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(interval);
+            // The first tick completes immediately, and there is nothing to compact yet.
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                let rocksdb = rocksdb.clone();
+                let tables = tables.clone();
+                tokio::task::spawn_blocking(move || compact_tables(&rocksdb, &tables))
+                    .await
+                    .expect("Compaction of the persisted store panicked");
+            }
+        })
+    }
+
+    /// Names of all tables, which are also the names of their column families.
+    fn table_names(&self) -> Vec<String> {
+        self.read_write.describe_all_tables().into_keys().collect()
     }
 
     pub fn read_replica(&self) -> PersistedStoreInnerReadOnlyWrapper {
@@ -164,6 +484,59 @@ impl PersistedStore {
         )
         .0
     }
+
+    /// Create a simulator whose genesis funds `count` freshly generated accounts with a single
+    /// coin of `balance_per_account` each. Returns the simulator along with the addresses of the
+    /// funded accounts, whose keys are available through its keystore.
+    pub fn new_sim_with_funded_accounts<R>(
+        rng: R,
+        count: usize,
+        balance_per_account: u64,
+    ) -> (Simulacrum<R, Self>, Vec<SuiAddress>)
+    where
+        R: rand::RngCore + rand::CryptoRng,
+    {
+        let account_configs = (0..count)
+            .map(|_| AccountConfig {
+                address: None,
+                gas_amounts: vec![balance_per_account],
+            })
+            .collect();
+        let sim = Self::new_sim_with_protocol_version_and_accounts(
+            rng,
+            0,
+            ProtocolVersion::MAX,
+            account_configs,
+            None,
+        );
+        let addresses = sim
+            .keystore()
+            .accounts()
+            .map(|(address, _)| *address)
+            .collect();
+        (sim, addresses)
+    }
+}
+
+fn flush_tables(rocksdb: &RocksDB, tables: &[String]) -> Result<(), TypedStoreError> {
+    for table in tables {
+        let cf = rocksdb
+            .cf_handle(table)
+            .expect("Tables are created when the store is opened");
+        rocksdb
+            .flush_cf(&cf)
+            .map_err(|e| TypedStoreError::RocksDBError(e.into_string()))?;
+    }
+    Ok(())
+}
+
+fn compact_tables(rocksdb: &RocksDB, tables: &[String]) {
+    for table in tables {
+        let cf = rocksdb
+            .cf_handle(table)
+            .expect("Tables are created when the store is opened");
+        rocksdb.compact_range_cf(&cf, None::<Vec<u8>>, None::<Vec<u8>>);
+    }
 }
 
 impl SimulatorStore for PersistedStore {
@@ -238,20 +611,30 @@ impl SimulatorStore for PersistedStore {
             .expect("Fatal: DB read failed")
     }
 
+    /// Looks the events up through `events_tx_digest_index`. If the index entry is missing, falls
+    /// back to the events digest in the transaction's effects, and repairs the index entry when
+    /// the events are found that way.
     fn get_transaction_events_by_tx_digest(
         &self,
         tx_digest: &TransactionDigest,
     ) -> Option<TransactionEvents> {
-        self.read_write
+        if let Some(events_digest) = self
+            .read_write
             .events_tx_digest_index
             .get(tx_digest)
             .expect("Fatal: DB read failed")
-            .and_then(|x| {
-                self.read_write
-                    .events
-                    .get(&x)
-                    .expect("Fatal: DB read failed")
-            })
+        {
+            return self.get_transaction_events(&events_digest);
+        }
+
+        let effects = self.get_transaction_effects(tx_digest)?;
+        let events_digest = effects.events_digest()?;
+        let events = self.get_transaction_events(events_digest)?;
+        self.read_write
+            .events_tx_digest_index
+            .insert(tx_digest, events_digest)
+            .expect("Fatal: DB write failed");
+        Some(events)
     }
 
     fn get_object(&self, id: &ObjectID) -> Option<Object> {
@@ -361,6 +744,10 @@ impl SimulatorStore for PersistedStore {
     }
 
     fn insert_transaction_effects(&mut self, effects: TransactionEffects) {
+        self.read_write
+            .effects_digest_to_tx
+            .insert(&effects.digest(), effects.transaction_digest())
+            .expect("Fatal: DB write failed");
         self.read_write
             .effects
             .insert(effects.transaction_digest(), &effects)
@@ -733,6 +1120,13 @@ mod tests {
     use super::*;
     use rand::{rngs::StdRng, SeedableRng};
 
+    /// A simulator whose genesis funds a single account, which `request_gas` draws from.
+    fn funded_sim() -> Simulacrum<StdRng, PersistedStore> {
+        let rng = StdRng::from_seed([9; 32]);
+        let (sim, _) = PersistedStore::new_sim_with_funded_accounts(rng, 1, 100_000_000_000_000);
+        sim
+    }
+
     #[tokio::test]
     async fn deterministic_genesis() {
         let rng = StdRng::from_seed([9; 32]);
@@ -780,4 +1174,362 @@ mod tests {
             chain3.store().get_committee_by_epoch(0),
         );
     }
+
+    #[tokio::test]
+    async fn funded_accounts() {
+        let rng = StdRng::from_seed([9; 32]);
+        let balance = 1_000_000_000;
+        let (sim, addresses) = PersistedStore::new_sim_with_funded_accounts(rng, 3, balance);
+
+        assert_eq!(addresses.len(), 3);
+        for address in addresses {
+            let coins: Vec<_> = sim
+                .store()
+                .owned_objects(address)
+                .filter(|object| object.is_gas_coin())
+                .collect();
+            assert_eq!(coins.len(), 1);
+            assert_eq!(coins[0].get_coin_value_unsafe(), balance);
+        }
+    }
+
+    #[tokio::test]
+    async fn transaction_by_effects_digest() {
+        let mut sim = funded_sim();
+
+        let effects = sim
+            .request_gas(SuiAddress::random_for_testing_only(), 1_000)
+            .unwrap();
+        let transaction = sim
+            .store_inner()
+            .get_transaction_by_effects_digest(&effects.digest())
+            .unwrap();
+        assert_eq!(transaction.digest(), effects.transaction_digest());
+
+        assert!(sim
+            .store_inner()
+            .get_transaction_by_effects_digest(&TransactionEffectsDigest::random())
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn assemble_full_checkpoint() {
+        let mut sim = funded_sim();
+        let recipient = SuiAddress::random_for_testing_only();
+        let effects = sim.request_gas(recipient, 1_000).unwrap();
+        let checkpoint = sim.create_checkpoint();
+
+        let store = sim.store_inner();
+        let full_checkpoint = store
+            .assemble_full_checkpoint(checkpoint.sequence_number)
+            .unwrap();
+        assert_eq!(
+            full_checkpoint.checkpoint_summary.digest(),
+            checkpoint.digest()
+        );
+        assert_eq!(
+            full_checkpoint.transactions.len(),
+            full_checkpoint.checkpoint_contents.size()
+        );
+
+        for transaction in &full_checkpoint.transactions {
+            let tx_digest = transaction.transaction.digest();
+            assert_eq!(transaction.effects.transaction_digest(), tx_digest);
+            assert_eq!(
+                Some(&transaction.effects),
+                store.get_transaction_effects(tx_digest).as_ref()
+            );
+            assert_eq!(
+                transaction.events,
+                store.get_transaction_events_by_tx_digest(tx_digest)
+            );
+        }
+
+        let gas_transaction = full_checkpoint
+            .transactions
+            .iter()
+            .find(|transaction| transaction.transaction.digest() == effects.transaction_digest())
+            .unwrap();
+        assert_eq!(gas_transaction.effects, effects);
+        assert!(gas_transaction
+            .output_objects
+            .iter()
+            .any(|object| object.owner == Owner::AddressOwner(recipient)));
+
+        assert!(store
+            .assemble_full_checkpoint(checkpoint.sequence_number + 1)
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn fork_at_checkpoint() {
+        let mut sim = funded_sim();
+        let recipient = SuiAddress::random_for_testing_only();
+
+        sim.request_gas(recipient, 1_000).unwrap();
+        let checkpoint = sim.create_checkpoint();
+        let (coin_id, forked_version, _) = sim
+            .store()
+            .owned_objects(recipient)
+            .next()
+            .unwrap()
+            .compute_object_reference();
+
+        // Mutate the faucet coin, and create more coins after the fork point.
+        let (faucet, _) = sim.keystore().accounts().next().unwrap();
+        let faucet = *faucet;
+        let faucet_coin = sim
+            .store()
+            .owned_objects(faucet)
+            .find(|object| object.is_gas_coin())
+            .unwrap();
+        let faucet_coin_ref = faucet_coin.compute_object_reference();
+        sim.request_gas(recipient, 2_000).unwrap();
+        sim.create_checkpoint();
+
+        let fork = sim
+            .store_inner()
+            .fork_at_checkpoint(checkpoint.sequence_number);
+
+        assert_eq!(
+            fork.get_highest_checkpint().unwrap().digest(),
+            checkpoint.digest()
+        );
+        assert_eq!(
+            SimulatorStore::get_object(&fork, &coin_id)
+                .unwrap()
+                .version(),
+            forked_version
+        );
+        assert_eq!(
+            SimulatorStore::get_object(&fork, &faucet_coin_ref.0)
+                .unwrap()
+                .compute_object_reference(),
+            faucet_coin_ref,
+        );
+        assert_ne!(
+            SimulatorStore::get_object(sim.store_inner(), &faucet_coin_ref.0)
+                .unwrap()
+                .compute_object_reference(),
+            faucet_coin_ref,
+        );
+        assert_eq!(fork.owned_objects(recipient).count(), 1);
+        assert_eq!(sim.store().owned_objects(recipient).count(), 2);
+    }
+
+    #[tokio::test]
+    async fn insert_checkpoint_with_contents() {
+        let mut sim = funded_sim();
+        let recipient = SuiAddress::random_for_testing_only();
+
+        sim.request_gas(recipient, 1_000).unwrap();
+        let checkpoint1 = sim.create_checkpoint();
+        sim.request_gas(recipient, 2_000).unwrap();
+        let checkpoint2 = sim.create_checkpoint();
+        let contents1 = sim
+            .store()
+            .get_checkpoint_contents(&checkpoint1.content_digest)
+            .unwrap();
+        let contents2 = sim
+            .store()
+            .get_checkpoint_contents(&checkpoint2.content_digest)
+            .unwrap();
+
+        let mut store = sim.store_inner().fork_at_checkpoint(0);
+
+        // A checkpoint paired with another checkpoint's contents is rejected.
+        assert!(matches!(
+            store.insert_checkpoint_with_contents(checkpoint1.clone(), contents2),
+            Err(SuiError::Storage(_))
+        ));
+        assert_eq!(store.get_highest_checkpint().unwrap().sequence_number, 0);
+        assert!(store
+            .get_checkpoint_contents(&checkpoint1.content_digest)
+            .is_none());
+
+        store
+            .insert_checkpoint_with_contents(checkpoint1.clone(), contents1.clone())
+            .unwrap();
+        assert_eq!(
+            store.get_highest_checkpint().unwrap().digest(),
+            checkpoint1.digest()
+        );
+        assert_eq!(
+            store.get_checkpoint_contents(&checkpoint1.content_digest),
+            Some(contents1)
+        );
+    }
+
+    #[tokio::test]
+    async fn insert_checkpoints_in_batch() {
+        let rng = StdRng::from_seed([9; 32]);
+        let mut sim = PersistedStore::new_sim_with_protocol_version_and_accounts(
+            rng,
+            0,
+            ProtocolVersion::MAX,
+            vec![],
+            None,
+        );
+        let checkpoints: Vec<_> = (0..100).map(|_| sim.create_checkpoint()).collect();
+
+        let mut store = PersistedStore::open(tempdir().unwrap().into_path());
+        store.insert_checkpoints(checkpoints.clone());
+
+        for checkpoint in &checkpoints {
+            assert_eq!(
+                store
+                    .get_checkpoint_by_sequence_number(checkpoint.sequence_number())
+                    .unwrap()
+                    .digest(),
+                checkpoint.digest()
+            );
+            assert_eq!(
+                store
+                    .get_checkpoint_by_digest(checkpoint.digest())
+                    .unwrap()
+                    .sequence_number(),
+                checkpoint.sequence_number()
+            );
+        }
+        assert_eq!(
+            store.get_highest_checkpint().unwrap().digest(),
+            checkpoints.last().unwrap().digest()
+        );
+    }
+
+    #[tokio::test]
+    async fn flush_and_compact() {
+        let rng = StdRng::from_seed([9; 32]);
+        let mut sim = PersistedStore::new_sim_with_protocol_version_and_accounts(
+            rng,
+            0,
+            ProtocolVersion::MAX,
+            vec![],
+            None,
+        );
+        let checkpoints: Vec<_> = (0..50).map(|_| sim.create_checkpoint()).collect();
+
+        let mut store = PersistedStore::open(tempdir().unwrap().into_path());
+        for round in 0..5 {
+            // Overwrite the same entries repeatedly, leaving garbage for compaction to remove.
+            for checkpoint in &checkpoints {
+                store.insert_checkpoint(checkpoint.clone());
+            }
+            if round % 2 == 0 {
+                store.flush().unwrap();
+            }
+        }
+
+        store.flush().unwrap();
+        store.compact();
+        let compaction_task = store.spawn_compaction_task(Duration::from_millis(10));
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        compaction_task.abort();
+
+        for checkpoint in &checkpoints {
+            assert_eq!(
+                store
+                    .get_checkpoint_by_digest(checkpoint.digest())
+                    .unwrap()
+                    .sequence_number(),
+                checkpoint.sequence_number()
+            );
+        }
+        assert_eq!(
+            store.get_highest_checkpint().unwrap().digest(),
+            checkpoints.last().unwrap().digest()
+        );
+    }
+
+    #[tokio::test]
+    async fn events_by_tx_digest_without_index_entry() {
+        let mut sim = funded_sim();
+        let effects = sim
+            .request_gas(SuiAddress::random_for_testing_only(), 1_000)
+            .unwrap();
+        let transaction = sim
+            .store()
+            .get_transaction(effects.transaction_digest())
+            .unwrap();
+
+        let mut store = PersistedStore::open(tempdir().unwrap().into_path());
+        let events = TransactionEvents {
+            data: vec![sui_types::event::Event::random_for_testing()],
+        };
+        let effects = sui_types::effects::TestEffectsBuilder::new(transaction.data())
+            .with_events_digest(events.digest())
+            .build();
+        let tx_digest = *effects.transaction_digest();
+        store.insert_transaction_effects(effects);
+        store.insert_events(&tx_digest, events.clone());
+
+        // Lose the index entry, but not the events themselves.
+        store
+            .read_write
+            .events_tx_digest_index
+            .remove(&tx_digest)
+            .unwrap();
+
+        assert_eq!(
+            store.get_transaction_events_by_tx_digest(&tx_digest),
+            Some(events.clone())
+        );
+        // The index entry has been repaired.
+        assert_eq!(
+            store
+                .read_write
+                .events_tx_digest_index
+                .get(&tx_digest)
+                .unwrap(),
+            Some(events.digest())
+        );
+
+        assert!(store
+            .get_transaction_events_by_tx_digest(&TransactionDigest::random())
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn available_epochs() {
+        let mut store = PersistedStore::open(tempdir().unwrap().into_path());
+        assert!(store.available_epochs().is_empty());
+
+        let (committee, _) = Committee::new_simple_test_committee_of_size(4);
+        for epoch in 0..3 {
+            let mut committee = committee.clone();
+            committee.epoch = epoch;
+            store.insert_committee(committee);
+        }
+        assert_eq!(store.available_epochs(), vec![0, 1, 2]);
+
+        // Re-inserting a committee for a known epoch does not add an epoch.
+        store.insert_committee(committee);
+        assert_eq!(store.available_epochs(), vec![0, 1, 2]);
+    }
+
+    #[tokio::test]
+    async fn check_invariants() {
+        let mut sim = funded_sim();
+        let effects = sim
+            .request_gas(SuiAddress::random_for_testing_only(), 1_000)
+            .unwrap();
+        sim.create_checkpoint();
+        assert_eq!(sim.store_inner().check_invariants(), Ok(()));
+
+        // Point an object created by the transaction at a version that was never stored.
+        let (object_id, version, _) = effects.created()[0].0;
+        let missing_version = version.next();
+        sim.store_inner()
+            .read_write
+            .live_objects
+            .insert(&object_id, &missing_version)
+            .unwrap();
+        assert_eq!(
+            sim.store_inner().check_invariants(),
+            Err(InvariantViolation::MissingLiveObjectVersion {
+                object_id,
+                version: missing_version,
+            })
+        );
+    }
 }
@@ -3,9 +3,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::base_types::*;
-use crate::crypto::{random_committee_key_pairs_of_size, AuthorityKeyPair, AuthorityPublicKey};
+use crate::crypto::{
+    random_committee_key_pairs_of_size, AuthorityKeyPair, AuthorityPublicKey, DefaultHash,
+};
 use crate::error::{SuiError, SuiResult};
 use crate::multiaddr::Multiaddr;
+use fastcrypto::hash::HashFunction;
 use fastcrypto::traits::KeyPair;
 use rand::rngs::ThreadRng;
 use rand::seq::SliceRandom;
@@ -72,6 +75,39 @@ impl Committee {
         }
     }
 
+    /// Like `new`, but returns `SuiError::InvalidNextCommittee` instead of panicking when
+    /// `voting_rights` do not form a valid committee, e.g. when they are the next epoch committee
+    /// of an end of epoch checkpoint read from an untrusted or corrupted source.
+    pub fn new_checked(
+        epoch: EpochId,
+        voting_rights: BTreeMap<AuthorityName, StakeUnit>,
+    ) -> SuiResult<Self> {
+        if voting_rights.is_empty() {
+            return Err(SuiError::InvalidNextCommittee(format!(
+                "committee for epoch {epoch} has no members"
+            )));
+        }
+        let total_votes = voting_rights
+            .values()
+            .try_fold(0 as StakeUnit, |total, votes| total.checked_add(*votes));
+        if total_votes != Some(TOTAL_VOTING_POWER) {
+            return Err(SuiError::InvalidNextCommittee(format!(
+                "committee for epoch {epoch} has total voting power {total_votes:?}, expected {TOTAL_VOTING_POWER}"
+            )));
+        }
+        // `load_inner` expects every authority name to be a valid public key.
+        if let Some(name) = voting_rights
+            .keys()
+            .find(|name| AuthorityPublicKey::try_from(**name).is_err())
+        {
+            return Err(SuiError::InvalidNextCommittee(format!(
+                "committee for epoch {epoch} has an invalid authority key {}",
+                name.concise()
+            )));
+        }
+        Ok(Self::new(epoch, voting_rights))
+    }
+
     /// Normalize the given weights to TOTAL_VOTING_POWER and create the committee.
     /// Used for testing only: a production system is using the voting weights
     /// of the Sui System object.
@@ -136,6 +172,21 @@ impl Committee {
         self.epoch
     }
 
+    /// Canonical digest of the epoch and the `(authority_name, weight)` pairs sorted by name,
+    /// so that nodes can cheaply confirm they agree on the committee for an epoch.
+    pub fn digest(&self) -> CommitteeDigest {
+        let mut voting_rights = self.voting_rights.clone();
+        voting_rights.sort_by_key(|(name, _)| *name);
+
+        let mut hasher = DefaultHash::default();
+        hasher.update(self.epoch.to_le_bytes());
+        for (name, weight) in &voting_rights {
+            hasher.update(name);
+            hasher.update(weight.to_le_bytes());
+        }
+        hasher.finalize().digest
+    }
+
     pub fn public_key(&self, authority: &AuthorityName) -> SuiResult<&AuthorityPublicKey> {
         debug_assert_eq!(self.expanded_keys.len(), self.voting_rights.len());
         match self.expanded_keys.get(authority) {
@@ -373,6 +424,82 @@ mod test {
     use crate::crypto::{get_key_pair, AuthorityKeyPair};
     use fastcrypto::traits::KeyPair;
 
+    #[test]
+    fn test_new_checked() {
+        assert!(matches!(
+            Committee::new_checked(1, BTreeMap::new()),
+            Err(SuiError::InvalidNextCommittee(_))
+        ));
+
+        let (_, sec1): (_, AuthorityKeyPair) = get_key_pair();
+        let (_, sec2): (_, AuthorityKeyPair) = get_key_pair();
+        let a1: AuthorityName = sec1.public().into();
+        let a2: AuthorityName = sec2.public().into();
+
+        let unnormalized = BTreeMap::from([(a1, 1), (a2, 1)]);
+        assert!(matches!(
+            Committee::new_checked(1, unnormalized),
+            Err(SuiError::InvalidNextCommittee(_))
+        ));
+
+        let overflowing = BTreeMap::from([(a1, StakeUnit::MAX), (a2, TOTAL_VOTING_POWER)]);
+        assert!(matches!(
+            Committee::new_checked(1, overflowing),
+            Err(SuiError::InvalidNextCommittee(_))
+        ));
+
+        let invalid_key = BTreeMap::from([
+            (a1, TOTAL_VOTING_POWER / 2),
+            (AuthorityName::ZERO, TOTAL_VOTING_POWER / 2),
+        ]);
+        assert!(matches!(
+            Committee::new_checked(1, invalid_key),
+            Err(SuiError::InvalidNextCommittee(_))
+        ));
+
+        let valid = BTreeMap::from([(a1, TOTAL_VOTING_POWER / 2), (a2, TOTAL_VOTING_POWER / 2)]);
+        let committee = Committee::new_checked(1, valid).unwrap();
+        assert_eq!(committee.epoch, 1);
+        assert_eq!(committee.num_members(), 2);
+    }
+
+    #[test]
+    fn test_digest() {
+        let names: Vec<AuthorityName> = (0..4)
+            .map(|_| {
+                let (_, sec): (_, AuthorityKeyPair) = get_key_pair();
+                sec.public().into()
+            })
+            .collect();
+
+        let mut forward = BTreeMap::new();
+        for (i, name) in names.iter().enumerate() {
+            forward.insert(*name, i as StakeUnit + 1);
+        }
+        let mut backward = BTreeMap::new();
+        for (i, name) in names.iter().enumerate().rev() {
+            backward.insert(*name, i as StakeUnit + 1);
+        }
+
+        let committee = Committee::new_for_testing_with_normalized_voting_power(1, forward);
+        let same = Committee::new_for_testing_with_normalized_voting_power(1, backward);
+        assert_eq!(committee.digest(), same.digest());
+
+        // The digest does not depend on the order of the voting rights.
+        let mut reordered = committee.clone();
+        reordered.voting_rights.reverse();
+        assert_eq!(committee.digest(), reordered.digest());
+
+        let mut next_epoch = committee.clone();
+        next_epoch.epoch = 2;
+        assert_ne!(committee.digest(), next_epoch.digest());
+
+        let mut reweighted = committee.clone();
+        reweighted.voting_rights[0].1 += 1;
+        reweighted.voting_rights[1].1 -= 1;
+        assert_ne!(committee.digest(), reweighted.digest());
+    }
+
     #[test]
     fn test_shuffle_by_weight() {
         let (_, sec1): (_, AuthorityKeyPair) = get_key_pair();
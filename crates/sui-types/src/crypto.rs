@@ -544,10 +544,7 @@ impl SuiAuthoritySignature for AuthoritySignature {
     where
         T: Serialize,
     {
-        let mut intent_msg_bytes =
-            bcs::to_bytes(&value).expect("Message serialization should not fail");
-        epoch.write(&mut intent_msg_bytes);
-        secret.sign(&intent_msg_bytes)
+        secret.sign(&authority_signing_bytes(value, *epoch))
     }
 
     #[instrument(level = "trace", skip_all)]
@@ -560,8 +557,7 @@ impl SuiAuthoritySignature for AuthoritySignature {
     where
         T: Serialize,
     {
-        let mut message = bcs::to_bytes(&value).expect("Message serialization should not fail");
-        epoch.write(&mut message);
+        let message = authority_signing_bytes(value, epoch);
 
         let public_key = AuthorityPublicKey::try_from(author).map_err(|_| {
             SuiError::KeyConversionError(
@@ -581,6 +577,14 @@ impl SuiAuthoritySignature for AuthoritySignature {
     }
 }
 
+/// The exact bytes signed by an `AuthoritySignature` over `value` in `epoch`: the BCS serialized
+/// intent message, followed by the BCS serialized epoch.
+pub fn authority_signing_bytes<T: Serialize>(value: &IntentMessage<T>, epoch: EpochId) -> Vec<u8> {
+    let mut bytes = bcs::to_bytes(value).expect("Message serialization should not fail");
+    epoch.write(&mut bytes);
+    bytes
+}
+
 // TODO: get_key_pair() and get_key_pair_from_bytes() should return KeyPair only.
 // TODO: rename to random_key_pair
 pub fn get_key_pair<KP: KeypairTraits>() -> (SuiAddress, KP)
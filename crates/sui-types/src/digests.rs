@@ -486,6 +486,25 @@ impl fmt::Debug for SenderSignedDataDigest {
     }
 }
 
+/// A digest of a SignedTransaction, which commits to the authority signature as well as the
+/// sender signed data.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SignedTransactionDigest(Digest);
+
+impl SignedTransactionDigest {
+    pub const fn new(digest: [u8; 32]) -> Self {
+        Self(Digest::new(digest))
+    }
+}
+
+impl fmt::Debug for SignedTransactionDigest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SignedTransactionDigest")
+            .field(&self.0)
+            .finish()
+    }
+}
+
 /// A transaction will have a (unique) digest.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct TransactionDigest(Digest);
@@ -5,8 +5,8 @@ use self::effects_v2::TransactionEffectsV2;
 use crate::base_types::{ExecutionDigests, ObjectID, ObjectRef, SequenceNumber};
 use crate::committee::{Committee, EpochId};
 use crate::crypto::{
-    default_hash, AuthoritySignInfo, AuthoritySignInfoTrait, AuthorityStrongQuorumSignInfo,
-    EmptySignInfo,
+    authority_signing_bytes, default_hash, AuthoritySignInfo, AuthoritySignInfoTrait,
+    AuthorityStrongQuorumSignInfo, EmptySignInfo,
 };
 use crate::digests::{
     ObjectDigest, TransactionDigest, TransactionEffectsDigest, TransactionEventsDigest,
@@ -25,7 +25,7 @@ pub use effects_v2::UnchangedSharedKind;
 use enum_dispatch::enum_dispatch;
 pub use object_change::{EffectsObjectChange, ObjectIn, ObjectOut};
 use serde::{Deserialize, Serialize};
-use shared_crypto::intent::{Intent, IntentScope};
+use shared_crypto::intent::{Intent, IntentMessage, IntentScope};
 use std::collections::BTreeMap;
 use sui_protocol_config::ProtocolConfig;
 pub use test_effects_builder::TestEffectsBuilder;
@@ -248,6 +248,23 @@ impl TransactionEffects {
             .collect()
     }
 
+    /// Return mutated and created objects sorted by object ID, so that the result does not
+    /// depend on the order in which the effects store them.
+    pub fn sorted_mutated_and_created(&self) -> Vec<(ObjectRef, Owner)> {
+        let mut objects: Vec<_> = self.mutated().into_iter().chain(self.created()).collect();
+        objects.sort_by_key(|(obj_ref, _)| obj_ref.0);
+        objects
+    }
+
+    /// The bytes an authority signs to produce the signature of a `SignedTransactionEffects` for
+    /// these effects in `epoch`, so that the signature can be verified independently.
+    pub fn signing_bytes(&self, epoch: EpochId) -> Vec<u8> {
+        authority_signing_bytes(
+            &IntentMessage::new(Intent::sui_app(Self::SCOPE), self),
+            epoch,
+        )
+    }
+
     /// Return all objects that existed in the state prior to the transaction
     /// but no longer exist in the state after the transaction.
     /// It includes deleted and wrapped objects, but does not include unwrapped_then_deleted objects.
@@ -257,6 +257,12 @@ pub enum UserInputError {
 
     #[error("Commands following a command with Random can only be TransferObjects or MergeCoins")]
     PostRandomCommandRestrictions,
+
+    #[error("Gas object {object_id} cannot also be used as a transaction input")]
+    GasObjectUsedAsArgument { object_id: ObjectID },
+
+    #[error("Transaction data is missing its {field}")]
+    MissingTransactionDataField { field: String },
 }
 
 #[derive(
@@ -627,6 +633,20 @@ pub enum SuiError {
 
     #[error("Too many requests")]
     TooManyRequests,
+
+    #[error("Type arguments are too complex: {nodes} type nodes (maximum {max_nodes}), nested {depth} deep (maximum {max_depth})")]
+    TypeArgumentTooComplex {
+        nodes: u64,
+        max_nodes: u64,
+        depth: u32,
+        max_depth: u32,
+    },
+
+    #[error("Shared object {id} is listed more than once in the transaction inputs")]
+    DuplicateSharedObjectInput { id: ObjectID },
+
+    #[error("Invalid next epoch committee: {0}")]
+    InvalidNextCommittee(String),
 }
 
 #[repr(u64)]
@@ -790,6 +810,8 @@ impl SuiError {
             SuiError::TxAlreadyFinalizedWithDifferentUserSigs => false,
             SuiError::FailedToVerifyTxCertWithExecutedEffects { .. } => false,
             SuiError::ObjectLockConflict { .. } => false,
+            SuiError::TypeArgumentTooComplex { .. } => false,
+            SuiError::DuplicateSharedObjectInput { .. } => false,
 
             // NB: This is not an internal overload, but instead an imposed rate
             // limit / blocking of a client. It must be non-retryable otherwise
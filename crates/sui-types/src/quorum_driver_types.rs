@@ -19,6 +19,25 @@ use serde::{Deserialize, Serialize};
 use strum::AsRefStr;
 use thiserror::Error;
 
+/// Renders validator responses as the number of validators that signed, followed by each distinct
+/// error with the number of validators that returned it, so the message stays short for large
+/// committees.
+fn summarize_validator_responses(responses: &[(AuthorityName, Result<(), SuiError>)]) -> String {
+    let mut signed = 0;
+    let mut errors: BTreeMap<String, usize> = BTreeMap::new();
+    for (_, response) in responses {
+        match response {
+            Ok(()) => signed += 1,
+            Err(e) => *errors.entry(e.to_string()).or_default() += 1,
+        }
+    }
+    let mut summary = format!("{signed} of {} validators signed", responses.len());
+    for (error, count) in errors {
+        summary.push_str(&format!("; {count} returned: {error}"));
+    }
+    summary
+}
+
 pub type QuorumDriverResult = Result<QuorumDriverResponse, QuorumDriverError>;
 
 pub type QuorumDriverEffectsQueueResult =
@@ -50,6 +69,17 @@ pub enum QuorumDriverError {
     TimeoutBeforeFinality,
     #[error("Transaction failed to reach finality with transient error after {total_attempts} attempts.")]
     FailedWithTransientErrorAfterMaximumAttempts { total_attempts: u32 },
+    /// All validators were heard from without collecting a quorum of signatures, and retries were
+    /// exhausted. `responses` holds the outcome of the last attempt for each validator.
+    #[error(
+        "Transaction failed to reach a quorum of validators after {total_attempts} attempts, collected {collected_stake} stake. {}.",
+        summarize_validator_responses(responses)
+    )]
+    QuorumNotReached {
+        total_attempts: u32,
+        collected_stake: StakeUnit,
+        responses: Vec<(AuthorityName, Result<(), SuiError>)>,
+    },
     #[error("{NON_RECOVERABLE_ERROR_MSG}: {errors:?}.")]
     NonRecoverableTransactionError { errors: GroupedErrors },
     #[error("Transaction is not processed because {overloaded_stake} of validators by stake are overloaded with certificates pending execution.")]
@@ -119,8 +149,51 @@ pub struct QuorumDriverResponse {
     // Output objects will only be populated in the happy path
     pub output_objects: Option<Vec<Object>>,
     pub auxiliary_data: Option<Vec<u8>>,
+    /// Signers of the certificate the transaction was executed with. Only populated when the
+    /// effects were obtained by processing a certificate, not when validators returned effects
+    /// for an already executed transaction directly.
+    pub certificate_signers: Option<CertificateSigners>,
+}
+
+/// The authorities that signed a transaction certificate, and their total voting power.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CertificateSigners {
+    pub signers: Vec<AuthorityName>,
+    pub signed_stake: StakeUnit,
 }
 
+/// A request to execute `transaction`, usually built with one of the constructors for each
+/// `ExecuteTransactionRequestType`:
+///
+/// ```
+/// # use sui_types::base_types::random_object_ref;
+/// # use sui_types::crypto::get_account_key_pair;
+/// # use sui_types::quorum_driver_types::{ExecuteTransactionRequest, ExecuteTransactionRequestType};
+/// # use sui_types::transaction::{Transaction, TransactionData};
+/// # let (sender, keypair) = get_account_key_pair();
+/// # let data = TransactionData::new_transfer_sui(sender, sender, None, random_object_ref(), 1_000_000, 1_000);
+/// # let transaction = Transaction::from_data_and_signer(data, vec![&keypair]);
+/// let request = ExecuteTransactionRequest::wait_for_effects_cert(transaction.clone());
+/// assert!(matches!(
+///     request.request_type,
+///     ExecuteTransactionRequestType::WaitForEffectsCert
+/// ));
+///
+/// let request = ExecuteTransactionRequest::wait_for_local_execution(transaction.clone());
+/// assert!(matches!(
+///     request.request_type,
+///     ExecuteTransactionRequestType::WaitForLocalExecution
+/// ));
+///
+/// let request = ExecuteTransactionRequest::new(
+///     transaction,
+///     ExecuteTransactionRequestType::WaitForEffectsCert,
+/// );
+/// assert!(matches!(
+///     request.request_type,
+///     ExecuteTransactionRequestType::WaitForEffectsCert
+/// ));
+/// ```
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ExecuteTransactionRequest {
     pub transaction: Transaction,
@@ -128,6 +201,32 @@ pub struct ExecuteTransactionRequest {
 }
 
 impl ExecuteTransactionRequest {
+    pub fn new<T: Into<Transaction>>(
+        transaction: T,
+        request_type: ExecuteTransactionRequestType,
+    ) -> Self {
+        Self {
+            transaction: transaction.into(),
+            request_type,
+        }
+    }
+
+    /// Request that returns once the transaction's effects are certified.
+    pub fn wait_for_effects_cert<T: Into<Transaction>>(transaction: T) -> Self {
+        Self::new(
+            transaction,
+            ExecuteTransactionRequestType::WaitForEffectsCert,
+        )
+    }
+
+    /// Request that returns once the transaction has also been executed locally by the node.
+    pub fn wait_for_local_execution<T: Into<Transaction>>(transaction: T) -> Self {
+        Self::new(
+            transaction,
+            ExecuteTransactionRequestType::WaitForLocalExecution,
+        )
+    }
+
     pub fn transaction_type(&self) -> TransactionType {
         if self.transaction.contains_shared_object() {
             TransactionType::SharedObject
@@ -3,7 +3,7 @@
 
 use super::error::Result;
 use super::ObjectStore;
-use crate::base_types::EpochId;
+use crate::base_types::{EpochId, ObjectID, ObjectInfo, SuiAddress};
 use crate::committee::Committee;
 use crate::digests::{
     CheckpointContentsDigest, CheckpointDigest, TransactionDigest, TransactionEventsDigest,
@@ -145,6 +145,22 @@ pub trait ReadStore: ObjectStore {
         digest: &CheckpointContentsDigest,
     ) -> Result<Option<FullCheckpointContents>>;
 
+    /// Get the live objects owned by `owner`, in increasing object id order, starting after
+    /// `cursor` if one is given. Objects are read lazily as the iterator advances.
+    ///
+    /// Stores that do not index objects by owner return a `Missing` error, as the index is
+    /// missing rather than the store being unhealthy.
+    fn get_owned_objects(
+        &self,
+        owner: SuiAddress,
+        cursor: Option<ObjectID>,
+    ) -> Result<Box<dyn Iterator<Item = ObjectInfo> + '_>> {
+        let _ = (owner, cursor);
+        Err(super::error::Error::missing(
+            "objects are not indexed by owner in this store",
+        ))
+    }
+
     // Fetch all checkpoint data
     // TODO fix return type to not be anyhow
     fn get_checkpoint_data(
@@ -400,6 +416,14 @@ impl<T: ReadStore + ?Sized> ReadStore for &T {
     ) -> anyhow::Result<CheckpointData> {
         (*self).get_checkpoint_data(checkpoint, checkpoint_contents)
     }
+
+    fn get_owned_objects(
+        &self,
+        owner: SuiAddress,
+        cursor: Option<ObjectID>,
+    ) -> Result<Box<dyn Iterator<Item = ObjectInfo> + '_>> {
+        (*self).get_owned_objects(owner, cursor)
+    }
 }
 
 impl<T: ReadStore + ?Sized> ReadStore for Box<T> {
@@ -522,6 +546,14 @@ impl<T: ReadStore + ?Sized> ReadStore for Box<T> {
     ) -> anyhow::Result<CheckpointData> {
         (**self).get_checkpoint_data(checkpoint, checkpoint_contents)
     }
+
+    fn get_owned_objects(
+        &self,
+        owner: SuiAddress,
+        cursor: Option<ObjectID>,
+    ) -> Result<Box<dyn Iterator<Item = ObjectInfo> + '_>> {
+        (**self).get_owned_objects(owner, cursor)
+    }
 }
 
 impl<T: ReadStore + ?Sized> ReadStore for Arc<T> {
@@ -644,4 +676,12 @@ impl<T: ReadStore + ?Sized> ReadStore for Arc<T> {
     ) -> anyhow::Result<CheckpointData> {
         (**self).get_checkpoint_data(checkpoint, checkpoint_contents)
     }
+
+    fn get_owned_objects(
+        &self,
+        owner: SuiAddress,
+        cursor: Option<ObjectID>,
+    ) -> Result<Box<dyn Iterator<Item = ObjectInfo> + '_>> {
+        (**self).get_owned_objects(owner, cursor)
+    }
 }
@@ -7,6 +7,7 @@ use crate::base_types::{EpochId, TransactionDigest};
 use crate::committee::Committee;
 use crate::digests::{CheckpointContentsDigest, CheckpointDigest, TransactionEventsDigest};
 use crate::effects::{TransactionEffects, TransactionEvents};
+use crate::error::SuiResult;
 use crate::messages_checkpoint::{
     CheckpointContents, CheckpointSequenceNumber, FullCheckpointContents, VerifiedCheckpoint,
     VerifiedCheckpointContents,
@@ -179,6 +180,9 @@ impl ObjectStore for SharedInMemoryStore {
 
 impl WriteStore for SharedInMemoryStore {
     fn insert_checkpoint(&self, checkpoint: &VerifiedCheckpoint) -> Result<()> {
+        // The in-memory store only logs an invalid next epoch committee, so reject it here,
+        // before anything is inserted.
+        next_epoch_committee(checkpoint).map_err(super::error::Error::custom)?;
         self.inner_mut().insert_checkpoint(checkpoint);
         Ok(())
     }
@@ -217,6 +221,19 @@ impl SharedInMemoryStore {
     }
 }
 
+/// The committee of the next epoch, if `checkpoint` is the last checkpoint of its epoch.
+fn next_epoch_committee(checkpoint: &VerifiedCheckpoint) -> SuiResult<Option<Committee>> {
+    let Some(end_of_epoch_data) = &checkpoint.data().end_of_epoch_data else {
+        return Ok(None);
+    };
+    let next_committee = end_of_epoch_data
+        .next_epoch_committee
+        .iter()
+        .cloned()
+        .collect();
+    Committee::new_checked(checkpoint.epoch().checked_add(1).unwrap(), next_committee).map(Some)
+}
+
 #[derive(Debug, Default)]
 pub struct InMemoryStore {
     highest_verified_checkpoint: Option<(CheckpointSequenceNumber, CheckpointDigest)>,
@@ -338,15 +355,13 @@ impl InMemoryStore {
         let digest = *checkpoint.digest();
         let sequence_number = *checkpoint.sequence_number();
 
-        if let Some(end_of_epoch_data) = &checkpoint.data().end_of_epoch_data {
-            let next_committee = end_of_epoch_data
-                .next_epoch_committee
-                .iter()
-                .cloned()
-                .collect();
-            let committee =
-                Committee::new(checkpoint.epoch().checked_add(1).unwrap(), next_committee);
-            self.insert_committee(committee);
+        match next_epoch_committee(checkpoint) {
+            Ok(Some(committee)) => self.insert_committee(committee),
+            Ok(None) => {}
+            Err(e) => error!(
+                checkpoint = sequence_number,
+                "Invalid next epoch committee in end of epoch checkpoint: {e}"
+            ),
         }
 
         self.checkpoints.insert(digest, checkpoint.clone());
@@ -79,6 +79,15 @@ fn default_drain_timeout() -> u64 {
     TRAFFIC_SINK_TIMEOUT_SEC
 }
 
+/// Number of bits of each counter in a traffic sketch. 16 bit counters halve the memory used by
+/// the sketch, but only reliably count up to ~65,000 tallies per key in an update interval.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub enum SketchWidth {
+    Bits16,
+    #[default]
+    Bits32,
+}
+
 #[serde_as]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
@@ -97,6 +106,13 @@ pub struct FreqThresholdConfig {
     pub sketch_probability: f64,
     #[serde(default = "default_sketch_tolerance")]
     pub sketch_tolerance: f64,
+    #[serde(default)]
+    pub sketch_width: SketchWidth,
+    /// Time after policy creation during which traffic is recorded but never
+    /// blocked, so that request rates are not estimated from a partially
+    /// filled window.
+    #[serde(default)]
+    pub warmup_window_secs: u64,
 }
 
 impl Default for FreqThresholdConfig {
@@ -109,6 +125,8 @@ impl Default for FreqThresholdConfig {
             sketch_capacity: default_sketch_capacity(),
             sketch_probability: default_sketch_probability(),
             sketch_tolerance: default_sketch_tolerance(),
+            sketch_width: SketchWidth::default(),
+            warmup_window_secs: 0,
         }
     }
 }
@@ -159,6 +177,14 @@ pub enum PolicyType {
     /// with granularity of `update_interval_secs`
     FreqThreshold(FreqThresholdConfig),
 
+    /// Like `FreqThreshold`, but tallies carrying a proxy_ip (the client IP forwarded
+    /// by a proxy) are counted per proxy_ip and compared against `proxy_threshold`.
+    /// When a client exceeds it, only its proxy_ip is blocked, so that an abusive
+    /// client behind a shared proxy is isolated without blocking the proxy's
+    /// connection_ip. Tallies without a proxy_ip are counted per connection_ip against
+    /// `connection_threshold`, and block the connection_ip.
+    ProxyAwareFreqThreshold(FreqThresholdConfig),
+
     /* Below this point are test policies, and thus should not be used in production */
     ///
     /// Simple policy that adds connection_ip to blocklist when the same connection_ip
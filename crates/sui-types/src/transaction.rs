@@ -8,9 +8,9 @@ use crate::committee::{Committee, EpochId, ProtocolVersion};
 use crate::crypto::{
     default_hash, AuthoritySignInfo, AuthoritySignInfoTrait, AuthoritySignature,
     AuthorityStrongQuorumSignInfo, DefaultHash, Ed25519SuiSignature, EmptySignInfo,
-    RandomnessRound, Signature, Signer, SuiSignatureInner, ToFromBytes,
+    RandomnessRound, Signature, Signer, SuiSignatureInner, ToFromBytes, VerificationObligation,
 };
-use crate::digests::{CertificateDigest, SenderSignedDataDigest};
+use crate::digests::{CertificateDigest, SenderSignedDataDigest, SignedTransactionDigest};
 use crate::digests::{ChainIdentifier, ConsensusCommitDigest, ZKLoginInputsDigest};
 use crate::execution::SharedInput;
 use crate::message_envelope::{Envelope, Message, TrustedEnvelope, VerifiedEnvelope};
@@ -31,6 +31,7 @@ use crate::{
 use enum_dispatch::enum_dispatch;
 use fastcrypto::{encoding::Base64, hash::HashFunction};
 use itertools::Either;
+use move_binary_format::CompiledModule;
 use move_core_types::ident_str;
 use move_core_types::identifier::IdentStr;
 use move_core_types::{identifier::Identifier, language_storage::TypeTag};
@@ -160,6 +161,24 @@ fn type_tag_validity_check(
     Ok(())
 }
 
+/// Returns the number of nodes in `tag`, counting the tag itself and each of its type parameters
+/// recursively, and the depth of its deepest node, where a tag without type parameters has depth 1.
+pub fn type_tag_nodes_and_depth(tag: &TypeTag) -> (u64, u32) {
+    let mut nodes = 0;
+    let mut max_depth = 0;
+    let mut stack = vec![(tag, 1)];
+    while let Some((tag, depth)) = stack.pop() {
+        nodes += 1;
+        max_depth = max_depth.max(depth);
+        match tag {
+            TypeTag::Vector(t) => stack.push((t, depth + 1)),
+            TypeTag::Struct(s) => stack.extend(s.type_params.iter().map(|t| (t, depth + 1))),
+            _ => (),
+        }
+    }
+    (nodes, max_depth)
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
 pub struct ChangeEpoch {
     /// The next (to become) epoch ID.
@@ -778,7 +797,64 @@ pub struct ProgrammableMoveCall {
     pub arguments: Vec<Argument>,
 }
 
+/// A Move call in a programmable transaction, with its arguments counted by kind, e.g. for
+/// indexing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveCallSummary {
+    pub package: ObjectID,
+    pub module: Identifier,
+    pub function: Identifier,
+    /// Pure inputs passed to the call.
+    pub pure_arguments: usize,
+    /// Owned, immutable or receiving objects passed to the call, including the gas coin.
+    pub object_arguments: usize,
+    /// Shared objects passed to the call.
+    pub shared_object_arguments: usize,
+    /// Results of earlier commands passed to the call.
+    pub result_arguments: usize,
+}
+
+impl MoveCallSummary {
+    pub fn total_argument_count(&self) -> usize {
+        self.pure_arguments
+            + self.object_arguments
+            + self.shared_object_arguments
+            + self.result_arguments
+    }
+}
+
 impl ProgrammableMoveCall {
+    /// Summarizes this call, classifying its arguments using the `inputs` of the programmable
+    /// transaction it is part of. Arguments referring to inputs that do not exist are not counted.
+    pub fn summary(&self, inputs: &[CallArg]) -> MoveCallSummary {
+        let mut summary = MoveCallSummary {
+            package: self.package,
+            module: self.module.clone(),
+            function: self.function.clone(),
+            pure_arguments: 0,
+            object_arguments: 0,
+            shared_object_arguments: 0,
+            result_arguments: 0,
+        };
+        for argument in &self.arguments {
+            match argument {
+                Argument::GasCoin => summary.object_arguments += 1,
+                Argument::Input(i) => match inputs.get(*i as usize) {
+                    Some(CallArg::Pure(_)) => summary.pure_arguments += 1,
+                    Some(CallArg::Object(ObjectArg::SharedObject { .. })) => {
+                        summary.shared_object_arguments += 1
+                    }
+                    Some(CallArg::Object(
+                        ObjectArg::ImmOrOwnedObject(_) | ObjectArg::Receiving(_),
+                    )) => summary.object_arguments += 1,
+                    None => (),
+                },
+                Argument::Result(_) | Argument::NestedResult(_, _) => summary.result_arguments += 1,
+            }
+        }
+        summary
+    }
+
     fn input_objects(&self) -> Vec<InputObjectKind> {
         let ProgrammableMoveCall {
             package,
@@ -816,6 +892,30 @@ impl ProgrammableMoveCall {
         Ok(())
     }
 
+    /// Client-side check that the type arguments of this call are not too large for the
+    /// verifier: their total number of nodes must not exceed `max_type_nodes`, and none of them
+    /// may be nested deeper than `max_type_argument_depth`.
+    pub fn check_type_arguments(&self, config: &ProtocolConfig) -> SuiResult<()> {
+        let (nodes, depth) = self
+            .type_arguments
+            .iter()
+            .map(type_tag_nodes_and_depth)
+            .fold((0, 0), |(nodes, depth), (n, d)| (nodes + n, depth.max(d)));
+
+        let max_nodes = config.max_type_nodes();
+        let max_depth = config.max_type_argument_depth();
+        fp_ensure!(
+            nodes <= max_nodes && depth <= max_depth,
+            SuiError::TypeArgumentTooComplex {
+                nodes,
+                max_nodes,
+                depth,
+                max_depth,
+            }
+        );
+        Ok(())
+    }
+
     fn is_input_arg_used(&self, arg: u16) -> bool {
         self.arguments
             .iter()
@@ -1101,6 +1201,16 @@ impl ProgrammableTransaction {
             .collect()
     }
 
+    pub fn move_call_summaries(&self) -> Vec<MoveCallSummary> {
+        self.commands
+            .iter()
+            .filter_map(|command| match command {
+                Command::MoveCall(m) => Some(m.summary(&self.inputs)),
+                _ => None,
+            })
+            .collect()
+    }
+
     pub fn non_system_packages_to_be_published(&self) -> impl Iterator<Item = &Vec<Vec<u8>>> + '_ {
         self.commands
             .iter()
@@ -1322,6 +1432,14 @@ impl TransactionKind {
         }
     }
 
+    /// Summaries of the Move calls made by this transaction, in order.
+    pub fn move_call_summaries(&self) -> Vec<MoveCallSummary> {
+        match &self {
+            Self::ProgrammableTransaction(pt) => pt.move_call_summaries(),
+            _ => vec![],
+        }
+    }
+
     pub fn receiving_objects(&self) -> Vec<ObjectRef> {
         match &self {
             TransactionKind::ChangeEpoch(_)
@@ -1948,6 +2066,113 @@ impl TransactionData {
             self.gas_data().payment.clone(),
         )
     }
+
+    /// Checks the gas budget of this transaction against the maximum allowed by `config`,
+    /// so that clients can reject an over-budget transaction before submitting it.
+    pub fn check_gas_budget(&self, config: &ProtocolConfig) -> SuiResult {
+        check_gas_budget(config, self.gas_budget())
+    }
+}
+
+/// Checks that `gas_budget` does not exceed the maximum transaction gas allowed by `config`.
+pub fn check_gas_budget(config: &ProtocolConfig, gas_budget: u64) -> SuiResult {
+    let max_budget = config.max_tx_gas();
+    fp_ensure!(
+        gas_budget <= max_budget,
+        UserInputError::GasBudgetTooHigh {
+            gas_budget,
+            max_budget
+        }
+        .into()
+    );
+    Ok(())
+}
+
+/// Builds a `TransactionData`, checking on `build` that all required fields are set and that the
+/// transaction's inputs are valid. The gas owner defaults to the sender, and the transaction
+/// does not expire unless an expiration is set.
+#[derive(Default)]
+pub struct TransactionDataBuilder {
+    kind: Option<TransactionKind>,
+    sender: Option<SuiAddress>,
+    gas_payment: Vec<ObjectRef>,
+    gas_owner: Option<SuiAddress>,
+    gas_budget: Option<u64>,
+    gas_price: Option<u64>,
+    expiration: Option<TransactionExpiration>,
+}
+
+impl TransactionDataBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn kind(mut self, kind: TransactionKind) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+
+    pub fn sender(mut self, sender: SuiAddress) -> Self {
+        self.sender = Some(sender);
+        self
+    }
+
+    pub fn gas_payment(mut self, gas_payment: Vec<ObjectRef>) -> Self {
+        self.gas_payment = gas_payment;
+        self
+    }
+
+    /// Sponsor the transaction's gas with `gas_owner`, instead of the sender.
+    pub fn gas_owner(mut self, gas_owner: SuiAddress) -> Self {
+        self.gas_owner = Some(gas_owner);
+        self
+    }
+
+    pub fn gas_budget(mut self, gas_budget: u64) -> Self {
+        self.gas_budget = Some(gas_budget);
+        self
+    }
+
+    pub fn gas_price(mut self, gas_price: u64) -> Self {
+        self.gas_price = Some(gas_price);
+        self
+    }
+
+    pub fn expiration(mut self, expiration: TransactionExpiration) -> Self {
+        self.expiration = Some(expiration);
+        self
+    }
+
+    /// Builds the transaction data, failing if a required field is missing, or if its input
+    /// objects are invalid, e.g. because a gas payment object is also used as an input.
+    pub fn build(self) -> SuiResult<TransactionData> {
+        let missing = |field: &str| UserInputError::MissingTransactionDataField {
+            field: field.to_string(),
+        };
+        let kind = self.kind.ok_or_else(|| missing("kind"))?;
+        let sender = self.sender.ok_or_else(|| missing("sender"))?;
+        fp_ensure!(
+            !self.gas_payment.is_empty(),
+            UserInputError::MissingGasPayment.into()
+        );
+        let gas_budget = self.gas_budget.ok_or_else(|| missing("gas budget"))?;
+        let gas_price = self.gas_price.ok_or_else(|| missing("gas price"))?;
+
+        let data = TransactionData::V1(TransactionDataV1 {
+            kind,
+            sender,
+            gas_data: GasData {
+                payment: self.gas_payment,
+                owner: self.gas_owner.unwrap_or(sender),
+                price: gas_price,
+                budget: gas_budget,
+            },
+            expiration: self.expiration.unwrap_or(TransactionExpiration::None),
+        });
+        data.input_objects()?;
+        data.check_gas_not_used_as_argument()?;
+        Ok(data)
+    }
 }
 
 #[enum_dispatch]
@@ -1987,6 +2212,10 @@ pub trait TransactionDataAPI {
 
     fn input_objects(&self) -> UserInputResult<Vec<InputObjectKind>>;
 
+    /// Like `input_objects`, but also fails if a module published or upgraded by the transaction
+    /// cannot be deserialized, rather than leaving that error to be found during execution.
+    fn try_input_objects(&self) -> SuiResult<Vec<InputObjectKind>>;
+
     fn receiving_objects(&self) -> Vec<ObjectRef>;
 
     fn validity_check(&self, config: &ProtocolConfig) -> UserInputResult;
@@ -1996,6 +2225,9 @@ pub trait TransactionDataAPI {
     /// Check if the transaction is compliant with sponsorship.
     fn check_sponsorship(&self) -> UserInputResult;
 
+    /// Check that no gas payment object is also an input of the transaction.
+    fn check_gas_not_used_as_argument(&self) -> UserInputResult;
+
     fn is_system_tx(&self) -> bool;
     fn is_genesis_tx(&self) -> bool;
 
@@ -2089,6 +2321,20 @@ impl TransactionDataAPI for TransactionDataV1 {
         Ok(inputs)
     }
 
+    fn try_input_objects(&self) -> SuiResult<Vec<InputObjectKind>> {
+        if let TransactionKind::ProgrammableTransaction(pt) = &self.kind {
+            let modules = pt.non_system_packages_to_be_published().flatten();
+            for (index, module) in modules.enumerate() {
+                CompiledModule::deserialize_with_defaults(module).map_err(|error| {
+                    SuiError::ModuleDeserializationFailure {
+                        error: format!("module at index {index}: {error}"),
+                    }
+                })?;
+            }
+        }
+        Ok(self.input_objects()?)
+    }
+
     fn receiving_objects(&self) -> Vec<ObjectRef> {
         self.kind.receiving_objects()
     }
@@ -2102,6 +2348,9 @@ impl TransactionDataAPI for TransactionDataV1 {
                 value: config.max_gas_payment_objects().to_string()
             }
         );
+        if config.reject_gas_object_as_argument() {
+            self.check_gas_not_used_as_argument()?;
+        }
         self.validity_check_no_gas_check(config)
     }
 
@@ -2117,6 +2366,21 @@ impl TransactionDataAPI for TransactionDataV1 {
         self.gas_owner() != self.sender
     }
 
+    /// The gas coin can only be used in a transaction through `Argument::GasCoin`.
+    fn check_gas_not_used_as_argument(&self) -> UserInputResult {
+        let inputs = self.kind.input_objects()?;
+        match self
+            .gas()
+            .iter()
+            .find(|(gas_id, _, _)| inputs.iter().any(|input| input.object_id() == *gas_id))
+        {
+            Some((object_id, _, _)) => Err(UserInputError::GasObjectUsedAsArgument {
+                object_id: *object_id,
+            }),
+            None => Ok(()),
+        }
+    }
+
     /// Check if the transaction is compliant with sponsorship.
     fn check_sponsorship(&self) -> UserInputResult {
         // Not a sponsored transaction, nothing to check
@@ -2448,6 +2712,27 @@ impl<S> Envelope<SenderSignedData, S> {
             .into_iter()
     }
 
+    /// Returns the shared input objects of this transaction, keeping only the first occurrence of
+    /// each object.
+    pub fn unique_shared_input_objects(&self) -> Vec<SharedInputObject> {
+        let mut seen = HashSet::new();
+        self.shared_input_objects()
+            .filter(|object| seen.insert(object.id))
+            .collect()
+    }
+
+    /// Checks that no shared object is listed more than once in the inputs of this transaction.
+    pub fn validate_shared_inputs(&self) -> SuiResult<()> {
+        let mut seen = HashSet::new();
+        match self
+            .shared_input_objects()
+            .find(|object| !seen.insert(object.id))
+        {
+            Some(object) => Err(SuiError::DuplicateSharedObjectInput { id: object.id }),
+            None => Ok(()),
+        }
+    }
+
     // Returns the primary key for this transaction.
     pub fn key(&self) -> TransactionKey {
         match &self.data().intent_message().value.kind() {
@@ -2668,6 +2953,10 @@ pub type TrustedTransaction = TrustedEnvelope<SenderSignedData, EmptySignInfo>;
 pub type SignedTransaction = Envelope<SenderSignedData, AuthoritySignInfo>;
 pub type VerifiedSignedTransaction = VerifiedEnvelope<SenderSignedData, AuthoritySignInfo>;
 
+/// Signed transactions already verified against a committee of a given epoch, so that repeated
+/// checks of the same signed transaction can skip the signature verification.
+pub type SignatureCache = VerifiedDigestCache<(SignedTransactionDigest, EpochId)>;
+
 impl Transaction {
     pub fn verify_signature_for_testing(
         &self,
@@ -2712,6 +3001,43 @@ impl SignedTransaction {
         )
     }
 
+    pub fn signed_transaction_digest(&self) -> SignedTransactionDigest {
+        let mut digest = DefaultHash::default();
+        bcs::serialize_into(&mut digest, self).expect("serialization should not fail");
+        let hash = digest.finalize();
+        SignedTransactionDigest::new(hash.into())
+    }
+
+    /// Verifies the sender and authority signatures, unless this exact signed transaction was
+    /// already verified against a committee of the same epoch. The cache key commits to the
+    /// sender signatures and the authority signature, so a transaction that differs in any
+    /// signature is always verified.
+    pub fn check_cached(
+        &self,
+        committee: &Committee,
+        verify_params: &VerifyParams,
+        zklogin_inputs_cache: Arc<VerifiedDigestCache<ZKLoginInputsDigest>>,
+        cache: &SignatureCache,
+    ) -> SuiResult {
+        cache.is_verified(
+            (self.signed_transaction_digest(), committee.epoch()),
+            || {
+                verify_sender_signed_data_message_signatures(
+                    self.data(),
+                    committee.epoch(),
+                    verify_params,
+                    zklogin_inputs_cache,
+                )?;
+                self.auth_sig().verify_secure(
+                    self.data(),
+                    Intent::sui_app(IntentScope::SenderSignedTransaction),
+                    committee,
+                )
+            },
+            || Ok(()),
+        )
+    }
+
     pub fn try_into_verified_for_testing(
         self,
         committee: &Committee,
@@ -2725,6 +3051,38 @@ impl SignedTransaction {
 pub type CertifiedTransaction = Envelope<SenderSignedData, AuthorityStrongQuorumSignInfo>;
 
 impl CertifiedTransaction {
+    /// Builds a certificate from a complete set of authority signatures on `transaction`, e.g.
+    /// when reconstructing it from storage. Fails if an authority signed more than once, if a
+    /// signer is not in `committee`, if the signers do not form a quorum, or if the aggregated
+    /// signature does not verify.
+    pub fn new_from_signatures(
+        transaction: Transaction,
+        signatures: Vec<(AuthorityName, AuthoritySignature)>,
+        committee: &Committee,
+    ) -> SuiResult<Self> {
+        let mut seen: BTreeMap<AuthorityName, &AuthoritySignature> = BTreeMap::new();
+        for (authority, signature) in &signatures {
+            if let Some(previous) = seen.insert(*authority, signature) {
+                return Err(SuiError::StakeAggregatorRepeatedSigner {
+                    signer: *authority,
+                    conflicting_sig: previous != signature,
+                });
+            }
+        }
+
+        let signatures = signatures
+            .into_iter()
+            .map(|(authority, signature)| AuthoritySignInfo {
+                epoch: committee.epoch(),
+                authority,
+                signature,
+            })
+            .collect();
+        let cert = Self::new(transaction.into_data(), signatures, committee)?;
+        cert.verify_committee_sigs_only(committee)?;
+        Ok(cert)
+    }
+
     pub fn certificate_digest(&self) -> CertificateDigest {
         let mut digest = DefaultHash::default();
         bcs::serialize_into(&mut digest, self).expect("serialization should not fail");
@@ -2779,6 +3137,51 @@ impl CertifiedTransaction {
     }
 }
 
+/// Verifies the committee signatures of all `certs`, as `verify_committee_sigs_only` does for each
+/// of them, but with a single batched cryptographic check across all the certificates. Sender
+/// signatures are not checked. On failure the error does not say which certificate is invalid;
+/// callers that need to know should verify the certificates individually.
+pub fn verify_certificates_batch(
+    certs: &[CertifiedTransaction],
+    committee: &Committee,
+) -> SuiResult<()> {
+    if certs.is_empty() {
+        return Ok(());
+    }
+
+    let mut obligation = VerificationObligation::default();
+    for cert in certs {
+        let idx = obligation.add_message(
+            cert.data(),
+            cert.epoch(),
+            Intent::sui_app(IntentScope::SenderSignedTransaction),
+        );
+        cert.auth_sig()
+            .add_to_verification_obligation(committee, &mut obligation, idx)?;
+    }
+    obligation.verify_all()
+}
+
+/// Maps each shared object used as an input by any of `transactions` to the digests of the
+/// transactions using it, in the order they appear in `transactions`. Objects that only one
+/// transaction uses are included too, so callers looking for contention should check the length
+/// of each list.
+pub fn shared_object_contention(
+    transactions: &[Transaction],
+) -> BTreeMap<ObjectID, Vec<TransactionDigest>> {
+    let mut contention: BTreeMap<ObjectID, Vec<TransactionDigest>> = BTreeMap::new();
+    for transaction in transactions {
+        let digest = *transaction.digest();
+        for shared in transaction.shared_input_objects() {
+            let digests = contention.entry(shared.id).or_default();
+            if digests.last() != Some(&digest) {
+                digests.push(digest);
+            }
+        }
+    }
+    contention
+}
+
 pub type VerifiedCertificate = VerifiedEnvelope<SenderSignedData, AuthorityStrongQuorumSignInfo>;
 pub type TrustedCertificate = TrustedEnvelope<SenderSignedData, AuthorityStrongQuorumSignInfo>;
 
@@ -16,13 +16,16 @@ use crate::crypto::{
     AuthoritySignInfoTrait, SuiAuthoritySignature,
 };
 use crate::digests::TransactionEventsDigest;
-use crate::effects::{SignedTransactionEffects, TestEffectsBuilder, TransactionEffectsAPI};
+use crate::effects::{
+    SignedTransactionEffects, TestEffectsBuilder, TransactionEffects, TransactionEffectsAPI,
+};
 use crate::execution_status::ExecutionStatus;
 use crate::gas::GasCostSummary;
 use crate::object::Owner;
 use fastcrypto::traits::AggregateAuthenticator;
 use fastcrypto::traits::KeyPair;
 use move_core_types::language_storage::StructTag;
+use prometheus::IntCounter;
 use roaring::RoaringBitmap;
 use std::collections::hash_map::DefaultHasher;
 use std::collections::BTreeMap;
@@ -115,6 +118,82 @@ fn test_signed_values() {
         .is_err());
 }
 
+#[test]
+fn test_signed_transaction_check_cached() {
+    let (_, sec1): (_, AuthorityKeyPair) = get_key_pair();
+    let (_, sec2): (_, AuthorityKeyPair) = get_key_pair();
+    let (_, sec3): (_, AuthorityKeyPair) = get_key_pair();
+    let (a_sender, sender_sec): (_, AccountKeyPair) = get_key_pair();
+    let authorities = BTreeMap::from([
+        (AuthorityPublicKeyBytes::from(sec1.public()), 1),
+        (AuthorityPublicKeyBytes::from(sec2.public()), 1),
+    ]);
+    let committee = Committee::new_for_testing_with_normalized_voting_power(0, authorities);
+
+    let gas_price = 10;
+    let transaction = Transaction::from_data_and_signer(
+        TransactionData::new_transfer(
+            a_sender,
+            random_object_ref(),
+            a_sender,
+            random_object_ref(),
+            TEST_ONLY_GAS_UNIT_FOR_TRANSFER * gas_price,
+            gas_price,
+        ),
+        vec![&sender_sec],
+    );
+
+    let hits = IntCounter::new("hits", "hits").unwrap();
+    let misses = IntCounter::new("misses", "misses").unwrap();
+    let cache = SignatureCache::new(
+        hits.clone(),
+        misses.clone(),
+        IntCounter::new("evictions", "evictions").unwrap(),
+    );
+    let check = |signed: &SignedTransaction| {
+        signed.check_cached(
+            &committee,
+            &Default::default(),
+            Arc::new(VerifiedDigestCache::new_empty()),
+            &cache,
+        )
+    };
+
+    let signed = SignedTransaction::new(
+        committee.epoch(),
+        transaction.clone(),
+        &sec1,
+        AuthorityPublicKeyBytes::from(sec1.public()),
+    );
+    check(&signed).unwrap();
+    assert_eq!((hits.get(), misses.get()), (0, 1));
+
+    // The second check of the same signed transaction skips verification.
+    check(&signed).unwrap();
+    assert_eq!((hits.get(), misses.get()), (1, 1));
+
+    // A signature by another authority is verified on its own.
+    let signed_by_other = SignedTransaction::new(
+        committee.epoch(),
+        transaction.clone(),
+        &sec2,
+        AuthorityPublicKeyBytes::from(sec2.public()),
+    );
+    check(&signed_by_other).unwrap();
+    assert_eq!((hits.get(), misses.get()), (1, 2));
+
+    // Failed verifications are not cached.
+    let signed_by_outsider = SignedTransaction::new(
+        committee.epoch(),
+        transaction,
+        &sec3,
+        AuthorityPublicKeyBytes::from(sec3.public()),
+    );
+    check(&signed_by_outsider).unwrap_err();
+    check(&signed_by_outsider).unwrap_err();
+    assert_eq!((hits.get(), misses.get()), (1, 4));
+}
+
 #[test]
 fn test_certificates() {
     let (_a1, sec1): (_, AuthorityKeyPair) = get_key_pair();
@@ -1347,6 +1426,273 @@ fn test_certificate_digest() {
     assert_ne!(digest, cert.certificate_digest());
 }
 
+#[test]
+fn test_verify_certificates_batch() {
+    let (committee, key_pairs) = Committee::new_simple_test_committee();
+    let (receiver, _): (_, AccountKeyPair) = get_key_pair();
+    let (sender, sender_sec): (_, AccountKeyPair) = get_key_pair();
+
+    let gas_price = 10;
+    let make_cert = |signers: &[AuthorityKeyPair]| {
+        let transaction = Transaction::from_data_and_signer(
+            TransactionData::new_transfer(
+                receiver,
+                random_object_ref(),
+                sender,
+                random_object_ref(),
+                TEST_ONLY_GAS_UNIT_FOR_TRANSFER * gas_price,
+                gas_price,
+            ),
+            vec![&sender_sec],
+        );
+        let sigs: Vec<_> = signers
+            .iter()
+            .map(|key_pair| {
+                SignedTransaction::new(
+                    committee.epoch(),
+                    transaction.clone().into_data(),
+                    key_pair,
+                    AuthorityPublicKeyBytes::from(key_pair.public()),
+                )
+                .auth_sig()
+                .clone()
+            })
+            .collect();
+        CertifiedTransaction::new(transaction.into_data(), sigs, &committee).unwrap()
+    };
+
+    let mut certs: Vec<_> = (0..4).map(|_| make_cert(&key_pairs[..3])).collect();
+    for cert in &certs {
+        cert.verify_committee_sigs_only(&committee).unwrap();
+    }
+    verify_certificates_batch(&certs, &committee).unwrap();
+    verify_certificates_batch(&[], &committee).unwrap();
+
+    // Give one certificate the aggregate signature of another transaction.
+    let other = make_cert(&key_pairs[1..]);
+    certs[2].auth_sig_mut_for_testing().signature = other.auth_sig().signature.clone();
+
+    let results: Vec<_> = certs
+        .iter()
+        .map(|cert| cert.verify_committee_sigs_only(&committee).is_ok())
+        .collect();
+    assert_eq!(results, vec![true, true, false, true]);
+    assert!(verify_certificates_batch(&certs, &committee).is_err());
+
+    // The remaining certificates still verify as a batch.
+    certs.remove(2);
+    verify_certificates_batch(&certs, &committee).unwrap();
+}
+
+#[test]
+fn test_certificate_display_is_independent_of_signature_order() {
+    let (committee, key_pairs) = Committee::new_simple_test_committee();
+    let (receiver, _): (_, AccountKeyPair) = get_key_pair();
+    let (sender, sender_sec): (_, AccountKeyPair) = get_key_pair();
+
+    let gas_price = 10;
+    let transaction = Transaction::from_data_and_signer(
+        TransactionData::new_transfer(
+            receiver,
+            random_object_ref(),
+            sender,
+            random_object_ref(),
+            TEST_ONLY_GAS_UNIT_FOR_TRANSFER * gas_price,
+            gas_price,
+        ),
+        vec![&sender_sec],
+    );
+    let signatures: Vec<_> = key_pairs
+        .iter()
+        .take(3)
+        .map(|key_pair| {
+            let sig = SignedTransaction::new(
+                committee.epoch(),
+                transaction.clone().into_data(),
+                key_pair,
+                AuthorityPublicKeyBytes::from(key_pair.public()),
+            )
+            .auth_sig()
+            .clone();
+            (sig.authority, sig.signature)
+        })
+        .collect();
+    let mut reversed = signatures.clone();
+    reversed.reverse();
+
+    let cert =
+        CertifiedTransaction::new_from_signatures(transaction.clone(), signatures, &committee)
+            .unwrap();
+    let reversed_cert =
+        CertifiedTransaction::new_from_signatures(transaction, reversed, &committee).unwrap();
+
+    // Signers are printed as the set of their committee indices, whatever order they signed in.
+    assert_eq!(cert.to_string(), reversed_cert.to_string());
+}
+
+#[test]
+fn test_certificate_new_from_signatures() {
+    let (committee, key_pairs) = Committee::new_simple_test_committee();
+    let (receiver, _): (_, AccountKeyPair) = get_key_pair();
+    let (sender, sender_sec): (_, AccountKeyPair) = get_key_pair();
+
+    let gas_price = 10;
+    let transaction = Transaction::from_data_and_signer(
+        TransactionData::new_transfer(
+            receiver,
+            random_object_ref(),
+            sender,
+            random_object_ref(),
+            TEST_ONLY_GAS_UNIT_FOR_TRANSFER * gas_price,
+            gas_price,
+        ),
+        vec![&sender_sec],
+    );
+    let signatures: Vec<_> = key_pairs
+        .iter()
+        .map(|key_pair| {
+            let sig = SignedTransaction::new(
+                committee.epoch(),
+                transaction.clone().into_data(),
+                key_pair,
+                AuthorityPublicKeyBytes::from(key_pair.public()),
+            )
+            .auth_sig()
+            .clone();
+            (sig.authority, sig.signature)
+        })
+        .collect();
+
+    let cert = CertifiedTransaction::new_from_signatures(
+        transaction.clone(),
+        signatures[..3].to_vec(),
+        &committee,
+    )
+    .unwrap();
+    assert_eq!(cert.digest(), transaction.digest());
+    cert.verify_committee_sigs_only(&committee).unwrap();
+
+    // Below quorum.
+    assert!(CertifiedTransaction::new_from_signatures(
+        transaction.clone(),
+        signatures[..2].to_vec(),
+        &committee,
+    )
+    .is_err());
+
+    // A repeated signer does not count twice towards the quorum.
+    let repeated = vec![
+        signatures[0].clone(),
+        signatures[1].clone(),
+        signatures[1].clone(),
+    ];
+    assert!(matches!(
+        CertifiedTransaction::new_from_signatures(transaction.clone(), repeated, &committee),
+        Err(SuiError::StakeAggregatorRepeatedSigner {
+            conflicting_sig: false,
+            ..
+        })
+    ));
+
+    // A signature from the wrong authority fails verification.
+    let mut mismatched = signatures[..3].to_vec();
+    mismatched[0].1 = signatures[3].1.clone();
+    assert!(
+        CertifiedTransaction::new_from_signatures(transaction, mismatched, &committee).is_err()
+    );
+}
+
+#[test]
+fn test_shared_object_contention() {
+    let (sender, sender_sec): (_, AccountKeyPair) = get_key_pair();
+    let counter = ObjectID::random();
+    let gas_price = 10;
+
+    let make_tx = |arguments: Vec<CallArg>| {
+        Transaction::from_data_and_signer(
+            TransactionData::new_move_call(
+                sender,
+                ObjectID::random(),
+                Identifier::new("counter").unwrap(),
+                Identifier::new("increment").unwrap(),
+                vec![],
+                random_object_ref(),
+                arguments,
+                TEST_ONLY_GAS_UNIT_FOR_TRANSFER * gas_price,
+                gas_price,
+            )
+            .unwrap(),
+            vec![&sender_sec],
+        )
+    };
+    let shared_counter = || {
+        CallArg::Object(ObjectArg::SharedObject {
+            id: counter,
+            initial_shared_version: SequenceNumber::from_u64(1),
+            mutable: true,
+        })
+    };
+
+    let tx_1 = make_tx(vec![shared_counter()]);
+    let tx_2 = make_tx(vec![CallArg::Object(ObjectArg::ImmOrOwnedObject(
+        random_object_ref(),
+    ))]);
+    let tx_3 = make_tx(vec![shared_counter()]);
+
+    let contention = shared_object_contention(&[tx_1.clone(), tx_2.clone(), tx_3.clone()]);
+    assert_eq!(
+        contention,
+        BTreeMap::from([(counter, vec![*tx_1.digest(), *tx_3.digest()])])
+    );
+    assert!(contention.values().flatten().all(|d| d != tx_2.digest()));
+    assert!(shared_object_contention(&[]).is_empty());
+}
+
+#[test]
+fn test_try_input_objects_rejects_malformed_modules() {
+    let (sender, _): (_, AccountKeyPair) = get_key_pair();
+    let dep = ObjectID::random();
+    let gas_price = 10;
+
+    let empty_module = move_binary_format::file_format::empty_module();
+    let mut module = vec![];
+    empty_module
+        .serialize_with_version(empty_module.version, &mut module)
+        .unwrap();
+    let publish = |modules: Vec<Vec<u8>>| {
+        TransactionData::new_module(
+            sender,
+            random_object_ref(),
+            modules,
+            vec![dep],
+            TEST_ONLY_GAS_UNIT_FOR_TRANSFER * gas_price,
+            gas_price,
+        )
+    };
+
+    let valid = publish(vec![module.clone()]);
+    assert_eq!(
+        valid.try_input_objects().unwrap(),
+        valid.input_objects().unwrap()
+    );
+    assert!(valid
+        .try_input_objects()
+        .unwrap()
+        .contains(&InputObjectKind::MovePackage(dep)));
+
+    // The lenient variant still ignores the malformed module.
+    let malformed = publish(vec![module, vec![0xde, 0xad, 0xbe, 0xef]]);
+    assert!(malformed.input_objects().is_ok());
+    let err = malformed.try_input_objects().unwrap_err();
+    assert!(
+        matches!(
+            &err,
+            SuiError::ModuleDeserializationFailure { error } if error.contains("index 1")
+        ),
+        "{err:?}"
+    );
+}
+
 // Use this to ensure that our approximation for components used in effects size are not smaller than expected
 // If this test fails, the value of the constant must be increased
 #[test]
@@ -1387,3 +1733,417 @@ fn check_approx_effects_components_size() {
         "Update APPROX_SIZE_OF_EXECUTION_STATUS constant"
     );
 }
+
+#[test]
+fn test_check_gas_budget() {
+    let config = ProtocolConfig::get_for_max_version_UNSAFE();
+    let max_budget = config.max_tx_gas();
+
+    assert!(check_gas_budget(&config, 0).is_ok());
+    assert!(check_gas_budget(&config, max_budget).is_ok());
+    assert_eq!(
+        check_gas_budget(&config, max_budget + 1).unwrap_err(),
+        SuiError::UserInputError {
+            error: UserInputError::GasBudgetTooHigh {
+                gas_budget: max_budget + 1,
+                max_budget,
+            }
+        }
+    );
+
+    let (sender, _): (_, AccountKeyPair) = get_key_pair();
+    let tx = |gas_budget| {
+        TransactionData::new_transfer_sui(
+            SuiAddress::random_for_testing_only(),
+            sender,
+            None,
+            random_object_ref(),
+            gas_budget,
+            1,
+        )
+    };
+    assert!(tx(max_budget).check_gas_budget(&config).is_ok());
+    assert!(tx(max_budget + 1).check_gas_budget(&config).is_err());
+}
+
+#[test]
+fn test_gas_object_used_as_argument() {
+    let sender = SuiAddress::random_for_testing_only();
+    let gas = random_object_ref();
+    let tx = |obj_arg: ObjectArg| {
+        let mut builder = ProgrammableTransactionBuilder::new();
+        let obj = builder.obj(obj_arg).unwrap();
+        builder.transfer_arg(sender, obj);
+        TransactionData::new_programmable(sender, vec![gas], builder.finish(), 1_000_000, 1)
+    };
+
+    // Distinct owned argument and gas.
+    let other = random_object_ref();
+    let inputs = tx(ObjectArg::ImmOrOwnedObject(other))
+        .input_objects()
+        .unwrap();
+    assert_eq!(
+        inputs,
+        vec![
+            InputObjectKind::ImmOrOwnedMoveObject(other),
+            InputObjectKind::ImmOrOwnedMoveObject(gas),
+        ]
+    );
+
+    let mut config = ProtocolConfig::get_for_max_version_UNSAFE();
+    config.set_reject_gas_object_as_argument_for_testing(true);
+    assert!(tx(ObjectArg::ImmOrOwnedObject(other))
+        .validity_check(&config)
+        .is_ok());
+
+    let owned = tx(ObjectArg::ImmOrOwnedObject(gas));
+    let shared = tx(ObjectArg::SharedObject {
+        id: gas.0,
+        initial_shared_version: gas.1,
+        mutable: true,
+    });
+    for tx in [&owned, &shared] {
+        // Listing the inputs does not fail, the gas object is rejected by the validity check.
+        assert!(tx.input_objects().is_ok());
+        assert_eq!(
+            tx.validity_check(&config).unwrap_err(),
+            UserInputError::GasObjectUsedAsArgument { object_id: gas.0 }
+        );
+    }
+
+    // Without the feature flag, such transactions are left to the input object checks.
+    config.set_reject_gas_object_as_argument_for_testing(false);
+    assert!(owned.validity_check(&config).is_ok());
+}
+
+#[test]
+fn test_sorted_mutated_and_created() {
+    let owner = Owner::AddressOwner(SuiAddress::random_for_testing_only());
+    let created: Vec<_> = (0..4).map(|_| (random_object_ref(), owner)).collect();
+    let mutated: Vec<_> = (0..4).map(|_| (random_object_ref(), owner)).collect();
+    let effects = |created: Vec<(ObjectRef, Owner)>, mutated: Vec<(ObjectRef, Owner)>| {
+        TransactionEffects::new_from_execution_v1(
+            ExecutionStatus::Success,
+            0,
+            GasCostSummary::default(),
+            vec![],
+            vec![],
+            TransactionDigest::random(),
+            created,
+            mutated,
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            (random_object_ref(), owner),
+            None,
+            vec![],
+        )
+    };
+
+    let sorted = effects(created.clone(), mutated.clone()).sorted_mutated_and_created();
+    assert_eq!(sorted.len(), 8);
+    assert!(sorted.windows(2).all(|w| w[0].0 .0 < w[1].0 .0));
+
+    let mut reversed_created = created;
+    reversed_created.reverse();
+    let mut reversed_mutated = mutated;
+    reversed_mutated.reverse();
+    assert_eq!(
+        effects(reversed_created, reversed_mutated).sorted_mutated_and_created(),
+        sorted
+    );
+}
+
+#[test]
+fn test_move_call_summaries() {
+    let owned = random_object_ref();
+    let inputs = vec![
+        CallArg::Pure(bcs::to_bytes(&42u64).unwrap()),
+        CallArg::Object(ObjectArg::ImmOrOwnedObject(owned)),
+        CallArg::Object(ObjectArg::SharedObject {
+            id: ObjectID::random(),
+            initial_shared_version: SequenceNumber::from_u64(1),
+            mutable: true,
+        }),
+        CallArg::Object(ObjectArg::Receiving(random_object_ref())),
+    ];
+    let move_call = |function: &str, arguments| {
+        Command::MoveCall(Box::new(ProgrammableMoveCall {
+            package: ObjectID::random(),
+            module: Identifier::new("m").unwrap(),
+            function: Identifier::new(function).unwrap(),
+            type_arguments: vec![],
+            arguments,
+        }))
+    };
+    let kind = TransactionKind::ProgrammableTransaction(ProgrammableTransaction {
+        inputs,
+        commands: vec![
+            move_call("first", vec![Argument::Input(0)]),
+            Command::TransferObjects(vec![Argument::Input(1)], Argument::Input(0)),
+            move_call(
+                "second",
+                vec![
+                    Argument::Input(0),
+                    Argument::Input(1),
+                    Argument::Input(2),
+                    Argument::Input(3),
+                    Argument::GasCoin,
+                    Argument::Result(0),
+                    Argument::NestedResult(0, 1),
+                ],
+            ),
+        ],
+    });
+
+    let summaries = kind.move_call_summaries();
+    assert_eq!(summaries.len(), 2);
+    assert_eq!(summaries[0].function.as_str(), "first");
+    assert_eq!(summaries[0].pure_arguments, 1);
+    assert_eq!(summaries[0].total_argument_count(), 1);
+
+    let second = &summaries[1];
+    assert_eq!(second.function.as_str(), "second");
+    assert_eq!(second.pure_arguments, 1);
+    assert_eq!(second.object_arguments, 3);
+    assert_eq!(second.shared_object_arguments, 1);
+    assert_eq!(second.result_arguments, 2);
+    assert_eq!(second.total_argument_count(), 7);
+
+    assert!(
+        TransactionKind::Genesis(GenesisTransaction { objects: vec![] })
+            .move_call_summaries()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_move_call_check_type_arguments() {
+    let config = ProtocolConfig::get_for_max_version_UNSAFE();
+    let move_call = |type_arguments| ProgrammableMoveCall {
+        package: ObjectID::random(),
+        module: Identifier::new("m").unwrap(),
+        function: Identifier::new("f").unwrap(),
+        type_arguments,
+        arguments: vec![],
+    };
+
+    // vector<vector<...<u8>>> with the given number of nodes, which is also its depth.
+    let nested_vector =
+        |nodes: u32| (1..nodes).fold(TypeTag::U8, |tag, _| TypeTag::Vector(Box::new(tag)));
+
+    let simple = StructTag {
+        address: ObjectID::random().into(),
+        module: Identifier::new("m").unwrap(),
+        name: Identifier::new("S").unwrap(),
+        type_params: vec![TypeTag::U64, TypeTag::Vector(Box::new(TypeTag::Address))],
+    };
+    assert_eq!(
+        type_tag_nodes_and_depth(&TypeTag::Struct(Box::new(simple.clone()))),
+        (4, 3)
+    );
+    move_call(vec![TypeTag::Struct(Box::new(simple)), TypeTag::Bool])
+        .check_type_arguments(&config)
+        .unwrap();
+
+    let max_depth = config.max_type_argument_depth();
+    move_call(vec![nested_vector(max_depth)])
+        .check_type_arguments(&config)
+        .unwrap();
+    let err = move_call(vec![nested_vector(max_depth + 1)])
+        .check_type_arguments(&config)
+        .unwrap_err();
+    assert!(
+        matches!(err, SuiError::TypeArgumentTooComplex { depth, .. } if depth == max_depth + 1),
+        "{err:?}"
+    );
+
+    // Each argument is shallow enough, but together they have too many nodes.
+    let max_nodes = config.max_type_nodes();
+    let arguments = (0..max_nodes / max_depth as u64 + 1)
+        .map(|_| nested_vector(max_depth))
+        .collect();
+    let err = move_call(arguments)
+        .check_type_arguments(&config)
+        .unwrap_err();
+    assert!(
+        matches!(err, SuiError::TypeArgumentTooComplex { nodes, .. } if nodes > max_nodes),
+        "{err:?}"
+    );
+}
+
+#[test]
+fn test_effects_signing_bytes() {
+    let (committee, key_pairs) = Committee::new_simple_test_committee();
+    let key_pair = &key_pairs[0];
+    let name = AuthorityPublicKeyBytes::from(key_pair.public());
+    let effects = TransactionEffects::default();
+    let signed_effects =
+        SignedTransactionEffects::new(committee.epoch(), effects.clone(), key_pair, name);
+
+    let bytes = effects.signing_bytes(committee.epoch());
+
+    // Signing the bytes directly reproduces the authority's signature over the effects...
+    let signature: AuthoritySignature = fastcrypto::traits::Signer::sign(key_pair, &bytes);
+    assert_eq!(signature, signed_effects.auth_sig().signature);
+
+    // ...and the signature verifies against those bytes with the committee's key.
+    let public_key = committee.public_key(&name).unwrap();
+    fastcrypto::traits::VerifyingKey::verify(
+        public_key,
+        &bytes,
+        &signed_effects.auth_sig().signature,
+    )
+    .unwrap();
+
+    // The bytes are epoch specific.
+    assert!(fastcrypto::traits::VerifyingKey::verify(
+        public_key,
+        &effects.signing_bytes(committee.epoch() + 1),
+        &signed_effects.auth_sig().signature,
+    )
+    .is_err());
+}
+
+#[test]
+fn test_transaction_data_builder() {
+    let sender = SuiAddress::random_for_testing_only();
+    let gas = random_object_ref();
+    let kind = |obj: ObjectRef| {
+        let mut builder = ProgrammableTransactionBuilder::new();
+        let obj = builder.obj(ObjectArg::ImmOrOwnedObject(obj)).unwrap();
+        builder.transfer_arg(sender, obj);
+        TransactionKind::ProgrammableTransaction(builder.finish())
+    };
+    let other = random_object_ref();
+    let builder = || {
+        TransactionDataBuilder::new()
+            .kind(kind(other))
+            .sender(sender)
+            .gas_payment(vec![gas])
+            .gas_budget(1_000_000)
+            .gas_price(1)
+    };
+
+    // A valid build matches the equivalent constructor.
+    assert_eq!(
+        builder().build().unwrap(),
+        TransactionData::new(kind(other), sender, gas, 1_000_000, 1)
+    );
+
+    // The gas owner and expiration can be overridden.
+    let sponsor = SuiAddress::random_for_testing_only();
+    let data = builder()
+        .gas_owner(sponsor)
+        .expiration(TransactionExpiration::Epoch(5))
+        .build()
+        .unwrap();
+    assert_eq!(data.gas_owner(), sponsor);
+    assert_eq!(data.expiration(), &TransactionExpiration::Epoch(5));
+
+    // Gas payment used as an input.
+    assert_eq!(
+        builder().kind(kind(gas)).build().unwrap_err(),
+        UserInputError::GasObjectUsedAsArgument { object_id: gas.0 }.into()
+    );
+
+    // Missing fields.
+    let missing = |field: &str| -> SuiError {
+        UserInputError::MissingTransactionDataField {
+            field: field.to_string(),
+        }
+        .into()
+    };
+    assert_eq!(
+        TransactionDataBuilder::new()
+            .sender(sender)
+            .gas_payment(vec![gas])
+            .gas_budget(1_000_000)
+            .gas_price(1)
+            .build()
+            .unwrap_err(),
+        missing("kind")
+    );
+    assert_eq!(
+        TransactionDataBuilder::new()
+            .kind(kind(other))
+            .gas_payment(vec![gas])
+            .gas_budget(1_000_000)
+            .gas_price(1)
+            .build()
+            .unwrap_err(),
+        missing("sender")
+    );
+    assert_eq!(
+        builder().gas_payment(vec![]).build().unwrap_err(),
+        UserInputError::MissingGasPayment.into()
+    );
+    assert_eq!(
+        TransactionDataBuilder::new()
+            .kind(kind(other))
+            .sender(sender)
+            .gas_payment(vec![gas])
+            .gas_price(1)
+            .build()
+            .unwrap_err(),
+        missing("gas budget")
+    );
+    assert_eq!(
+        TransactionDataBuilder::new()
+            .kind(kind(other))
+            .sender(sender)
+            .gas_payment(vec![gas])
+            .gas_budget(1_000_000)
+            .build()
+            .unwrap_err(),
+        missing("gas price")
+    );
+}
+
+#[test]
+fn test_duplicate_shared_inputs() {
+    let shared = |object: ObjectRef| {
+        CallArg::Object(ObjectArg::SharedObject {
+            id: object.0,
+            initial_shared_version: object.1,
+            mutable: true,
+        })
+    };
+    let tx = |inputs: Vec<CallArg>| {
+        let data = TransactionData::new_programmable(
+            SuiAddress::random_for_testing_only(),
+            vec![random_object_ref()],
+            ProgrammableTransaction {
+                inputs,
+                commands: vec![],
+            },
+            1_000_000,
+            1,
+        );
+        Transaction::from_data(data, vec![])
+    };
+    let (first, second) = (random_object_ref(), random_object_ref());
+
+    let unique = tx(vec![shared(first), shared(second)]);
+    assert!(unique.validate_shared_inputs().is_ok());
+    assert_eq!(
+        unique.unique_shared_input_objects(),
+        unique.shared_input_objects().collect::<Vec<_>>()
+    );
+
+    let duplicated = tx(vec![shared(first), shared(second), shared(first)]);
+    assert_eq!(duplicated.shared_input_objects().count(), 3);
+    assert_eq!(
+        duplicated
+            .unique_shared_input_objects()
+            .iter()
+            .map(|object| object.id)
+            .collect::<Vec<_>>(),
+        vec![first.0, second.0]
+    );
+    assert_eq!(
+        duplicated.validate_shared_inputs().unwrap_err(),
+        SuiError::DuplicateSharedObjectInput { id: first.0 }
+    );
+}
@@ -614,8 +614,18 @@ impl NativesCostTable {
     }
 }
 
-pub fn all_natives(silent: bool) -> NativeFunctionTable {
-    let sui_framework_natives: &[(&str, &str, NativeFunction)] = &[
+/// Returns the `(module, function)` names of all natives registered by `all_natives` for the Sui
+/// framework and Sui system packages, so that tooling can audit them without building a table.
+pub fn native_function_descriptors() -> Vec<(&'static str, &'static str)> {
+    sui_system_natives()
+        .into_iter()
+        .chain(sui_framework_natives())
+        .map(|(module_name, func_name, _)| (module_name, func_name))
+        .collect()
+}
+
+fn sui_framework_natives() -> Vec<(&'static str, &'static str, NativeFunction)> {
+    vec![
         ("address", "from_bytes", make_native!(address::from_bytes)),
         ("address", "to_u256", make_native!(address::to_u256)),
         ("address", "from_u256", make_native!(address::from_u256)),
@@ -883,11 +893,21 @@ pub fn all_natives(silent: bool) -> NativeFunctionTable {
             "poseidon_bn254_internal",
             make_native!(poseidon::poseidon_bn254_internal),
         ),
-    ];
+    ]
+}
+
+fn sui_system_natives() -> Vec<(&'static str, &'static str, NativeFunction)> {
+    vec![(
+        "validator",
+        "validate_metadata_bcs",
+        make_native!(validator::validate_metadata_bcs),
+    )]
+}
+
+pub fn all_natives(silent: bool) -> NativeFunctionTable {
     let sui_framework_natives_iter =
-        sui_framework_natives
-            .iter()
-            .cloned()
+        sui_framework_natives()
+            .into_iter()
             .map(|(module_name, func_name, func)| {
                 (
                     SUI_FRAMEWORK_ADDRESS,
@@ -896,14 +916,8 @@ pub fn all_natives(silent: bool) -> NativeFunctionTable {
                     func,
                 )
             });
-    let sui_system_natives: &[(&str, &str, NativeFunction)] = &[(
-        "validator",
-        "validate_metadata_bcs",
-        make_native!(validator::validate_metadata_bcs),
-    )];
-    sui_system_natives
-        .iter()
-        .cloned()
+    sui_system_natives()
+        .into_iter()
         .map(|(module_name, func_name, func)| {
             (
                 SUI_SYSTEM_ADDRESS,
@@ -990,3 +1004,34 @@ macro_rules! make_native {
 pub(crate) fn legacy_test_cost() -> InternalGas {
     InternalGas::new(0)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn native_function_descriptors_match_all_natives() {
+        let descriptors = native_function_descriptors();
+        assert_eq!(
+            descriptors.len(),
+            sui_framework_natives().len() + sui_system_natives().len()
+        );
+        // Every descriptor is registered exactly once in the native function table.
+        let unique: BTreeSet<_> = descriptors.iter().collect();
+        assert_eq!(unique.len(), descriptors.len());
+
+        let table: BTreeSet<_> = all_natives(/* silent */ true)
+            .into_iter()
+            .filter(|(address, _, _, _)| {
+                *address == SUI_FRAMEWORK_ADDRESS || *address == SUI_SYSTEM_ADDRESS
+            })
+            .map(|(_, module_name, func_name, _)| (module_name.to_string(), func_name.to_string()))
+            .collect();
+        let descriptors: BTreeSet<_> = descriptors
+            .into_iter()
+            .map(|(module_name, func_name)| (module_name.to_string(), func_name.to_string()))
+            .collect();
+        assert_eq!(table, descriptors);
+    }
+}